@@ -1,4 +1,4 @@
-use argon::Compilation;
+use argon::{Compilation, CompileOptions};
 use argon_package::package_layout;
 use clap::Arg;
 use crate::thor;
@@ -14,6 +14,8 @@ impl Subcommand for Build {
     fn definition(&self, app: ClapApp) -> ClapApp {
         app.arg(Arg::from_usage(
             "--pretty 'print the json in pretty format'",
+        )).arg(Arg::from_usage(
+            "--release 'leave out the bounds checks on indexing'",
         ))
     }
 
@@ -22,7 +24,13 @@ impl Subcommand for Build {
 
         let details = package_layout(package).with_context(|_| "packagelayout".to_string())?;
 
-        let mut compilation = Compilation::new();
+        let options = if matches.command.is_present("release") {
+            CompileOptions::release()
+        } else {
+            CompileOptions::default()
+        };
+
+        let mut compilation = Compilation::with_options(options);
         let key = compilation
             .add(&details.lib)
             .with_context(|_| "adding path".to_string())?;
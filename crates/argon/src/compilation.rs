@@ -1,6 +1,7 @@
 use self::codemap_table::CodemapTable;
 use code_database::{AbsolutePath, Database as CodeDatabase, RealFile};
 use crate::database::*;
+use crate::CompileOptions;
 use failure::Error;
 use parity_wasm::elements;
 use std::borrow::Borrow;
@@ -81,13 +82,17 @@ impl Compilation {
     }
 
     pub fn new() -> Compilation {
+        Compilation::with_options(CompileOptions::default())
+    }
+
+    pub fn with_options(options: CompileOptions) -> Compilation {
         Compilation {
             database: Database {
                 files: CodeDatabase::new(),
                 code: CodeTable::new(),
                 ast: AstTable::new(),
                 typed: TypedTable::new(),
-                wasm: WasmTable::new(),
+                wasm: WasmTable::new(options),
             },
         }
     }
@@ -1,7 +1,12 @@
+crate mod abi;
 crate mod body;
 crate mod compile_source;
+crate mod context;
 crate mod expression;
 crate mod function;
+crate mod instances;
 crate mod math;
+crate mod options;
 
 pub use self::math::*;
+pub use self::options::{CompileOptions, TupleReturns};
@@ -0,0 +1,125 @@
+use super::function::wasm_type;
+use crate::{CompileOptions, StructType, TupleReturns, Type};
+use parity_wasm::elements::{self, Opcode, ValueType};
+
+// wasm MVP functions return at most one value, so unless multi-value is
+// enabled, a function returning a tuple or a slice instead takes a pointer
+// as its first parameter and stores the fields there
+crate fn returns_through_pointer(ret: &Type, options: CompileOptions) -> bool {
+    match ret {
+        Type::Tuple(..) | Type::Slice(..) => options.tuple_returns == TupleReturns::OutPointer,
+        _ => false,
+    }
+}
+
+// The offset of each field of a tuple stored in memory. Each field is
+// aligned to its own size, relative to a pointer aligned to 8 bytes.
+crate fn layout(fields: &[ValueType]) -> Vec<u32> {
+    let mut offset = 0;
+
+    fields
+        .iter()
+        .map(|field| {
+            let size = size_of(*field);
+            let aligned = (offset + size - 1) / size * size;
+
+            offset = aligned + size;
+            aligned
+        })
+        .collect()
+}
+
+// How many bytes a tuple laid out by `layout` takes up
+crate fn tuple_size(fields: &[ValueType]) -> u32 {
+    layout(fields)
+        .iter()
+        .zip(fields)
+        .map(|(offset, field)| offset + size_of(*field))
+        .max()
+        .unwrap_or(0)
+}
+
+// Where a struct's fields are, relative to its address, and how much memory
+// it takes up
+#[derive(Debug, Clone, Eq, PartialEq)]
+crate struct StructLayout {
+    crate offsets: Vec<u32>,
+    crate size: u32,
+    crate align: u32,
+}
+
+// A struct's fields are laid out like a tuple's, in the order they're
+// declared. Its alignment is its largest field's, and its size is rounded
+// up to it.
+crate fn struct_layout(ty: &StructType) -> StructLayout {
+    let fields: Vec<ValueType> = ty
+        .fields
+        .iter()
+        .map(|field| wasm_type(&field.ty).expect("fields are numbers or booleans"))
+        .collect();
+
+    let offsets = layout(&fields);
+    let align = fields.iter().map(|field| size_of(*field)).max().unwrap_or(1);
+    let end = tuple_size(&fields);
+
+    StructLayout {
+        offsets,
+        size: (end + align - 1) / align * align,
+        align,
+    }
+}
+
+// Elements are laid out one after another with no padding, since every
+// wasm value's size is a multiple of its alignment. An array of structs
+// holds pointers to them.
+crate fn element_size(ty: &Type) -> u32 {
+    size_of(element_type(ty))
+}
+
+crate fn element_type(ty: &Type) -> ValueType {
+    wasm_type(ty).expect("elements are numbers, booleans or structs")
+}
+
+// Loads and stores of values laid out by the compiler, which are aligned to
+// their size
+crate fn load(ty: ValueType, offset: u32) -> Opcode {
+    load_aligned(ty, alignment(ty), offset)
+}
+
+crate fn store(ty: ValueType, offset: u32) -> Opcode {
+    store_aligned(ty, alignment(ty), offset)
+}
+
+// `align` is the log2 of the alignment the address is promised to have
+crate fn load_aligned(ty: ValueType, align: u32, offset: u32) -> Opcode {
+    match ty {
+        ValueType::I32 => elements::Opcode::I32Load(align, offset),
+        ValueType::I64 => elements::Opcode::I64Load(align, offset),
+        ValueType::F32 => elements::Opcode::F32Load(align, offset),
+        ValueType::F64 => elements::Opcode::F64Load(align, offset),
+    }
+}
+
+crate fn store_aligned(ty: ValueType, align: u32, offset: u32) -> Opcode {
+    match ty {
+        ValueType::I32 => elements::Opcode::I32Store(align, offset),
+        ValueType::I64 => elements::Opcode::I64Store(align, offset),
+        ValueType::F32 => elements::Opcode::F32Store(align, offset),
+        ValueType::F64 => elements::Opcode::F64Store(align, offset),
+    }
+}
+
+fn size_of(ty: ValueType) -> u32 {
+    match ty {
+        ValueType::I32 | ValueType::F32 => 4,
+        ValueType::I64 | ValueType::F64 => 8,
+    }
+}
+
+// loads and stores take the log2 of their alignment
+fn alignment(ty: ValueType) -> u32 {
+    match size_of(ty) {
+        4 => 2,
+        _ => 3,
+    }
+}
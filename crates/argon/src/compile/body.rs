@@ -1,18 +1,78 @@
-use super::expression::compile_expression;
-use crate::annotated;
+use super::context::{FunctionContext, Heap};
+use super::expression::{compile_expression, drop_value, produces_value, store_results};
+use super::instances::Instances;
+use crate::annotated::{self, Annotated};
+use crate::{CompileOptions, Type};
 use parity_wasm::elements;
 
+// Returns the body's instructions and the wasm locals it uses
 crate fn compile_body(
     input: &annotated::Block,
     function: &annotated::Function,
-) -> Vec<elements::Opcode> {
+    instances: &mut Instances,
+    heap: &mut Heap,
+    options: CompileOptions,
+) -> (Vec<elements::Opcode>, Vec<elements::ValueType>) {
     let mut instructions = vec![];
+    let mut cx = FunctionContext::new(function, instances, heap, options);
 
-    for expression in &input.expressions {
-        compile_expression(&mut instructions, expression, function);
+    compile_block(&mut instructions, input, &mut cx);
+
+    // a void function discards whatever its body produces
+    if function.ret.node == Type::Void {
+        drop_value(&mut instructions, &function.body.ty);
+    }
+
+    if cx.out_pointer().is_some() && !function.body.ty.is_never() {
+        store_results(&mut instructions, &mut cx);
     }
 
     instructions.push(elements::Opcode::End);
 
-    instructions
+    (instructions, cx.into_locals())
+}
+
+// Only the tail expression of a block leaves its value on the stack; the
+// values of statements are dropped.
+crate fn compile_block(
+    body: &mut Vec<elements::Opcode>,
+    input: &annotated::Block,
+    cx: &mut FunctionContext<'_>,
+) {
+    let count = input.expressions.len();
+
+    for (i, expression) in input.expressions.iter().enumerate() {
+        compile_expression(body, expression, cx);
+
+        let is_tail = input.tail && i + 1 == count;
+
+        if !is_tail && produces_value(&expression.ty) {
+            lint_discarded(expression);
+            drop_value(body, &expression.ty);
+        }
+    }
+}
+
+// Computing a value without side effects only to throw it away is almost
+// certainly a mistake, like `x + 1;` instead of `x += 1;`
+fn lint_discarded(expression: &Annotated<annotated::Expression>) {
+    match expression.item {
+        annotated::Expression::Const(..)
+        | annotated::Expression::VariableAccess(..)
+        | annotated::Expression::Binary { .. }
+        | annotated::Expression::Compare { .. }
+        | annotated::Expression::Logical { .. }
+        | annotated::Expression::Unary { .. }
+        | annotated::Expression::Convert { .. }
+        | annotated::Expression::Field { .. }
+        | annotated::Expression::Index { .. }
+        | annotated::Expression::Len { .. } => {
+            warn!(
+                target: "argon::lint",
+                "unused value: the result of {:?} is discarded", expression.item
+            );
+        }
+
+        _ => {}
+    }
 }
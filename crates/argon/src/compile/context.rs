@@ -0,0 +1,177 @@
+use super::abi::returns_through_pointer;
+use super::function::wasm_types;
+use super::instances::Instances;
+use crate::{annotated, CompileOptions};
+use parity_wasm::elements::ValueType;
+
+// Tracks the wasm labels (`block`, `loop` and `if`) that surround the
+// instruction being compiled, so `br` can compute relative label depths.
+// Also lays out the function's wasm locals.
+crate struct FunctionContext<'a> {
+    crate function: &'a annotated::Function,
+    crate instances: &'a mut Instances,
+    crate heap: &'a mut Heap,
+    crate options: CompileOptions,
+    depth: u32,
+    loops: Vec<LoopLabels>,
+    // the wasm locals each parameter and `let` local is stored in; a tuple
+    // takes one per field
+    locals: Vec<Vec<u32>>,
+    // the types of the wasm locals declared by the function body, which
+    // come after the parameters
+    declared: Vec<ValueType>,
+    // temps that were released, which later `temps` of the same type reuse
+    free: Vec<u32>,
+    next_local: u32,
+}
+
+// The global holding the address the next struct, array or returned tuple
+// is allocated at. It's only added to the module if something is allocated.
+#[derive(Debug, Copy, Clone)]
+crate struct Heap {
+    crate global: u32,
+    crate used: bool,
+    // whether a function uses `load`, `store` or `memory.*`, which need a
+    // memory even if nothing is allocated
+    crate accessed: bool,
+}
+
+// The absolute depths of the labels a `break` and a `continue` jump to
+struct LoopLabels {
+    exit: u32,
+    next: u32,
+    // the wasm locals a `break` stores a tuple in, since a block can only
+    // produce a single value
+    result: Vec<u32>,
+}
+
+impl FunctionContext<'a> {
+    crate fn new(
+        function: &'a annotated::Function,
+        instances: &'a mut Instances,
+        heap: &'a mut Heap,
+        options: CompileOptions,
+    ) -> FunctionContext<'a> {
+        let mut cx = FunctionContext {
+            function,
+            instances,
+            heap,
+            options,
+            depth: 0,
+            loops: vec![],
+            locals: vec![],
+            declared: vec![],
+            free: vec![],
+            next_local: 0,
+        };
+
+        // the out pointer comes before the other parameters
+        if cx.out_pointer().is_some() {
+            cx.next_local = 1;
+        }
+
+        for param in &function.params {
+            let count = wasm_types(&param.node).len() as u32;
+            let locals = (cx.next_local..cx.next_local + count).collect();
+
+            cx.locals.push(locals);
+            cx.next_local += count;
+        }
+
+        for local in &function.locals {
+            let types = wasm_types(&local.clone().into_type().node);
+            let locals = cx.temps(&types);
+
+            cx.locals.push(locals);
+        }
+
+        cx
+    }
+
+    // The wasm locals declared by the function body
+    crate fn into_locals(self) -> Vec<ValueType> {
+        self.declared
+    }
+
+    crate fn wasm_locals(&self, local: u32) -> Vec<u32> {
+        self.locals[local as usize].clone()
+    }
+
+    // Wasm locals for holding onto the fields of a tuple, a struct's address,
+    // an index or a value that is used more than once. Released temps are
+    // reused before new locals are declared.
+    crate fn temps(&mut self, types: &[ValueType]) -> Vec<u32> {
+        types.iter().map(|ty| self.temp(*ty)).collect()
+    }
+
+    fn temp(&mut self, ty: ValueType) -> u32 {
+        let reused = self.free.iter().position(|local| self.local_type(*local) == ty);
+
+        if let Some(index) = reused {
+            return self.free.remove(index);
+        }
+
+        self.declared.push(ty);
+        self.next_local += 1;
+        self.next_local - 1
+    }
+
+    // Hands temps back once nothing reads them anymore, so they can be
+    // reused by later `temps`
+    crate fn release(&mut self, temps: &[u32]) {
+        self.free.extend_from_slice(temps);
+    }
+
+    fn local_type(&self, local: u32) -> ValueType {
+        let first = self.next_local - self.declared.len() as u32;
+        self.declared[(local - first) as usize]
+    }
+
+    // The parameter holding where to store the tuple this function returns
+    crate fn out_pointer(&self) -> Option<u32> {
+        if returns_through_pointer(&self.function.ret.node, self.options) {
+            Some(0)
+        } else {
+            None
+        }
+    }
+
+    crate fn push_label(&mut self) {
+        self.depth += 1;
+    }
+
+    crate fn pop_label(&mut self) {
+        self.depth -= 1;
+    }
+
+    // Call after emitting the outer `block` and the inner `loop` of a loop
+    crate fn enter_loop(&mut self, result: Vec<u32>) {
+        self.loops.push(LoopLabels {
+            exit: self.depth - 1,
+            next: self.depth,
+            result,
+        });
+    }
+
+    crate fn exit_loop(&mut self) {
+        self.loops.pop();
+    }
+
+    crate fn break_label(&self) -> u32 {
+        self.depth - self.current_loop().exit
+    }
+
+    crate fn continue_label(&self) -> u32 {
+        self.depth - self.current_loop().next
+    }
+
+    crate fn break_result(&self) -> Vec<u32> {
+        self.current_loop().result.clone()
+    }
+
+    fn current_loop(&self) -> &LoopLabels {
+        self.loops
+            .last()
+            .expect("name resolution rejects a break outside of a loop")
+    }
+}
@@ -1,23 +1,95 @@
+use super::abi::{self, returns_through_pointer};
+use super::body::compile_block;
+use super::context::FunctionContext;
+use super::function::{fields, wasm_type, wasm_types};
 use crate::annotated::Annotated;
-use crate::ir::Spanned;
-use crate::math::math_op;
-use crate::{annotated, ast, InferType, MathType, Type};
+use crate::infer::literals::integer_range;
+use crate::lexer::Token;
+use crate::math::{compare_op, convert_op, math_op};
+use crate::{
+    annotated, ast, CompareOperator, Conversion, InferType, LogicalOperator, MathOperator,
+    MathType, StructType, Type, UnaryOperator,
+};
 use parity_wasm::elements;
 use std::mem::transmute;
 
 crate fn compile_expression(
     body: &mut Vec<elements::Opcode>,
     Annotated { item, ty }: &Annotated<annotated::Expression>,
-    function: &annotated::Function,
+    cx: &mut FunctionContext<'_>,
 ) {
     match item {
         annotated::Expression::Const(constant) => body.push(compile_const(constant, ty)),
 
         annotated::Expression::VariableAccess(local) => {
-            body.push(elements::Opcode::GetLocal(*local));
+            for local in cx.wasm_locals(*local) {
+                body.push(elements::Opcode::GetLocal(local));
+            }
+        }
+
+        annotated::Expression::GlobalAccess(global) => {
+            body.push(elements::Opcode::GetGlobal(*global));
+        }
+
+        annotated::Expression::ConstAccess { .. } => {
+            panic!("consts are inlined where they're used before compiling")
         }
 
-        annotated::Expression::Apply(..) => unimplemented!(),
+        annotated::Expression::FunctionAccess { .. } => {
+            panic!("functions can only be called, so a function access must be part of an Apply")
+        }
+
+        // calls are resolved to a function in the module and the types it's
+        // instantiated with, which `Instances` turns into a wasm function index
+        annotated::Expression::Apply(box function, args) => {
+            let ret = ty.clone().into_type().node;
+
+            // the callee stores the tuple in a slot allocated for this call
+            let slot = if returns_through_pointer(&ret, cx.options) {
+                let slot = cx.temps(&[elements::ValueType::I32])[0];
+
+                allocate(body, abi::tuple_size(&wasm_types(&ret)), cx);
+                body.push(elements::Opcode::TeeLocal(slot));
+
+                Some(slot)
+            } else {
+                None
+            };
+
+            for arg in args {
+                compile_expression(body, arg, cx);
+            }
+
+            match &function.item {
+                annotated::Expression::FunctionAccess {
+                    function,
+                    type_args,
+                } => {
+                    let type_args = type_args
+                        .iter()
+                        .map(|ty| ty.clone().into_type().node)
+                        .collect();
+
+                    body.push(elements::Opcode::Call(cx.instances.index(*function, type_args)))
+                }
+
+                other => panic!("cannot call {:?}; only functions can be called", other),
+            }
+
+            // load the tuple back out of the slot, which is then only needed
+            // again if the call allocated something after it
+            if let Some(slot) = slot {
+                let types = wasm_types(&ret);
+
+                for (field, offset) in types.iter().zip(abi::layout(&types)) {
+                    body.push(elements::Opcode::GetLocal(slot));
+                    body.push(abi::load(*field, offset));
+                }
+
+                free_if_last(body, slot, abi::tuple_size(&types), cx);
+                cx.release(&[slot]);
+            }
+        }
 
         annotated::Expression::Binary {
             operator,
@@ -29,32 +101,985 @@ crate fn compile_expression(
                 "inference bug: the left and right side of a binary operator must be the same type, got {:?} + {:?}", lhs, rhs
             );
 
-            compile_expression(body, lhs, function);
-            compile_expression(body, rhs, function);
+            compile_expression(body, lhs, cx);
+            compile_expression(body, rhs, cx);
             body.push(math_op((*operator).node, lhs.ty.as_math()));
         }
+
+        annotated::Expression::Compare {
+            operator,
+            box lhs,
+            box rhs,
+        } => {
+            debug_assert!(
+                lhs.ty.is_same_type(&rhs.ty),
+                "inference bug: the left and right side of a comparison must be the same type, got {:?} {:?} {:?}", lhs, operator, rhs
+            );
+
+            let fields = fields(&lhs.ty.clone().into_type().node);
+
+            compile_expression(body, lhs, cx);
+
+            if fields.len() == 1 {
+                compile_expression(body, rhs, cx);
+                body.push(compare_op((*operator).node, comparison_type(&fields[0])));
+            } else {
+                compare_tuples(body, operator.node, &fields, rhs, cx);
+            }
+        }
+
+        // `&&` and `||` only evaluate their right hand side if they have to
+        annotated::Expression::Logical {
+            operator,
+            box lhs,
+            box rhs,
+        } => {
+            compile_expression(body, lhs, cx);
+            body.push(elements::Opcode::If(elements::BlockType::Value(
+                elements::ValueType::I32,
+            )));
+            cx.push_label();
+
+            match operator.node {
+                LogicalOperator::And => {
+                    compile_expression(body, rhs, cx);
+                    body.push(elements::Opcode::Else);
+                    body.push(elements::Opcode::I32Const(0));
+                }
+                LogicalOperator::Or => {
+                    body.push(elements::Opcode::I32Const(1));
+                    body.push(elements::Opcode::Else);
+                    compile_expression(body, rhs, cx);
+                }
+            }
+
+            cx.pop_label();
+            body.push(elements::Opcode::End);
+        }
+
+        annotated::Expression::Unary { operator, box expr } => match operator.node {
+            UnaryOperator::Not => {
+                compile_expression(body, expr, cx);
+                body.push(elements::Opcode::I32Eqz);
+            }
+
+            // wasm has no integer negation, so `-x` is `0 - x`
+            UnaryOperator::Negate => match ty.as_math() {
+                MathType::F32 => {
+                    compile_expression(body, expr, cx);
+                    body.push(elements::Opcode::F32Neg);
+                }
+                MathType::F64 => {
+                    compile_expression(body, expr, cx);
+                    body.push(elements::Opcode::F64Neg);
+                }
+                math => {
+                    body.push(integer_const(0, math));
+                    compile_expression(body, expr, cx);
+                    body.push(math_op(MathOperator::Sub, math));
+                }
+            },
+
+            // `~x` is `x ^ -1`
+            UnaryOperator::BitNot => {
+                let math = ty.as_math();
+
+                compile_expression(body, expr, cx);
+                body.push(integer_const(-1, math));
+                body.push(math_op(MathOperator::Xor, math));
+            }
+        },
+
+        annotated::Expression::Convert {
+            box expr,
+            conversion,
+        } => {
+            compile_expression(body, expr, cx);
+
+            let (from, to) = (expr.ty.as_math(), ty.as_math());
+
+            match conversion {
+                Conversion::Saturating if is_float(from) && !is_float(to) => {
+                    saturating_truncate(body, from, to, cx)
+                }
+                _ => {
+                    if let Some(op) = convert_op(from, to) {
+                        body.push(op);
+                    }
+                }
+            }
+        }
+
+        annotated::Expression::Tuple(fields) => {
+            for field in fields {
+                compile_expression(body, field, cx);
+            }
+        }
+
+        annotated::Expression::Let { local, box value, .. }
+        | annotated::Expression::Assign { local, box value, .. } => {
+            compile_expression(body, value, cx);
+            set_locals(body, &cx.wasm_locals(*local));
+        }
+
+        annotated::Expression::GlobalAssign {
+            global, box value, ..
+        } => {
+            compile_expression(body, value, cx);
+            body.push(elements::Opcode::SetGlobal(*global));
+        }
+
+        annotated::Expression::Destructure {
+            locals,
+            box value,
+            ..
+        } => {
+            compile_expression(body, value, cx);
+
+            let locals: Vec<u32> = locals
+                .iter()
+                .flat_map(|local| cx.wasm_locals(*local))
+                .collect();
+
+            set_locals(body, &locals);
+        }
+
+        // a block can only produce a single value, so both branches store a
+        // tuple in the same locals, which are read once the `if` is done
+        annotated::Expression::If {
+            box condition,
+            then_block,
+            else_block,
+        } => {
+            let result = spilled_result(ty, cx);
+
+            compile_expression(body, condition, cx);
+            body.push(elements::Opcode::If(block_type(ty)));
+            cx.push_label();
+            compile_block(body, then_block, cx);
+            set_locals(body, &result);
+
+            match else_block {
+                Some(else_block) => {
+                    body.push(elements::Opcode::Else);
+                    compile_block(body, else_block, cx);
+                    set_locals(body, &result);
+                }
+
+                // the `if` is void, so discard whatever the block produced
+                None => drop_value(body, &then_block.ty),
+            }
+
+            cx.pop_label();
+            body.push(elements::Opcode::End);
+            get_locals(body, &result);
+            cx.release(&result);
+
+            // both branches diverge, but validation doesn't know that
+            if ty.is_never() {
+                body.push(elements::Opcode::Unreachable);
+            }
+        }
+
+        // block $exit
+        //   loop $next
+        //     br_if $exit (i32.eqz condition)
+        //     body
+        //     br $next
+        //   end
+        // end
+        annotated::Expression::While {
+            box condition,
+            body: loop_body,
+        } => {
+            enter_loop(body, elements::BlockType::NoResult, vec![], cx);
+
+            compile_expression(body, condition, cx);
+            body.push(elements::Opcode::I32Eqz);
+            body.push(elements::Opcode::BrIf(cx.break_label()));
+
+            compile_loop_body(body, loop_body, cx);
+            exit_loop(body, false, cx);
+        }
+
+        // block $exit (result T)
+        //   loop $next
+        //     body
+        //     br $next
+        //   end
+        //   unreachable
+        // end
+        annotated::Expression::Loop { body: loop_body } => {
+            let result = spilled_result(ty, cx);
+
+            enter_loop(body, block_type(ty), result.clone(), cx);
+            compile_loop_body(body, loop_body, cx);
+            exit_loop(body, produces_value(ty) && result.is_empty(), cx);
+            get_locals(body, &result);
+            cx.release(&result);
+
+            // a loop without a `break` never finishes
+            if ty.is_never() {
+                body.push(elements::Opcode::Unreachable);
+            }
+        }
+
+        annotated::Expression::Break { value, .. } => {
+            if let Some(box value) = value {
+                compile_expression(body, value, cx);
+                set_locals(body, &cx.break_result());
+            }
+
+            body.push(elements::Opcode::Br(cx.break_label()));
+        }
+
+        annotated::Expression::Continue => {
+            body.push(elements::Opcode::Br(cx.continue_label()));
+        }
+
+        annotated::Expression::Return { value, .. } => {
+            if let Some(box value) = value {
+                compile_expression(body, value, cx);
+            }
+
+            if cx.out_pointer().is_some() {
+                store_results(body, cx);
+            }
+
+            body.push(elements::Opcode::Return);
+        }
+
+        annotated::Expression::Load {
+            box address,
+            offset,
+            align,
+        } => {
+            cx.heap.accessed = true;
+            compile_expression(body, address, cx);
+            let loaded = wasm_type(&Type::Math(ty.as_math())).unwrap();
+            body.push(abi::load_aligned(loaded, *align, *offset));
+        }
+
+        annotated::Expression::Store {
+            box address,
+            box value,
+            offset,
+            align,
+            ..
+        } => {
+            cx.heap.accessed = true;
+            compile_expression(body, address, cx);
+            compile_expression(body, value, cx);
+            let stored = wasm_type(&Type::Math(value.ty.as_math())).unwrap();
+            body.push(abi::store_aligned(stored, *align, *offset));
+        }
+
+        // a module has at most one memory, which is memory 0
+        annotated::Expression::MemorySize => {
+            cx.heap.accessed = true;
+            body.push(elements::Opcode::CurrentMemory(0))
+        }
+
+        annotated::Expression::MemoryGrow { box pages } => {
+            cx.heap.accessed = true;
+            compile_expression(body, pages, cx);
+            body.push(elements::Opcode::GrowMemory(0));
+        }
+
+        // the fields are stored in the order they're written, so their
+        // side effects happen in that order too
+        annotated::Expression::Construct {
+            ty: constructed,
+            fields,
+        } => {
+            let layout = abi::struct_layout(struct_type(&constructed.node));
+            let address = cx.temps(&[elements::ValueType::I32])[0];
+
+            allocate(body, layout.size, cx);
+            body.push(elements::Opcode::SetLocal(address));
+
+            for (field, value) in fields {
+                let field_type = wasm_type(&value.ty.clone().into_type().node)
+                    .expect("fields are numbers or booleans");
+
+                body.push(elements::Opcode::GetLocal(address));
+                compile_expression(body, value, cx);
+                body.push(abi::store(field_type, layout.offsets[*field as usize]));
+            }
+
+            body.push(elements::Opcode::GetLocal(address));
+            cx.release(&[address]);
+        }
+
+        annotated::Expression::Field { box base, name } => {
+            let offset = field_offset(base, *name);
+            let field_type = wasm_type(&ty.clone().into_type().node)
+                .expect("fields are numbers or booleans");
+
+            compile_expression(body, base, cx);
+            body.push(abi::load(field_type, offset));
+        }
+
+        annotated::Expression::AssignField {
+            box base,
+            name,
+            operator,
+            box value,
+        } => {
+            let offset = field_offset(base, *name);
+            let field_type = wasm_type(&value.ty.clone().into_type().node)
+                .expect("fields are numbers or booleans");
+
+            compile_expression(body, base, cx);
+
+            match operator {
+                None => compile_expression(body, value, cx),
+                // `p.x += v` loads the field through the same address it
+                // stores it to
+                Some(operator) => {
+                    let address = cx.temps(&[elements::ValueType::I32])[0];
+
+                    body.push(elements::Opcode::TeeLocal(address));
+                    body.push(elements::Opcode::GetLocal(address));
+                    body.push(abi::load(field_type, offset));
+                    compile_expression(body, value, cx);
+                    body.push(math_op(operator.node, value.ty.as_math()));
+                    cx.release(&[address]);
+                }
+            }
+
+            body.push(abi::store(field_type, offset));
+        }
+
+        // the address is the struct
+        annotated::Expression::StructAt { box address } => {
+            compile_expression(body, address, cx);
+        }
+
+        // the elements are stored in the order they're written, so their
+        // side effects happen in that order too
+        annotated::Expression::Array {
+            elements: values, ..
+        } => {
+            let array = ty.clone().into_type().node;
+            let element = array.element().expect("inference bug: an array literal isn't an array");
+            let size = abi::element_size(element);
+            let address = cx.temps(&[elements::ValueType::I32])[0];
+
+            allocate(body, size * values.len() as u32, cx);
+            body.push(elements::Opcode::SetLocal(address));
+
+            for (i, value) in values.iter().enumerate() {
+                body.push(elements::Opcode::GetLocal(address));
+                compile_expression(body, value, cx);
+                body.push(abi::store(abi::element_type(element), i as u32 * size));
+            }
+
+            body.push(elements::Opcode::GetLocal(address));
+        }
+
+        // the value is computed once and stored in every element
+        //
+        // block $exit
+        //   loop $next
+        //     br_if $exit (i >= len)
+        //     store (address + i * size) value
+        //     i += 1
+        //     br $next
+        //   end
+        // end
+        annotated::Expression::RepeatArray {
+            box value, len, ..
+        } => {
+            let value_ty = value.ty.clone().into_type().node;
+            let element = abi::element_type(&value_ty);
+            let size = abi::element_size(&value_ty);
+            let temps = cx.temps(&[element, elements::ValueType::I32, elements::ValueType::I32]);
+            let (value_local, address, index) = (temps[0], temps[1], temps[2]);
+
+            compile_expression(body, value, cx);
+            body.push(elements::Opcode::SetLocal(value_local));
+
+            allocate(body, size * len, cx);
+            body.push(elements::Opcode::SetLocal(address));
+
+            body.push(elements::Opcode::I32Const(0));
+            body.push(elements::Opcode::SetLocal(index));
+
+            body.push(elements::Opcode::Block(elements::BlockType::NoResult));
+            cx.push_label();
+            body.push(elements::Opcode::Loop(elements::BlockType::NoResult));
+            cx.push_label();
+
+            body.push(elements::Opcode::GetLocal(index));
+            body.push(elements::Opcode::I32Const(*len as i32));
+            body.push(elements::Opcode::I32GeU);
+            body.push(elements::Opcode::BrIf(1));
+
+            body.push(elements::Opcode::GetLocal(address));
+            element_offset(body, index, size);
+            body.push(elements::Opcode::GetLocal(value_local));
+            body.push(abi::store(element, 0));
+
+            increment(body, index);
+            body.push(elements::Opcode::Br(0));
+
+            body.push(elements::Opcode::End);
+            cx.pop_label();
+            body.push(elements::Opcode::End);
+            cx.pop_label();
+
+            body.push(elements::Opcode::GetLocal(address));
+        }
+
+        annotated::Expression::Index {
+            box base,
+            box index,
+            ..
+        } => {
+            let element = abi::element_type(&ty.clone().into_type().node);
+
+            element_address(body, base, index, cx);
+            body.push(abi::load(element, 0));
+        }
+
+        annotated::Expression::AssignIndex {
+            box base,
+            box index,
+            operator,
+            box value,
+            ..
+        } => {
+            let element = abi::element_type(&value.ty.clone().into_type().node);
+
+            element_address(body, base, index, cx);
+
+            match operator {
+                None => compile_expression(body, value, cx),
+                // `a[i] += v` loads the element through the same address it
+                // stores it to
+                Some(operator) => {
+                    let address = cx.temps(&[elements::ValueType::I32])[0];
+
+                    body.push(elements::Opcode::TeeLocal(address));
+                    body.push(elements::Opcode::GetLocal(address));
+                    body.push(abi::load(element, 0));
+                    compile_expression(body, value, cx);
+                    body.push(math_op(operator.node, value.ty.as_math()));
+                }
+            }
+
+            body.push(abi::store(element, 0));
+        }
+
+        // a slice of an array points at its first element
+        annotated::Expression::SliceOf { box array, .. } => {
+            compile_expression(body, array, cx);
+            let len = sequence_length(body, &array.ty.clone().into_type().node, cx);
+
+            body.push(len);
+        }
+
+        annotated::Expression::Len { box sequence, .. } => {
+            compile_expression(body, sequence, cx);
+            let len = sequence_length(body, &sequence.ty.clone().into_type().node, cx);
+
+            body.push(elements::Opcode::Drop);
+            body.push(len.clone());
+            release_length(&len, cx);
+        }
+
+        // the element is loaded into the local before the body runs, so a
+        // `continue` moves on to the next one
+        //
+        // block $exit
+        //   loop $next
+        //     br_if $exit (i >= len)
+        //     local = (load (pointer + i * size))
+        //     i += 1
+        //     body
+        //     br $next
+        //   end
+        // end
+        annotated::Expression::For {
+            local,
+            box sequence,
+            element,
+            body: loop_body,
+            ..
+        } => {
+            let element = element.clone().into_type().node;
+            let size = abi::element_size(&element);
+
+            compile_expression(body, sequence, cx);
+            let len = sequence_length(body, &sequence.ty.clone().into_type().node, cx);
+
+            let temps = cx.temps(&[elements::ValueType::I32, elements::ValueType::I32]);
+            let (pointer, index) = (temps[0], temps[1]);
+
+            body.push(elements::Opcode::SetLocal(pointer));
+            body.push(elements::Opcode::I32Const(0));
+            body.push(elements::Opcode::SetLocal(index));
+
+            enter_loop(body, elements::BlockType::NoResult, vec![], cx);
+
+            body.push(elements::Opcode::GetLocal(index));
+            body.push(len.clone());
+            body.push(elements::Opcode::I32GeU);
+            body.push(elements::Opcode::BrIf(cx.break_label()));
+
+            body.push(elements::Opcode::GetLocal(pointer));
+            element_offset(body, index, size);
+            body.push(abi::load(abi::element_type(&element), 0));
+            set_locals(body, &cx.wasm_locals(*local));
+
+            increment(body, index);
+
+            compile_loop_body(body, loop_body, cx);
+            exit_loop(body, false, cx);
+
+            cx.release(&temps);
+            release_length(&len, cx);
+        }
     }
 }
 
-fn compile_const(constant: &ast::ConstExpression, ty: &InferType) -> elements::Opcode {
-    let ty = ty.clone().into_type();
+// Leaves the address of an element on the stack. The index is checked
+// against the length first, unless bounds checks are off.
+fn element_address(
+    body: &mut Vec<elements::Opcode>,
+    base: &Annotated<annotated::Expression>,
+    index: &Annotated<annotated::Expression>,
+    cx: &mut FunctionContext<'_>,
+) {
+    let sequence = base.ty.clone().into_type().node;
+    let element = sequence
+        .element()
+        .expect("inference bug: only arrays and slices can be indexed");
+
+    compile_expression(body, base, cx);
+    let len = sequence_length(body, &sequence, cx);
+    compile_expression(body, index, cx);
+
+    let index = cx.temps(&[elements::ValueType::I32])[0];
+    body.push(elements::Opcode::SetLocal(index));
+
+    if cx.options.bounds_checks {
+        body.push(elements::Opcode::GetLocal(index));
+        body.push(len.clone());
+        body.push(elements::Opcode::I32GeU);
+        trap_if(body, cx);
+    }
+
+    element_offset(body, index, abi::element_size(element));
+
+    cx.release(&[index]);
+    release_length(&len, cx);
+}
 
+// With a pointer to the first element on the stack, leaves the address of
+// the element at `index` instead
+fn element_offset(body: &mut Vec<elements::Opcode>, index: u32, size: u32) {
+    body.push(elements::Opcode::GetLocal(index));
+    body.push(elements::Opcode::I32Const(size as i32));
+    body.push(elements::Opcode::I32Mul);
+    body.push(elements::Opcode::I32Add);
+}
+
+fn increment(body: &mut Vec<elements::Opcode>, local: u32) {
+    body.push(elements::Opcode::GetLocal(local));
+    body.push(elements::Opcode::I32Const(1));
+    body.push(elements::Opcode::I32Add);
+    body.push(elements::Opcode::SetLocal(local));
+}
+
+// With an array or a slice on the stack, returns the instruction that
+// pushes its length. An array's length is part of its type, and a slice's
+// is popped into a local, which leaves its pointer on the stack either way.
+fn sequence_length(
+    body: &mut Vec<elements::Opcode>,
+    sequence: &Type,
+    cx: &mut FunctionContext<'_>,
+) -> elements::Opcode {
+    match sequence {
+        Type::Array(_, len) => elements::Opcode::I32Const(*len as i32),
+        Type::Slice(..) => {
+            let len = cx.temps(&[elements::ValueType::I32])[0];
+            body.push(elements::Opcode::SetLocal(len));
+            elements::Opcode::GetLocal(len)
+        }
+        other => panic!("inference bug: {:?} isn't an array or a slice", other),
+    }
+}
+
+// Hands back the local `sequence_length` kept a slice's length in, once the
+// instruction it returned has been pushed for the last time
+fn release_length(len: &elements::Opcode, cx: &mut FunctionContext<'_>) {
+    if let elements::Opcode::GetLocal(local) = len {
+        cx.release(&[*local]);
+    }
+}
+
+// The offset of the field `name` in the struct `base`
+fn field_offset(base: &Annotated<annotated::Expression>, name: Token) -> u32 {
+    let ty = base.ty.clone().into_type().node;
+    let declared = struct_type(&ty);
+    let field = declared
+        .field(name.to_ident())
+        .expect("inference bug: the struct has no such field");
+
+    abi::struct_layout(declared).offsets[field as usize]
+}
+
+fn struct_type(ty: &Type) -> &StructType {
     match ty {
-        Spanned {
-            node: Type::Math(math),
-            ..
-        } => match math {
-            MathType::I32 => elements::Opcode::I32Const(constant.to_i32()),
-            MathType::I64 => elements::Opcode::I64Const(constant.to_i32() as i64),
-            MathType::U32 => elements::Opcode::I32Const(unsafe { transmute(constant.to_u32()) }),
-            MathType::U64 => {
-                elements::Opcode::I64Const(unsafe { transmute(constant.to_i32() as i64) })
+        Type::Struct(declared) => &**declared,
+        other => panic!("inference bug: {:?} isn't a struct", other),
+    }
+}
+
+fn enter_loop(
+    body: &mut Vec<elements::Opcode>,
+    block_type: elements::BlockType,
+    result: Vec<u32>,
+    cx: &mut FunctionContext<'_>,
+) {
+    body.push(elements::Opcode::Block(block_type));
+    cx.push_label();
+    body.push(elements::Opcode::Loop(elements::BlockType::NoResult));
+    cx.push_label();
+    cx.enter_loop(result);
+}
+
+fn compile_loop_body(
+    body: &mut Vec<elements::Opcode>,
+    loop_body: &Annotated<annotated::Block>,
+    cx: &mut FunctionContext<'_>,
+) {
+    compile_block(body, loop_body, cx);
+    drop_value(body, &loop_body.ty);
+
+    body.push(elements::Opcode::Br(cx.continue_label()));
+}
+
+fn exit_loop(body: &mut Vec<elements::Opcode>, produces_value: bool, cx: &mut FunctionContext<'_>) {
+    cx.exit_loop();
+
+    // end the `loop`
+    body.push(elements::Opcode::End);
+    cx.pop_label();
+
+    // the block can only be left with a `br $exit`, but validation still
+    // needs a value at its end
+    if produces_value {
+        body.push(elements::Opcode::Unreachable);
+    }
+
+    body.push(elements::Opcode::End);
+    cx.pop_label();
+}
+
+// Whether an expression of this type leaves a value on the stack
+crate fn produces_value(ty: &InferType) -> bool {
+    !wasm_types(&ty.clone().into_type().node).is_empty()
+}
+
+// Discards the value an expression of this type left on the stack, one
+// field at a time
+crate fn drop_value(body: &mut Vec<elements::Opcode>, ty: &InferType) {
+    for _ in wasm_types(&ty.clone().into_type().node) {
+        body.push(elements::Opcode::Drop);
+    }
+}
+
+// Moves the tuple the function returns from the stack to its out pointer
+crate fn store_results(body: &mut Vec<elements::Opcode>, cx: &mut FunctionContext<'_>) {
+    let out = cx.out_pointer().expect("only tuples are returned through a pointer");
+    let types = wasm_types(&cx.function.ret.node);
+    let temps = cx.temps(&types);
+
+    set_locals(body, &temps);
+
+    for ((field, temp), offset) in types.iter().zip(&temps).zip(abi::layout(&types)) {
+        body.push(elements::Opcode::GetLocal(out));
+        body.push(elements::Opcode::GetLocal(*temp));
+        body.push(abi::store(*field, offset));
+    }
+
+    cx.release(&temps);
+}
+
+// Leaves the address of `size` newly allocated bytes on the stack. This is a
+// bump allocator: structs, arrays and returned tuples are allocated one
+// after another from the heap global, and only a returned tuple's slot is
+// ever handed back, so a loop that allocates keeps using more memory.
+// Memory grows when the heap runs past its end, and the allocation traps if
+// it can't grow or the heap would wrap around the end of the address space.
+// Modules whose memory has a maximum are rejected, since the heap would
+// trap once it reached it. Pages a function adds with `memory.grow` can be
+// handed out by later allocations, so a module that allocates shouldn't use
+// them for itself.
+fn allocate(body: &mut Vec<elements::Opcode>, size: u32, cx: &mut FunctionContext<'_>) {
+    cx.heap.used = true;
+    let heap = cx.heap.global;
+    let size = allocation_size(size);
+
+    body.push(elements::Opcode::GetGlobal(heap));
+
+    body.push(elements::Opcode::GetGlobal(heap));
+    body.push(elements::Opcode::I32Const(size as i32));
+    body.push(elements::Opcode::I32Add);
+    body.push(elements::Opcode::SetGlobal(heap));
+
+    // the end of the allocation wrapped around to a low address
+    body.push(elements::Opcode::GetGlobal(heap));
+    body.push(elements::Opcode::I32Const(size as i32));
+    body.push(elements::Opcode::I32LtU);
+    trap_if(body, cx);
+
+    body.push(elements::Opcode::GetGlobal(heap));
+    memory_bytes(body);
+    body.push(elements::Opcode::I32GtU);
+    body.push(elements::Opcode::If(elements::BlockType::NoResult));
+    cx.push_label();
+
+    // grow by enough pages to fit the end of the heap, rounding up without
+    // overflowing
+    body.push(elements::Opcode::GetGlobal(heap));
+    memory_bytes(body);
+    body.push(elements::Opcode::I32Sub);
+    body.push(elements::Opcode::I32Const(1));
+    body.push(elements::Opcode::I32Sub);
+    body.push(elements::Opcode::I32Const(16));
+    body.push(elements::Opcode::I32ShrU);
+    body.push(elements::Opcode::I32Const(1));
+    body.push(elements::Opcode::I32Add);
+    body.push(elements::Opcode::GrowMemory(0));
+
+    // `memory.grow` returns -1 when memory can't grow
+    body.push(elements::Opcode::I32Const(-1));
+    body.push(elements::Opcode::I32Eq);
+    trap_if(body, cx);
+
+    body.push(elements::Opcode::End);
+    cx.pop_label();
+}
+
+// Hands the `size` bytes at the address in `local` back to the heap if
+// they're the last thing allocated, so a loop calling a function that
+// returns a tuple doesn't keep allocating
+fn free_if_last(
+    body: &mut Vec<elements::Opcode>,
+    local: u32,
+    size: u32,
+    cx: &mut FunctionContext<'_>,
+) {
+    let heap = cx.heap.global;
+
+    body.push(elements::Opcode::GetGlobal(heap));
+    body.push(elements::Opcode::GetLocal(local));
+    body.push(elements::Opcode::I32Const(allocation_size(size) as i32));
+    body.push(elements::Opcode::I32Add);
+    body.push(elements::Opcode::I32Eq);
+    body.push(elements::Opcode::If(elements::BlockType::NoResult));
+    cx.push_label();
+
+    body.push(elements::Opcode::GetLocal(local));
+    body.push(elements::Opcode::SetGlobal(heap));
+
+    body.push(elements::Opcode::End);
+    cx.pop_label();
+}
+
+// Traps if the condition on the stack is true
+fn trap_if(body: &mut Vec<elements::Opcode>, cx: &mut FunctionContext<'_>) {
+    body.push(elements::Opcode::If(elements::BlockType::NoResult));
+    cx.push_label();
+    body.push(elements::Opcode::Unreachable);
+    body.push(elements::Opcode::End);
+    cx.pop_label();
+}
+
+// every allocation starts at a multiple of 8, which is enough for any field
+fn allocation_size(size: u32) -> u32 {
+    (size + 7) / 8 * 8
+}
+
+// The size of memory in bytes; a page is 64KiB
+fn memory_bytes(body: &mut Vec<elements::Opcode>) {
+    body.push(elements::Opcode::CurrentMemory(0));
+    body.push(elements::Opcode::I32Const(16));
+    body.push(elements::Opcode::I32Shl);
+}
+
+// Pops a value off the stack into `locals`, last field first
+fn set_locals(body: &mut Vec<elements::Opcode>, locals: &[u32]) {
+    for local in locals.iter().rev() {
+        body.push(elements::Opcode::SetLocal(*local));
+    }
+}
+
+fn get_locals(body: &mut Vec<elements::Opcode>, locals: &[u32]) {
+    for local in locals {
+        body.push(elements::Opcode::GetLocal(*local));
+    }
+}
+
+// The locals an `if` or `loop` producing a tuple stores it in, or none if
+// the block can produce the value itself
+fn spilled_result(ty: &InferType, cx: &mut FunctionContext<'_>) -> Vec<u32> {
+    let types = wasm_types(&ty.clone().into_type().node);
+
+    if types.len() > 1 {
+        cx.temps(&types)
+    } else {
+        vec![]
+    }
+}
+
+// Tuples are equal if all of their fields are. The left side is already on
+// the stack.
+fn compare_tuples(
+    body: &mut Vec<elements::Opcode>,
+    operator: CompareOperator,
+    fields: &[Type],
+    rhs: &Annotated<annotated::Expression>,
+    cx: &mut FunctionContext<'_>,
+) {
+    let types: Vec<_> = fields
+        .iter()
+        .map(|field| wasm_type(field).expect("fields are never void"))
+        .collect();
+
+    let lhs = cx.temps(&types);
+    set_locals(body, &lhs);
+
+    compile_expression(body, rhs, cx);
+    let rhs = cx.temps(&types);
+    set_locals(body, &rhs);
+
+    for (i, field) in fields.iter().enumerate() {
+        body.push(elements::Opcode::GetLocal(lhs[i]));
+        body.push(elements::Opcode::GetLocal(rhs[i]));
+        body.push(compare_op(operator, comparison_type(field)));
+
+        if i != 0 {
+            match operator {
+                CompareOperator::Equal => body.push(elements::Opcode::I32And),
+                CompareOperator::NotEqual => body.push(elements::Opcode::I32Or),
+                other => panic!("inference bug: {:?} can't compare tuples", other),
             }
+        }
+    }
+
+    cx.release(&lhs);
+    cx.release(&rhs);
+}
+
+// `from as saturating to`, where `from` is a float and `to` an integer.
+// Truncating traps on NaN and values outside of `to`'s range, so those are
+// replaced with 0 first, and `select` then picks `to`'s minimum or maximum
+// for them instead of the truncated value. NaN is neither below nor above
+// the range, so it stays 0.
+fn saturating_truncate(
+    body: &mut Vec<elements::Opcode>,
+    from: MathType,
+    to: MathType,
+    cx: &mut FunctionContext<'_>,
+) {
+    let float = wasm_type(&Type::Math(from)).unwrap();
+    let value = cx.temps(&[float])[0];
+    let (min, max) = integer_range(to);
+
+    // both bounds are powers of two (or 0), so floats hold them exactly
+    let lower = float_const(min as f64, from);
+    let upper = float_const((max + 1) as f64, from);
+
+    body.push(elements::Opcode::SetLocal(value));
+    body.push(integer_const(max as i64, to));
+    body.push(integer_const(min as i64, to));
+
+    body.push(elements::Opcode::GetLocal(value));
+    body.push(float_const(0.0, from));
+    body.push(elements::Opcode::GetLocal(value));
+    body.push(lower.clone());
+    body.push(compare_op(CompareOperator::GreaterEqual, from));
+    body.push(elements::Opcode::GetLocal(value));
+    body.push(upper.clone());
+    body.push(compare_op(CompareOperator::Less, from));
+    body.push(elements::Opcode::I32And);
+    body.push(elements::Opcode::Select);
+    body.push(convert_op(from, to).unwrap());
+
+    body.push(elements::Opcode::GetLocal(value));
+    body.push(lower);
+    body.push(compare_op(CompareOperator::Less, from));
+    body.push(elements::Opcode::Select);
+
+    body.push(elements::Opcode::GetLocal(value));
+    body.push(upper);
+    body.push(compare_op(CompareOperator::GreaterEqual, from));
+    body.push(elements::Opcode::Select);
+
+    cx.release(&[value]);
+}
+
+fn is_float(ty: MathType) -> bool {
+    ty == MathType::F32 || ty == MathType::F64
+}
+
+fn float_const(value: f64, ty: MathType) -> elements::Opcode {
+    match ty {
+        MathType::F32 => elements::Opcode::F32Const((value as f32).to_bits()),
+        MathType::F64 => elements::Opcode::F64Const(value.to_bits()),
+        _ => panic!("{:?} is not a float type", ty),
+    }
+}
+
+fn integer_const(value: i64, ty: MathType) -> elements::Opcode {
+    match ty {
+        MathType::I32 | MathType::U32 => elements::Opcode::I32Const(value as i32),
+        MathType::I64 | MathType::U64 => elements::Opcode::I64Const(value),
+        MathType::F32 | MathType::F64 => panic!("{:?} is not an integer type", ty),
+    }
+}
+
+// booleans are represented as i32s, so they compare like unsigned
+// integers, and structs and arrays are equal if they're at the same address
+fn comparison_type(ty: &Type) -> MathType {
+    match ty.representation() {
+        Type::Bool | Type::Struct(..) | Type::Array(..) => MathType::U32,
+        Type::Math(math) => *math,
+        other => panic!("Cannot compare values of type {:?}", other),
+    }
+}
+
+// A block producing a tuple leaves it in locals instead, so it produces
+// nothing itself
+fn block_type(ty: &InferType) -> elements::BlockType {
+    let types = wasm_types(&ty.clone().into_type().node);
+
+    if types.len() == 1 {
+        elements::BlockType::Value(types[0])
+    } else {
+        elements::BlockType::NoResult
+    }
+}
+
+crate fn compile_const(constant: &ast::ConstExpression, ty: &InferType) -> elements::Opcode {
+    let ty = ty.clone().into_type();
+
+    match ty.node.representation() {
+        Type::Math(math) => match math {
+            MathType::I32 | MathType::U32 => elements::Opcode::I32Const(constant.to_i32_bits()),
+            MathType::I64 | MathType::U64 => elements::Opcode::I64Const(constant.to_i64_bits()),
 
             MathType::F32 => elements::Opcode::F32Const(unsafe { transmute(constant.to_f32()) }),
             MathType::F64 => elements::Opcode::F64Const(unsafe { transmute(constant.to_f64()) }),
         },
 
+        Type::Bool => elements::Opcode::I32Const(constant.to_bool() as i32),
+
         other => panic!(
             "constant {:?} with type {:?} should have been eliminated by type inference",
             constant, other
@@ -1,34 +1,74 @@
+use super::abi::returns_through_pointer;
 use super::body::compile_body;
-use crate::{annotated, MathType, Type};
+use super::context::Heap;
+use super::instances::Instances;
+use crate::{annotated, CompileOptions, MathType, Type};
 use parity_wasm::{builder, elements};
 
 crate fn compile_function(
     function: builder::FunctionBuilder,
     input: &annotated::Function,
+    instances: &mut Instances,
+    heap: &mut Heap,
+    options: CompileOptions,
 ) -> builder::FunctionDefinition {
+    let (instructions, locals) = compile_body(&input.body, input, instances, heap, options);
+
     let mut signature = function.signature();
 
-    for ty in input.params.iter() {
-        signature = signature.with_param(parameter_type(&ty.node))
+    // the builder's function types have at most one result, so a tuple or
+    // a slice returned as multiple values gets its results when the
+    // module's types are encoded
+    match &input.ret.node {
+        ret if returns_through_pointer(ret, options) => {
+            signature = signature.with_param(elements::ValueType::I32);
+        }
+        Type::Tuple(..) | Type::Slice(..) => {}
+        ret => signature = signature.with_return_type(wasm_type(ret)),
     }
 
-    signature = signature.with_return_type(wasm_type(&input.ret.node));
+    // a tuple parameter is passed as one parameter per field
+    for ty in input.params.iter() {
+        for param in wasm_types(&ty.node) {
+            signature = signature.with_param(param);
+        }
+    }
 
-    let instructions = compile_body(&input.body, input);
+    let locals = locals
+        .into_iter()
+        .map(|ty| elements::Local::new(1, ty))
+        .collect();
 
     signature
         .build()
         .body()
+        .with_locals(locals)
         .with_opcodes(elements::Opcodes::new(instructions))
         .build()
         .build()
 }
 
-fn parameter_type(input: &Type) -> elements::ValueType {
-    wasm_type(input).expect("void is not allowed as a parameter type")
+// The types a value is made of at runtime, in order. Tuples are flattened,
+// so `((i32, f64), bool)` is made of an i32, an f64 and a bool, and `void`
+// isn't made of anything. A slice is made of a pointer to its first element
+// and its length.
+crate fn fields(input: &Type) -> Vec<Type> {
+    match input {
+        Type::Tuple(fields) => fields.iter().flat_map(|field| self::fields(field)).collect(),
+        Type::Slice(..) => vec![Type::u32(), Type::u32()],
+        Type::Void | Type::Never => vec![],
+        other => vec![other.clone()],
+    }
 }
 
-fn wasm_type(input: &Type) -> Option<elements::ValueType> {
+crate fn wasm_types(input: &Type) -> Vec<elements::ValueType> {
+    fields(input)
+        .iter()
+        .map(|field| wasm_type(field).expect("fields are never void"))
+        .collect()
+}
+
+crate fn wasm_type(input: &Type) -> Option<elements::ValueType> {
     match input {
         Type::Math(ty) => match ty {
             MathType::F32 => Some(elements::ValueType::F32),
@@ -40,6 +80,15 @@ fn wasm_type(input: &Type) -> Option<elements::ValueType> {
         Type::Function(..) | Type::Apply(..) => {
             panic!("Cannot convert a function into a wasm type")
         }
-        Type::Void => None,
+        Type::Void | Type::Never => None,
+        Type::Newtype(newtype) => wasm_type(&newtype.repr),
+        Type::Named(..) | Type::Param(..) => {
+            panic!("generic functions are only compiled once they're instantiated")
+        }
+        Type::Tuple(..) => panic!("tuples are flattened into their fields by wasm_types"),
+        // a struct is passed around as a pointer to its fields, and an
+        // array as a pointer to its first element
+        Type::Struct(..) | Type::Array(..) => Some(elements::ValueType::I32),
+        Type::Slice(..) => panic!("slices are flattened into a pointer and a length by wasm_types"),
     }
 }
@@ -0,0 +1,64 @@
+use crate::{annotated, Type};
+use std::collections::{BTreeMap, VecDeque};
+
+// Assigns wasm function indices. Each function that isn't generic gets
+// one, in the order of the module, and each distinct instantiation of a
+// generic function gets the next free one the first time it's called.
+crate struct Instances {
+    indices: BTreeMap<(u32, Vec<Type>), u32>,
+    pending: VecDeque<Instance>,
+    next: u32,
+}
+
+// A function that was given an index but hasn't been compiled yet
+crate struct Instance {
+    crate index: u32,
+    crate function: u32,
+    crate type_args: Vec<Type>,
+}
+
+impl Instances {
+    crate fn new(module: &annotated::Module) -> Instances {
+        let mut instances = Instances {
+            indices: BTreeMap::new(),
+            pending: VecDeque::new(),
+            next: 0,
+        };
+
+        for (function, func) in module.funcs.iter().enumerate() {
+            if func.generics.is_empty() {
+                instances.index(function as u32, vec![]);
+            }
+        }
+
+        instances
+    }
+
+    // The wasm index of `function` instantiated with `type_args`
+    crate fn index(&mut self, function: u32, type_args: Vec<Type>) -> u32 {
+        let key = (function, type_args);
+
+        if let Some(index) = self.indices.get(&key) {
+            return *index;
+        }
+
+        let index = self.next;
+        self.next += 1;
+
+        let (function, type_args) = key.clone();
+        self.indices.insert(key, index);
+        self.pending.push_back(Instance {
+            index,
+            function,
+            type_args,
+        });
+
+        index
+    }
+
+    // Instances come out in the order of their indices, which is the order
+    // they have to be pushed into the wasm module in
+    crate fn next_pending(&mut self) -> Option<Instance> {
+        self.pending.pop_front()
+    }
+}
@@ -12,6 +12,27 @@ pub enum MathType {
     F64,
 }
 
+// What `as` does with a float that's NaN or outside the range of the
+// integer type it's converted to
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Conversion {
+    // traps, like wasm's truncation instructions
+    Trapping,
+    // `as saturating`: NaN becomes 0, and other values are clamped to the
+    // integer type's range
+    Saturating,
+}
+
+impl MathType {
+    // The number of bytes a value takes up in linear memory
+    crate fn size(self) -> u32 {
+        match self {
+            MathType::I32 | MathType::U32 | MathType::F32 => 4,
+            MathType::I64 | MathType::U64 | MathType::F64 => 8,
+        }
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq)]
 pub enum MathOperator {
     Add,
@@ -26,6 +47,49 @@ pub enum MathOperator {
     ShiftRight,
 }
 
+impl MathOperator {
+    // Remainder, bitwise and shift operators don't exist for floats
+    crate fn is_integer_only(self) -> bool {
+        match self {
+            MathOperator::Add | MathOperator::Sub | MathOperator::Mul | MathOperator::Div => false,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum CompareOperator {
+    Equal,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+}
+
+impl CompareOperator {
+    // `<`, `<=`, `>` and `>=`, as opposed to `==` and `!=`
+    crate fn is_ordering(self) -> bool {
+        match self {
+            CompareOperator::Equal | CompareOperator::NotEqual => false,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum UnaryOperator {
+    Not,
+    Negate,
+    BitNot,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum LogicalOperator {
+    And,
+    Or,
+}
+
 crate fn math_op(operator: MathOperator, ty: MathType) -> elements::Opcode {
     match operator {
         MathOperator::Add => match ty {
@@ -58,6 +122,148 @@ crate fn math_op(operator: MathOperator, ty: MathType) -> elements::Opcode {
             MathType::F64 => Opcode::F64Div,
         },
 
-        _ => unimplemented!("Unimplemented operator {:?} for {:?}", operator, ty),
+        MathOperator::Rem => match ty {
+            MathType::I32 => Opcode::I32RemS,
+            MathType::I64 => Opcode::I64RemS,
+            MathType::U32 => Opcode::I32RemU,
+            MathType::U64 => Opcode::I64RemU,
+            MathType::F32 | MathType::F64 => float_operand(operator, ty),
+        },
+
+        MathOperator::And => match ty {
+            MathType::I32 | MathType::U32 => Opcode::I32And,
+            MathType::I64 | MathType::U64 => Opcode::I64And,
+            MathType::F32 | MathType::F64 => float_operand(operator, ty),
+        },
+
+        MathOperator::Or => match ty {
+            MathType::I32 | MathType::U32 => Opcode::I32Or,
+            MathType::I64 | MathType::U64 => Opcode::I64Or,
+            MathType::F32 | MathType::F64 => float_operand(operator, ty),
+        },
+
+        MathOperator::Xor => match ty {
+            MathType::I32 | MathType::U32 => Opcode::I32Xor,
+            MathType::I64 | MathType::U64 => Opcode::I64Xor,
+            MathType::F32 | MathType::F64 => float_operand(operator, ty),
+        },
+
+        MathOperator::ShiftLeft => match ty {
+            MathType::I32 | MathType::U32 => Opcode::I32Shl,
+            MathType::I64 | MathType::U64 => Opcode::I64Shl,
+            MathType::F32 | MathType::F64 => float_operand(operator, ty),
+        },
+
+        MathOperator::ShiftRight => match ty {
+            MathType::I32 => Opcode::I32ShrS,
+            MathType::I64 => Opcode::I64ShrS,
+            MathType::U32 => Opcode::I32ShrU,
+            MathType::U64 => Opcode::I64ShrU,
+            MathType::F32 | MathType::F64 => float_operand(operator, ty),
+        },
+    }
+}
+
+// The instruction for `from as to`, or None if both types have the same wasm
+// representation. Integers wrap when narrowed and sign or zero extend (based
+// on the source type) when widened, like Rust's `as`. Floats are truncated
+// towards zero when converted to integers, which traps if the value is NaN or
+// out of range. The saturating instructions aren't part of the wasm version
+// parity-wasm targets, so `as saturating` guards this instruction instead.
+crate fn convert_op(from: MathType, to: MathType) -> Option<elements::Opcode> {
+    use self::MathType::*;
+
+    let op = match (from, to) {
+        (I32, I32) | (I32, U32) | (U32, I32) | (U32, U32) => return None,
+        (I64, I64) | (I64, U64) | (U64, I64) | (U64, U64) => return None,
+        (F32, F32) | (F64, F64) => return None,
+
+        (I64, I32) | (I64, U32) | (U64, I32) | (U64, U32) => Opcode::I32WrapI64,
+        (I32, I64) | (I32, U64) => Opcode::I64ExtendSI32,
+        (U32, I64) | (U32, U64) => Opcode::I64ExtendUI32,
+
+        (F64, F32) => Opcode::F32DemoteF64,
+        (F32, F64) => Opcode::F64PromoteF32,
+
+        (I32, F32) => Opcode::F32ConvertSI32,
+        (U32, F32) => Opcode::F32ConvertUI32,
+        (I64, F32) => Opcode::F32ConvertSI64,
+        (U64, F32) => Opcode::F32ConvertUI64,
+        (I32, F64) => Opcode::F64ConvertSI32,
+        (U32, F64) => Opcode::F64ConvertUI32,
+        (I64, F64) => Opcode::F64ConvertSI64,
+        (U64, F64) => Opcode::F64ConvertUI64,
+
+        (F32, I32) => Opcode::I32TruncSF32,
+        (F32, U32) => Opcode::I32TruncUF32,
+        (F64, I32) => Opcode::I32TruncSF64,
+        (F64, U32) => Opcode::I32TruncUF64,
+        (F32, I64) => Opcode::I64TruncSF32,
+        (F32, U64) => Opcode::I64TruncUF32,
+        (F64, I64) => Opcode::I64TruncSF64,
+        (F64, U64) => Opcode::I64TruncUF64,
+    };
+
+    Some(op)
+}
+
+fn float_operand(operator: MathOperator, ty: MathType) -> ! {
+    panic!(
+        "inference bug: {:?} on {:?} should have been rejected by type inference",
+        operator, ty
+    )
+}
+
+crate fn compare_op(operator: CompareOperator, ty: MathType) -> elements::Opcode {
+    match operator {
+        CompareOperator::Equal => match ty {
+            MathType::I32 | MathType::U32 => Opcode::I32Eq,
+            MathType::I64 | MathType::U64 => Opcode::I64Eq,
+            MathType::F32 => Opcode::F32Eq,
+            MathType::F64 => Opcode::F64Eq,
+        },
+
+        CompareOperator::NotEqual => match ty {
+            MathType::I32 | MathType::U32 => Opcode::I32Ne,
+            MathType::I64 | MathType::U64 => Opcode::I64Ne,
+            MathType::F32 => Opcode::F32Ne,
+            MathType::F64 => Opcode::F64Ne,
+        },
+
+        CompareOperator::Less => match ty {
+            MathType::I32 => Opcode::I32LtS,
+            MathType::I64 => Opcode::I64LtS,
+            MathType::U32 => Opcode::I32LtU,
+            MathType::U64 => Opcode::I64LtU,
+            MathType::F32 => Opcode::F32Lt,
+            MathType::F64 => Opcode::F64Lt,
+        },
+
+        CompareOperator::LessEqual => match ty {
+            MathType::I32 => Opcode::I32LeS,
+            MathType::I64 => Opcode::I64LeS,
+            MathType::U32 => Opcode::I32LeU,
+            MathType::U64 => Opcode::I64LeU,
+            MathType::F32 => Opcode::F32Le,
+            MathType::F64 => Opcode::F64Le,
+        },
+
+        CompareOperator::Greater => match ty {
+            MathType::I32 => Opcode::I32GtS,
+            MathType::I64 => Opcode::I64GtS,
+            MathType::U32 => Opcode::I32GtU,
+            MathType::U64 => Opcode::I64GtU,
+            MathType::F32 => Opcode::F32Gt,
+            MathType::F64 => Opcode::F64Gt,
+        },
+
+        CompareOperator::GreaterEqual => match ty {
+            MathType::I32 => Opcode::I32GeS,
+            MathType::I64 => Opcode::I64GeS,
+            MathType::U32 => Opcode::I32GeU,
+            MathType::U64 => Opcode::I64GeU,
+            MathType::F32 => Opcode::F32Ge,
+            MathType::F64 => Opcode::F64Ge,
+        },
     }
 }
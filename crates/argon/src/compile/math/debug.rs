@@ -1,4 +1,4 @@
-use super::{MathOperator, MathType};
+use super::{CompareOperator, LogicalOperator, MathOperator, MathType, UnaryOperator};
 use std::fmt;
 
 impl fmt::Debug for MathType {
@@ -32,3 +32,41 @@ impl fmt::Debug for MathOperator {
         write!(f, "{}", operator)
     }
 }
+
+impl fmt::Debug for CompareOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = match self {
+            CompareOperator::Equal => "==",
+            CompareOperator::NotEqual => "!=",
+            CompareOperator::Less => "<",
+            CompareOperator::LessEqual => "<=",
+            CompareOperator::Greater => ">",
+            CompareOperator::GreaterEqual => ">=",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
+
+impl fmt::Debug for UnaryOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = match self {
+            UnaryOperator::Not => "!",
+            UnaryOperator::Negate => "-",
+            UnaryOperator::BitNot => "~",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
+
+impl fmt::Debug for LogicalOperator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let operator = match self {
+            LogicalOperator::And => "&&",
+            LogicalOperator::Or => "||",
+        };
+
+        write!(f, "{}", operator)
+    }
+}
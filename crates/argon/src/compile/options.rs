@@ -0,0 +1,38 @@
+// How a function returning a tuple or a slice hands its fields back to its
+// caller
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TupleReturns {
+    // as multiple results, which needs an engine that supports the
+    // multi-value proposal
+    MultiValue,
+    // through a pointer to linear memory the caller passes as the first
+    // argument, which every engine supports
+    OutPointer,
+}
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CompileOptions {
+    pub tuple_returns: TupleReturns,
+    // whether indexing an array or a slice past its end traps. Without the
+    // check, it reads or writes whatever memory comes after it.
+    pub bounds_checks: bool,
+}
+
+impl CompileOptions {
+    // For code that has already been tested with bounds checks on
+    pub fn release() -> CompileOptions {
+        CompileOptions {
+            bounds_checks: false,
+            ..CompileOptions::default()
+        }
+    }
+}
+
+impl Default for CompileOptions {
+    fn default() -> CompileOptions {
+        CompileOptions {
+            tuple_returns: TupleReturns::OutPointer,
+            bounds_checks: true,
+        }
+    }
+}
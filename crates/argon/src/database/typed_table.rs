@@ -5,7 +5,7 @@ use code_database::{AbsolutePath, TransactionId};
 use crate::annotated;
 use crate::compilation::DatabaseWithoutTyped;
 use crate::database::MapTableTrait;
-use crate::infer::UnifyTable;
+use crate::infer::{check_literals, fold_constants, UnifyTable};
 use crate::ir::{ast, resolved};
 use failure::Error;
 use std::borrow::{Borrow, Cow};
@@ -113,6 +113,9 @@ fn compile(ast: &ast::Module) -> Result<annotated::Module, Error> {
     trace!(target: "argon::compile::substitutions", "Substitutions: {:#?}", substitutions);
     let module = substitutions.apply_module(module);
     trace!(target: "argon::compile::applies", "After Substitutions: {:#?}", module);
+    check_literals(&module)?;
+    let module = fold_constants(module)?;
+    trace!(target: "argon::compile::folded", "After Folding: {:#?}", module);
 
     Ok(module)
 }
@@ -5,9 +5,17 @@ use code_database::{AbsolutePath, TransactionId};
 use codespan::FileMap;
 use crate::annotated;
 use crate::compilation::DatabaseWithoutWasm;
-use crate::compile::function::compile_function;
+use crate::compile::abi::returns_through_pointer;
+use crate::compile::context::Heap;
+use crate::compile::expression::compile_const;
+use crate::compile::function::{compile_function, wasm_type, wasm_types};
+use crate::compile::instances::{Instance, Instances};
 use crate::database::MapTableTrait;
+use crate::infer::{check_function, Substitution};
+use crate::ir::resolved::Memory;
+use crate::{CompileError, CompileOptions, Type};
 use failure::Error;
+use parity_wasm::elements::Serialize;
 use parity_wasm::{builder, elements};
 use std::borrow::Cow;
 use std::collections::BTreeMap;
@@ -20,12 +28,14 @@ crate struct Entry {
 
 crate struct WasmTable {
     index: BTreeMap<AbsolutePath, Entry>,
+    options: CompileOptions,
 }
 
 impl WasmTable {
-    crate fn new() -> WasmTable {
+    crate fn new(options: CompileOptions) -> WasmTable {
         WasmTable {
             index: BTreeMap::new(),
+            options,
         }
     }
 }
@@ -64,7 +74,7 @@ impl MapTableTrait<'parent, 'entry> for WasmTable {
         db: &mut Self::InnerTable,
         transaction: TransactionId,
     ) -> Result<Option<()>, Error> {
-        let cache_entry = compute_cache(key, db, transaction)?;
+        let cache_entry = compute_cache(key, db, transaction, self.options)?;
 
         match cache_entry {
             Some(cache_entry) => self.index.insert(key.clone(), cache_entry),
@@ -79,6 +89,7 @@ fn compute_cache(
     key: &AbsolutePath,
     mut db: &mut DatabaseWithoutWasm<'inner>,
     transaction: TransactionId,
+    options: CompileOptions,
 ) -> Result<Option<Entry>, Error>
 where
     Error: 'static,
@@ -102,7 +113,7 @@ where
         .get(&mut (), key, transaction)?
         .expect(&format!("Expected FileMap for {:?}", key));
 
-    let module = compile(&module, file.into_owned())?;
+    let module = compile(&module, file.into_owned(), options)?;
 
     let new_entry = Entry {
         module: module.clone(),
@@ -128,16 +139,106 @@ struct CodeLocation {
     _body: u32,
 }
 
-fn compile(module: &annotated::Module, file: Arc<FileMap>) -> Result<elements::Module, Error> {
+fn compile(
+    module: &annotated::Module,
+    file: Arc<FileMap>,
+    options: CompileOptions,
+) -> Result<elements::Module, Error> {
     let mut builder = builder::module();
+    let mut instances = Instances::new(module);
+    let mut returns_tuples = false;
+
+    // the heap's global comes after the module's, once it's known whether
+    // anything is allocated
+    let mut heap = Heap {
+        global: module.globals.len() as u32,
+        used: false,
+        accessed: false,
+    };
+
+    // the results of the functions returning tuples or slices as multiple
+    // values
+    let mut multi_value_results = BTreeMap::new();
+
+    // a global's index is its position in the module, since nothing is
+    // imported
+    for (index, global) in module.globals.iter().enumerate() {
+        let ty = wasm_type(&global.ty.node).expect("globals are numbers or booleans");
+        let value = match &global.value.item {
+            annotated::Expression::Const(constant) => compile_const(constant, &global.value.ty),
+            other => panic!("a global's value is folded to a constant, found {:?}", other),
+        };
+
+        builder = builder.with_global(elements::GlobalEntry::new(
+            elements::GlobalType::new(ty, global.mutable),
+            elements::InitExpr::new(vec![value, elements::Opcode::End]),
+        ));
+
+        if global.export {
+            let name = file.src_slice(global.name.span.to_codespan_span(&file))?;
+
+            builder = builder
+                .export()
+                .field(name)
+                .internal()
+                .global(index as u32)
+                .build();
+        }
+    }
+
+    // a pointer into memory can be passed in or out of an exported function
+    let mut points_into_memory = false;
+
+    // compiling a function can call generic functions that haven't been
+    // instantiated yet, which adds more functions to compile
+    while let Some(Instance {
+        index,
+        function,
+        type_args,
+    }) = instances.next_pending()
+    {
+        let func = &module.funcs[function as usize];
+
+        let instance;
+        let func = if type_args.is_empty() {
+            func
+        } else {
+            instance = Substitution::instantiate(type_args).apply_function(func.clone());
+            check_function(&instance)?;
+            &instance
+        };
+
+        match func.ret.node {
+            _ if returns_through_pointer(&func.ret.node, options) => returns_tuples = true,
+            Type::Tuple(..) | Type::Slice(..) => {
+                multi_value_results.insert(index, wasm_types(&func.ret.node));
+            }
+            _ => {}
+        }
+
+        points_into_memory |= func.ret.node.points_into_memory()
+            || func.params.iter().any(|param| param.node.points_into_memory());
 
-    for func in &module.funcs {
         let function = builder::function();
-        let function = compile_function(function, func);
+        let function = compile_function(function, func, &mut instances, &mut heap, options);
+
+        // the heap only ever grows, so a fixed maximum would eventually
+        // make an allocation trap
+        if heap.used && module.memory.map_or(false, |memory| memory.max.is_some()) {
+            return Err(CompileError::AllocationInBoundedMemory(func.name).into());
+        }
+
         let location: CodeLocation =
             unsafe { std::mem::transmute(builder.push_function(function)) };
 
-        let name_span = func.name.span.to_codespan_span();
+        // calls refer to functions by the index `Instances` gave them, so it
+        // has to line up with the index the builder assigned
+        debug_assert_eq!(
+            location.signature, index,
+            "function {:?} was pushed out of order", func.name
+        );
+
+        let name_span = func.name.span.to_codespan_span(&file);
         let name = file.src_slice(name_span)?;
 
         if func.modifiers.export {
@@ -150,5 +251,129 @@ fn compile(module: &annotated::Module, file: Arc<FileMap>) -> Result<elements::M
         }
     }
 
-    Ok(builder.build())
+    // structs, arrays and returned tuples are allocated after the pages
+    // memory starts with, so they never overlap what the module or the host
+    // put there. The first page is always skipped, which leaves the host a
+    // page to pass data in when the module doesn't declare a memory.
+    if heap.used {
+        let pages = module.memory.map(|memory| memory.min).unwrap_or(0).max(1);
+        let start = pages * PAGE_SIZE;
+
+        builder = builder.with_global(elements::GlobalEntry::new(
+            elements::GlobalType::new(elements::ValueType::I32, true),
+            elements::InitExpr::new(vec![
+                elements::Opcode::I32Const(start as i32),
+                elements::Opcode::End,
+            ]),
+        ));
+    }
+
+    // callers pass a pointer into this memory to functions returning
+    // tuples, structs, arrays and slices are pointers into it, and `load`
+    // and `store` read and write it, so the host has to be able to read it
+    let shares_memory = returns_tuples
+        || heap.used
+        || heap.accessed
+        || points_into_memory
+        || !module.structs.is_empty();
+
+    let memory = match module.memory {
+        Some(memory) => Some(memory),
+        None if shares_memory => Some(Memory {
+            min: 1,
+            max: None,
+            export: true,
+        }),
+        None => None,
+    };
+
+    if let Some(memory) = memory {
+        builder = builder
+            .memory()
+            .with_min(memory.min)
+            .with_max(memory.max)
+            .build();
+
+        if memory.export || shares_memory {
+            builder = builder
+                .export()
+                .field("memory")
+                .internal()
+                .memory(0)
+                .build();
+        }
+    }
+
+    let mut module = builder.build();
+
+    // the builder's function types have at most one result, so the type
+    // section is replaced with one encoded by hand, which the encoder writes
+    // out as is
+    if !multi_value_results.is_empty() {
+        let payload = multi_value_types(&module, &multi_value_results)?;
+
+        for section in module.sections_mut() {
+            if let elements::Section::Type(..) = section {
+                *section = elements::Section::Unparsed { id: 1, payload };
+                break;
+            }
+        }
+    }
+
+    Ok(module)
+}
+
+const PAGE_SIZE: u32 = 65536;
+
+// Encodes the module's type section, with the results of the functions
+// returning tuples or slices as multiple values filled in
+fn multi_value_types(
+    module: &elements::Module,
+    results: &BTreeMap<u32, Vec<elements::ValueType>>,
+) -> Result<Vec<u8>, Error> {
+    let functions = module
+        .function_section()
+        .expect("a function returns a tuple")
+        .entries();
+
+    let results: BTreeMap<u32, &Vec<elements::ValueType>> = results
+        .iter()
+        .map(|(function, results)| (functions[*function as usize].type_ref(), results))
+        .collect();
+
+    let types = module
+        .type_section()
+        .expect("every function has a type")
+        .types();
+
+    let mut entries = vec![];
+    elements::VarUint32::from(types.len()).serialize(&mut entries)?;
+
+    for (index, ty) in types.iter().enumerate() {
+        let elements::Type::Function(ty) = ty;
+
+        let results = match results.get(&(index as u32)) {
+            Some(results) => results.to_vec(),
+            None => ty.return_type().into_iter().collect(),
+        };
+
+        elements::VarUint7::from(ty.form()).serialize(&mut entries)?;
+
+        elements::VarUint32::from(ty.params().len()).serialize(&mut entries)?;
+        for param in ty.params() {
+            param.serialize(&mut entries)?;
+        }
+
+        elements::VarUint32::from(results.len()).serialize(&mut entries)?;
+        for result in results {
+            result.serialize(&mut entries)?;
+        }
+    }
+
+    // an unparsed section's payload starts with its size
+    let mut payload = vec![];
+    elements::VarUint32::from(entries.len()).serialize(&mut payload)?;
+    payload.extend(entries);
+
+    Ok(payload)
 }
@@ -1,11 +1,12 @@
 // auto-generated: "lalrpop 0.15.2"
-// sha256: 586912a963749a42d1fd7557e8e161397fd94d116fe7d5e5a9182cce6af71
+// sha256: 3b774d8094417e3d71ebae8452b7eec31134756e41ef2872fbca47385dec39a
 use crate::ast;
 use crate::ast::*;
 use crate::ir::*;
 use crate::ir::pos::SpannedItem;
-use crate::MathOperator;
+use crate::{Conversion, MathOperator};
 use crate::lexer::Tok;
+use lalrpop_util::ParseError;
 use nan_preserving_float::F64;
 use std::borrow::Cow;
 #[allow(unused_extern_crates)]
@@ -21,8 +22,9 @@ mod __parse__Module {
     use crate::ast::*;
     use crate::ir::*;
     use crate::ir::pos::SpannedItem;
-    use crate::MathOperator;
+    use crate::{Conversion, MathOperator};
     use crate::lexer::Tok;
+    use lalrpop_util::ParseError;
     use nan_preserving_float::F64;
     use std::borrow::Cow;
     #[allow(unused_extern_crates)]
@@ -32,166 +34,888 @@ mod __parse__Module {
     pub enum __Symbol<>
      {
         Variant0(Spanned<Tok>),
-        Variant1(Parameter),
-        Variant2(::std::vec::Vec<Parameter>),
-        Variant3(Expression),
-        Variant4(::std::vec::Vec<Expression>),
-        Variant5(::std::vec::Vec<Function>),
-        Variant6(Block),
-        Variant7(Function),
-        Variant8(Module),
-        Variant9(Parameters),
-        Variant10(Spanned<Type>),
-    }
-    const __ACTION: &'static [i8] = &[
+        Variant1(::std::option::Option<Spanned<Tok>>),
+        Variant2(Binding),
+        Variant3(::std::vec::Vec<Binding>),
+        Variant4(Expression),
+        Variant5(::std::vec::Vec<Expression>),
+        Variant6(Parameter),
+        Variant7(::std::vec::Vec<Parameter>),
+        Variant8(Spanned<Type>),
+        Variant9(::std::vec::Vec<Spanned<Type>>),
+        Variant10(TypeParameter),
+        Variant11(::std::vec::Vec<TypeParameter>),
+        Variant12(::std::option::Option<Spanned<Type>>),
+        Variant13(FieldDeclaration),
+        Variant14(::std::vec::Vec<FieldDeclaration>),
+        Variant15(FieldInit),
+        Variant16(::std::vec::Vec<FieldInit>),
+        Variant17(::std::vec::Vec<Item>),
+        Variant18(usize),
+        Variant19(Vec<Expression>),
+        Variant20(Block),
+        Variant21(Vec<FieldDeclaration>),
+        Variant22(Vec<FieldInit>),
+        Variant23(ConstDeclaration),
+        Variant24(::std::option::Option<Expression>),
+        Variant25(::std::option::Option<FieldDeclaration>),
+        Variant26(::std::option::Option<FieldInit>),
+        Variant27(Function),
+        Variant28(Vec<TypeParameter>),
+        Variant29(::std::option::Option<Vec<TypeParameter>>),
+        Variant30(GlobalDeclaration),
+        Variant31(Item),
+        Variant32(MemoryDeclaration),
+        Variant33(MemoryImmediates),
+        Variant34(Module),
+        Variant35((Vec<Expression>, bool)),
+        Variant36(Parameters),
+        Variant37(StructDeclaration),
+        Variant38(TypeDeclaration),
+    }
+    const __ACTION: &'static [i16] = &[
         // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 7, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 0, 14, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 17, 0, 0, 0, 18, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -34, -34, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -179, 0, -179, 0, -179, 0, 0, 0, 0, -179, 0, 0, 0, 0, 0, 0, 0, -179, 0, 0, -179, 0, 0, 0, -179, 0, -179, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -37, -37, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -207, 0, -207, 0, -207, 0, 0, 0, 0, -207, 0, 0, 0, 0, 0, 0, 0, -207, 0, 0, -207, 0, 0, 0, -207, 0, -207, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 7, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -178, 0, -178, 0, -178, 0, 0, 0, 0, -178, 0, 0, 0, 0, 0, 0, 0, -178, 0, 0, -178, 0, 0, 0, -178, 0, -178, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -183, 0, -183, 0, -183, 0, 0, 0, 0, -183, 0, 0, 0, 0, 0, 0, 0, -183, 0, 0, -183, 0, 0, 0, -183, 0, -183, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 0, 14, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 17, 0, 0, 0, 18, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -177, 0, -177, 0, -177, 0, 0, 0, 0, -177, 0, 0, 0, 0, 0, 0, 0, -177, 0, 0, -177, 0, 0, 0, -177, 0, -177, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -38, -38, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -175, 0, -175, 0, -175, 0, 0, 0, 0, -175, 0, 0, 0, 0, 0, 0, 0, -175, 0, 0, -175, 0, 0, 0, -175, 0, -175, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -33, -33, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -180, 0, -180, 0, -180, 0, 0, 0, 0, -180, 0, 0, 0, 0, 0, 0, 0, -180, 0, 0, -180, 0, 0, 0, -180, 0, -180, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -176, 0, -176, 0, -176, 0, 0, 0, 0, -176, 0, 0, 0, 0, 0, 0, 0, -176, 0, 0, -176, 0, 0, 0, -176, 0, -176, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -31, -31, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 22, 23, 24, 25, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 42, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 0, 0, 0, 0, -44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -44, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        0, -49, 0, 0, -49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -49, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -184, 0, -184, 0, -184, 0, 0, 0, 0, -184, 0, 0, 0, 0, 0, 0, 0, -184, 0, 0, -184, 0, 0, 0, -184, 0, -184, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, -50, 0, 0, -50, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -50, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, -45, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0,
+        0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, -46, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -206, 0, -206, 0, -206, 0, 0, 0, 0, -206, 0, 0, 0, 0, 0, 0, 0, -206, 0, 0, -206, 0, 0, 0, -206, 0, -206, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, -47, 0, 0, -47, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -47, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, -48, 0, 0, -48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -48, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, -24, -24, 0, -24, 0, -24, 0, -24, -24, -24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -24,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, -25, -25, 0, -25, 0, -25, 0, -25, -25, -25, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -25,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        0, 0, 0, -27, 0, -27, 0, 0, 0, -27, -27, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, -28, 0, -28, 0, 0, 0, -28, -28, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -28,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0,
         // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -9, -9, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -9,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        0, 0, -23, -23, 0, -23, 0, -23, 0, -23, -23, -23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -23,
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
         // State 32
-        0, 0, 48, -26, 0, -26, 0, 49, 0, -26, -26, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26,
+        0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        0, 0, 0, 50, 0, 51, 0, 0, 0, -19, -19, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -19,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
         // State 34
-        0, 0, -22, -22, 0, -22, 0, -22, 0, -22, -22, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -22,
+        0, 0, 0, 0, 0, 0, 66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        0, 0, -21, -21, 0, -21, 0, -21, 0, -21, -21, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -21,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        0, 0, -29, -29, 0, -29, 0, -29, 0, -29, -29, -29, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -29,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 71, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 37
-        0, 0, -20, -20, 0, -20, 0, -20, 0, -20, -20, -20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -20,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 38
-        0, 0, -32, -32, 0, -32, 0, -32, 0, -32, -32, -32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -17, -17, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
         // State 40
-        0, 52, 0, 0, 53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 76, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 0, 0, 0, -42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -42, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -202, 0, -202, 0, -202, 0, 0, 0, 0, -202, 0, 0, 0, 0, 0, 0, 0, -202, 0, 0, -202, 0, 0, 0, -202, 0, -202, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 22, 23, 24, 25, 0, 0,
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -30, -30, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -82, 0,
         // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, -10, -10, -10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -10,
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -18, -18, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -233, 0, 0, 0, 0, -233, 0, 0, 0, 0, 0, 0, 0, 0, -233, 0, 0, 0, -233, 0, -233, 0, 0, 0, 0, 0, 0, 0, -233, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -233, 0, 0, -233, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -231, 0, 0, 0, 0, -231, 0, 0, 0, 0, 0, 0, 0, 0, -231, 0, 0, 0, -231, 0, -231, 0, 0, 0, 0, 0, 0, 0, -231, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -231, 0, 0, -231, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 85, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, -43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -43, 0,
+        0, -210, -210, -210, -210, 0, -210, -210, 0, -210, 0, -210, -210, 0, 0, 0, 0, -210, 0, 0, -210, -210, -210, -210, -210, -210, -210, -210, -210, 0, 0, 0, 0, 0, -210, -210, -210, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -210, -210, -210, -210, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
         // State 53
-        0, -4, 0, 0, -4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, -232, 0, 0, 0, 0, -232, 0, 0, 0, 0, 0, 0, 0, 0, -232, 0, 0, 0, -232, 0, -232, 0, 0, 0, 0, 0, 0, 0, -232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -232, 0, 0, -232, 0,
         // State 54
-        0, -41, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -219, -219, -219, -219, 0, -219, -219, 0, -219, 0, -219, -219, 0, 0, 0, 0, -219, 0, 0, -219, -219, -219, -219, -219, -219, -219, -219, -219, 0, 0, 0, 0, 0, -219, -219, -219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -219, -219, -219, -219, 0,
         // State 55
-        0, 0, -13, -13, 0, -13, 0, -13, 0, -13, -13, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13,
+        0, -220, -220, -220, -220, 0, -220, -220, 0, -220, 0, -220, -220, 0, 0, 0, 0, -220, 0, 0, -220, -220, -220, -220, -220, -220, -220, -220, -220, 0, 0, 0, 0, 0, -220, -220, -220, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -220, -220, -220, -220, 0,
         // State 56
-        0, 0, -14, -14, 0, -14, 0, -14, 0, -14, -14, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14,
+        0, -215, -215, -215, -215, 0, -215, -215, 0, -215, 0, -215, -215, 0, 0, 0, 0, -215, 0, 0, -215, -215, -215, -215, -215, -215, -215, -215, -215, 0, 0, 0, 0, 0, -215, -215, -215, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -215, -215, -215, -215, 0,
         // State 57
-        0, 0, 48, -15, 0, -15, 0, 49, 0, -15, -15, -15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -15,
+        0, -216, -216, -216, -216, 0, -216, -216, 0, -216, 0, -216, -216, 0, 0, 0, 0, -216, 0, 0, -216, -216, -216, -216, -216, -216, -216, -216, -216, 0, 0, 0, 0, 0, -216, -216, -216, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -216, -216, -216, -216, 0,
         // State 58
-        0, 0, 48, -16, 0, -16, 0, 49, 0, -16, -16, -16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -16,
+        0, -217, -217, -217, -217, 0, -217, -217, 0, -217, 0, -217, -217, 0, 0, 0, 0, -217, 0, 0, -217, -217, -217, -217, -217, -217, -217, -217, -217, 0, 0, 0, 0, 0, -217, -217, -217, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -217, -217, -217, -217, 0,
         // State 59
-        0, -5, 0, 0, -5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, -218, -218, -218, -218, 0, -218, -218, 0, -218, 0, -218, -218, 0, 0, 0, 0, -218, 0, 0, -218, -218, -218, -218, -218, -218, -218, -218, -218, 0, 0, 0, 0, 0, -218, -218, -218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -218, -218, -218, -218, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -162, 0, -162, 0, -162, 0, 0, 0, 0, -162, 0, 0, 0, 0, 0, 0, 0, -162, 0, 0, -162, 0, 0, 0, -162, 0, -162, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 63
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 148, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, -228, 157,
+        // State 64
+        0, 0, 0, 0, 0, 0, 159, 0, 0, 0, 0, 160, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -225, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -225, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 161, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 162, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 165, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -240, 0, 0, 0, 0, 0, 0, 0, 166, 0, 0, 0, 0, 0, 0, -240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 168, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 169, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -200, 0, -200, 0, -200, 0, 0, 0, 0, -200, 0, 0, 0, 0, 0, 0, 0, -200, 0, 0, -200, 0, 0, 0, -200, 0, -200, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 170, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 172, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 173, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 82, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -84, 0,
+        // State 79
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 175, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 176, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -81, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 177, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 178, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 84
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 183, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 184, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -161, 0, -161, 0, -161, 0, 0, 0, 0, -161, 0, 0, 0, 0, 0, 0, 0, -161, 0, 0, -161, 0, 0, 0, -161, 0, -161, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 88
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -101, 0, 0, -101, 0,
+        // State 91
+        0, -128, -128, -128, -128, 0, -128, -128, 0, -128, 0, -128, -128, 0, 0, 0, 0, -128, 0, 0, -128, -128, -128, -128, 0, -128, -128, -128, -128, 0, 0, 0, 0, 0, -128, -128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -128, -128, -128, -128, 0,
+        // State 92
+        0, -126, -126, -126, -126, 0, -126, -126, 0, -126, 0, -126, -126, 0, 0, 0, 0, -126, 0, 0, -126, -126, -126, -126, 0, -126, -126, -126, -126, 0, 0, 0, 0, 0, -126, -126, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -126, -126, -126, -126, 0,
+        // State 93
+        0, -127, -127, -127, -127, 0, -127, -127, 0, -127, 0, -127, -127, 0, 0, 0, 0, -127, 0, 0, -127, -127, -127, -127, 0, -127, -127, -127, -127, 0, 0, 0, 0, 0, -127, -127, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -127, -127, -127, -127, 0,
+        // State 94
+        0, -130, 0, -130, -130, 0, -130, 0, 0, -130, 0, -130, -130, 0, 0, 0, 0, 0, 0, 0, -130, -130, -130, -130, 0, -130, -130, -130, -130, 0, 0, 0, 0, 0, -130, -130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -130, -130, -130, -130, 0,
+        // State 95
+        0, -131, 0, -131, -131, 0, -131, 0, 0, -131, 0, -131, -131, 0, 0, 0, 0, 0, 0, 0, -131, -131, -131, -131, 0, -131, -131, -131, -131, 0, 0, 0, 0, 0, -131, -131, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -131, -131, -131, -131, 0,
+        // State 96
+        0, -133, 0, -133, -133, 0, -133, 0, 0, 0, 0, -133, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, -133, -133, 0, -133, -133, -133, -133, 0, 0, 0, 0, 0, -133, -133, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -133, -133, -133, -133, 0,
+        // State 97
+        0, -134, 0, -134, -134, 0, -134, 0, 0, 0, 0, -134, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, -134, -134, 0, -134, -134, -134, -134, 0, 0, 0, 0, 0, -134, -134, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -134, -134, -134, -134, 0,
+        // State 98
+        0, -136, 0, -136, -136, 0, -136, 0, 0, 0, 0, -136, 0, 0, 0, 0, 0, 0, 0, 0, -136, -136, 0, -136, 0, -136, -136, -136, 0, 0, 0, 0, 0, 0, -136, -136, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -136, -136, -136, -136, 0,
+        // State 99
+        0, -138, 0, 0, -138, 0, -138, 0, 0, 0, 0, -138, 0, 0, 0, 0, 0, 0, 0, 0, -138, -138, 0, -138, 0, -138, -138, -138, 0, 0, 0, 0, 0, 0, -138, -138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -138, -138, -138, -138, 0,
+        // State 100
+        0, -140, 0, 0, -140, 0, -140, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, -140, -140, 0, -140, 0, -140, -140, -140, 0, 0, 0, 0, 0, 0, -140, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -140, -140, -140, -140, 0,
+        // State 101
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 148, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, -104, 157,
+        // State 102
+        0, -122, -122, -122, -122, 0, -122, -122, 0, -122, 0, -122, -122, 0, 0, -122, 0, -122, 0, 0, -122, -122, -122, -122, 0, -122, -122, -122, -122, 0, 0, 0, 0, -122, -122, -122, -122, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -122, -122, -122, -122, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -102, 0, 0, -102, 0,
+        // State 104
+        0, 0, 0, 0, -143, 0, -143, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -143, 0, -143, -143, 0,
+        // State 105
+        0, 0, 0, 0, -144, 0, -144, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -144, 0, -144, -144, 0,
+        // State 106
+        0, 0, 0, 0, -145, 0, -145, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -145, 0, -145, -145, 0,
+        // State 107
+        0, 0, 0, 0, -142, 0, -142, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -142, 0, -142, -142, 0,
+        // State 108
+        0, 0, 0, 0, -146, 0, -146, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -146, 0, -146, -146, 0,
+        // State 109
+        0, 0, 0, 0, -147, 0, -147, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -147, 0, -147, -147, 0,
+        // State 110
+        0, -125, -125, -125, -125, 0, -125, -125, 0, -125, 0, -125, -125, 0, 0, 0, 0, -125, 0, 0, -125, -125, -125, -125, 0, -125, -125, -125, -125, 0, 0, 0, 0, 0, -125, -125, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -125, -125, -125, -125, 0,
+        // State 111
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -211, 0,
+        // State 112
+        0, -241, -241, -241, -241, 0, -241, -241, 0, -241, 0, -241, -241, 0, 0, 190, 0, -241, 0, 0, -241, -241, -241, -241, 0, -241, -241, -241, -241, 0, 0, 0, 0, 191, -241, -241, -241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -241, -241, -241, -241, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -105, 0, 192, -105, 0,
+        // State 114
+        0, -129, 193, -129, -129, 0, -129, 194, 0, -129, 0, -129, -129, 0, 0, 0, 0, 195, 0, 0, -129, -129, -129, -129, 0, -129, -129, -129, -129, 0, 0, 0, 0, 0, -129, -129, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -129, -129, -129, -129, 0,
+        // State 115
+        0, -132, 0, -132, -132, 0, -132, 0, 0, 196, 0, -132, 197, 0, 0, 0, 0, 0, 0, 0, -132, -132, -132, -132, 0, -132, -132, -132, -132, 0, 0, 0, 0, 0, -132, -132, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -132, -132, -132, -132, 0,
+        // State 116
+        0, -135, 0, -135, -135, 0, -135, 0, 0, 0, 0, -135, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, 198, -135, 0, -135, -135, -135, 199, 0, 0, 0, 0, 0, -135, -135, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -135, -135, -135, -135, 0,
+        // State 117
+        0, -137, 0, 200, -137, 0, -137, 0, 0, 0, 0, -137, 0, 0, 0, 0, 0, 0, 0, 0, -137, -137, 0, -137, 0, -137, -137, -137, 0, 0, 0, 0, 0, 0, -137, -137, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -137, -137, -137, -137, 0,
+        // State 118
+        0, -139, 0, 0, -139, 0, -139, 0, 0, 0, 0, -139, 0, 0, 0, 0, 0, 0, 0, 0, -139, -139, 0, -139, 0, -139, -139, -139, 0, 0, 0, 0, 0, 0, -139, 201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -139, -139, -139, -139, 0,
+        // State 119
+        0, 202, 0, 0, -141, 0, -141, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, -141, 203, 0, 204, 0, 205, 206, 207, 0, 0, 0, 0, 0, 0, -141, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -141, 208, -141, -141, 0,
+        // State 120
+        0, 0, 0, 0, -148, 0, -148, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -148, 0, -148, -148, 0,
+        // State 121
+        0, 0, 0, 0, 209, 0, -123, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -123, 0, -123, -123, 0,
+        // State 122
+        0, -121, -121, -121, -121, 0, -121, -121, 0, -121, 0, -121, -121, 0, 0, -121, 0, -121, 0, 0, -121, -121, -121, -121, 0, -121, -121, -121, -121, 0, 0, 0, 0, -121, -121, -121, -121, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -121, -121, -121, -121, 0,
+        // State 123
+        -75, 0, 0, -75, 0, -75, -75, 0, 0, 0, 0, -75, -75, 0, 0, 0, 0, 0, 0, 0, -75, 0, 0, 0, 0, 0, 0, 0, 0, -75, -75, -75, 0, -75, -75, 0, 0, 0, -75, 0, -75, 0, 0, 0, 0, 0, -75, -75, 0, 0, 0, -75, 0, -75, -75, -75, -75, 0, -75, 0, -75, 0, -75, 0, -75, 0, 0, 0, -75, -75, 0, 0, -75, -75,
+        // State 124
+        0, -120, -120, -120, -120, 0, -120, -120, 0, -120, 0, -120, -120, 0, 0, -120, 0, -120, 0, 0, -120, -120, -120, -120, 0, -120, -120, -120, -120, 0, 0, 0, 0, -120, -120, -120, -120, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -120, -120, -120, -120, 0,
+        // State 125
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 148, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, -228, 157,
+        // State 126
+        0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -124, 0, -124, -124, 0,
+        // State 127
+        0, 0, 0, 0, -149, 0, -149, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -149, 0, -149, -149, 0,
+        // State 128
+        -76, 0, 0, -76, 0, -76, -76, 0, 0, 0, 0, -76, -76, 0, 0, 0, 0, 0, 0, 0, -76, 0, 0, 0, 0, 0, 0, 0, 0, -76, -76, -76, 0, -76, -76, 0, 0, 0, -76, 0, -76, 0, 0, 0, 0, 0, -76, -76, 0, 0, 0, -76, 0, -76, -76, -76, -76, 0, -76, 0, -76, 0, -76, 0, -76, 0, 0, 0, -76, -76, 0, 0, -76, -76,
+        // State 129
+        0, -109, -109, -109, -109, 0, -109, -109, 0, -109, 0, -109, -109, 0, 0, -109, 0, -109, 0, 0, -109, -109, -109, -109, 0, -109, -109, -109, -109, 0, 0, 0, 0, -109, -109, -109, -109, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -109, -109, -109, -109, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -229, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -103, 0, 0, -103, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 211, 0,
+        // State 133
+        0, -96, -96, -96, -96, 0, -96, -96, 0, -96, 0, -96, -96, 0, 0, 0, 0, -96, 0, 0, -96, -96, -96, -96, 0, -96, -96, -96, -96, 0, 0, 0, 0, 0, -96, -96, -96, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -96, -96, -96, -96, 0,
+        // State 134
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 135
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 136
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 137
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 138
+        0, -158, -158, -158, -158, 0, -158, -158, 0, -158, 0, -158, -158, 0, 0, -158, 0, -158, 0, 0, -158, -158, -158, -158, 0, -158, -158, -158, -158, 0, 0, 0, 0, -158, -158, -158, -158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -158, -158, -158, -158, 0,
+        // State 139
+        0, -119, -119, -119, -119, 220, -119, -119, 221, -119, 222, -119, -119, 223, 0, -119, 0, -119, 224, 0, -119, -119, -119, -119, 225, -119, -119, -119, -119, 0, 0, 0, 0, -119, -119, -119, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, -119, -119, -119, 0,
+        // State 140
+        0, -174, -174, -174, -174, 0, -174, -174, 0, -174, 0, -174, -174, 0, 0, -174, 0, -174, 0, 0, -174, -174, -174, -174, 0, -174, -174, -174, -174, 0, 0, 0, 0, -174, -174, -174, -174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -174, -174, -174, -174, 0,
+        // State 141
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 142
+        135, 0, 0, 136, 0, 137, -80, 0, 0, 0, 0, -80, 138, 0, 0, 0, 0, 0, 0, 0, -80, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, -80, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, -80, 0, 0, -80, 157,
+        // State 143
+        0, -108, -108, -108, -108, 0, -108, -108, 0, -108, 0, -108, -108, 0, 0, -108, 0, -108, 0, 0, -108, -108, -108, -108, 0, -108, -108, -108, -108, 0, 0, 0, 0, -108, -108, -108, -108, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -108, -108, -108, -108, 0,
+        // State 144
+        0, -78, -78, -78, -78, 0, -78, -78, 0, -78, 0, -78, -78, 0, 0, -78, 0, -78, 0, 0, -78, -78, -78, -78, 0, -78, -78, -78, -78, 0, 0, 0, 0, -78, -78, -78, -78, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -78, -78, -78, -78, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 147
+        0, 0, 0, 0, 0, 232, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 233, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 235, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 237, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 238, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        135, 0, 0, 136, 0, 137, -227, 0, 0, 0, 0, -227, 138, 0, 0, 0, 0, 0, 0, 0, -227, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, -227, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, -227, 0, 0, -227, 157,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 240, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        0, -77, -77, -77, -77, 0, -77, -77, 0, -77, 0, -77, -77, 0, 0, -77, 0, -77, 0, 0, -77, -77, -77, -77, 0, -77, -77, -77, -77, 0, 0, 0, 0, -77, -77, -77, -77, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -77, -77, -77, -77, 0,
+        // State 155
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 156
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 157
+        0, 0, 0, 0, 0, 0, 243, 0, 0, 0, 0, 244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -223, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -223, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 161
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 247, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 248, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 249, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 164
+        0, 0, 0, 0, 0, -163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 251, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 252, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 168
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 254, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 170
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 256, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -201, 0, -201, 0, -201, 0, 0, 0, 0, -201, 0, 0, 0, 0, 0, 0, 0, -201, 0, 0, -201, 0, 0, 0, -201, 0, -201, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -238, 0, -238, 0, -238, 0, 0, 0, 0, -238, 0, 0, 0, 0, 0, 0, 0, -238, 0, 0, -238, 0, 0, 0, -238, 0, -238, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 257, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -83, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -230, 0, -230, 0, -230, 0, 0, 0, 0, -230, 0, 0, 0, 0, 0, 0, 0, -230, 0, 0, -230, 0, 0, 0, -230, 0, -230, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -40, 0,
+        // State 176
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -237, 0, -237, 0, -237, 0, 0, 0, 0, -237, 0, 0, 0, 0, 0, 0, 0, -237, 0, 0, -237, 0, 0, 0, -237, 0, -237, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -104, 0, 0, -104, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 259, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 260, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 261, 0, 0, 0, 0, 262, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 264, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -160, 0, -160, 0, -160, 0, 0, 0, 0, -160, 0, 0, 0, 0, 0, 0, 0, -160, 0, 0, -160, 0, 0, 0, -160, 0, -160, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -214, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 269, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 188
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 148, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, -228, 157,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 271, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 191
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 192
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 193
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 194
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 195
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 196
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 197
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 198
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 199
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 200
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 201
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 202
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 203
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 204
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 205
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 206
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 207
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 208
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 214, 141, 0, 142, 0, 0, 0, 0, 0, 0, 144, 0, 0, 0, 0, 0, 145, 0, 0, 0, 0, 0, 0, 0, 149, 0, 151, 0, 152, 0, 0, 0, 154, 0, 155, 0, 0, 0, 0, 0, 0, 0, 0, 157,
+        // State 209
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -212, 0,
+        // State 210
+        -74, 0, 0, -74, 0, -74, -74, 0, 0, 0, 0, -74, -74, 0, 0, 0, 0, 0, 0, 0, -74, 0, 0, 0, 0, 0, 0, 0, 0, -74, -74, -74, 0, -74, -74, 0, 0, 0, -74, -74, -74, -74, -74, -74, 0, 0, -74, -74, -74, 0, 0, -74, 0, -74, -74, -74, -74, 0, -74, -74, -74, 0, -74, -74, -74, -74, 0, 0, -74, -74, 0, 0, -74, -74,
+        // State 211
+        0, -241, -241, -241, -241, 0, -241, -241, 0, -241, 0, -241, -241, 0, 0, 291, 0, -241, 0, 0, -241, -241, -241, -241, 0, -241, -241, -241, -241, 0, 0, 0, 0, 292, -241, -241, -241, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -241, -241, -241, -241, 0,
+        // State 212
+        0, -242, -242, -242, -242, 0, -242, -242, 0, -242, 0, -242, -242, 0, 0, 0, 0, -242, 0, 0, -242, -242, -242, -242, 0, -242, -242, -242, -242, 0, 0, 0, 0, 0, -242, -242, -242, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -242, -242, -242, -242, 0,
+        // State 213
+        0, -119, -119, -119, -119, 220, -119, -119, 0, -119, 0, -119, -119, 0, 0, -119, 0, -119, 0, 0, -119, -119, -119, -119, 0, -119, -119, -119, -119, 0, 0, 0, 0, -119, -119, -119, -119, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -119, -119, -119, -119, 0,
+        // State 214
+        0, -245, -245, -245, -245, 0, -245, -245, 0, -245, 0, -245, -245, 0, 0, 0, 0, -245, 0, 0, -245, -245, -245, -245, 0, -245, -245, -245, -245, 0, 0, 0, 0, 0, -245, -245, -245, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -245, -245, -245, -245, 0,
+        // State 215
+        0, 0, 0, 0, 0, 0, 294, 0, 0, 0, 0, 295, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, -243, -243, -243, -243, 0, -243, -243, 0, -243, 0, -243, -243, 0, 0, 0, 0, -243, 0, 0, -243, -243, -243, -243, 0, -243, -243, -243, -243, 0, 0, 0, 0, 0, -243, -243, -243, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -243, -243, -243, -243, 0,
+        // State 217
+        0, -118, -118, -118, -118, 0, -118, -118, 0, -118, 0, -118, -118, 0, 0, -118, 0, -118, 0, 0, -118, -118, -118, -118, 0, -118, -118, -118, -118, 0, 0, 0, 0, -118, -118, -118, -118, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -118, -118, -118, -118, 0,
+        // State 218
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 219
+        135, 0, 0, 136, 0, 137, 298, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 220
+        -60, 0, 0, -60, 0, -60, 0, 0, 0, 0, 0, 0, -60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -60, -60, -60, 0, -60, 0, 0, 0, 0, -60, 0, -60, 0, 0, 0, 0, 0, -60, -60, 0, 0, 0, -60, 0, 0, -60, -60, -60, 0, -60, 0, -60, 0, -60, 0, -60, 0, 0, 0, -60, 0, 0, 0, 0, -60,
+        // State 221
+        -58, 0, 0, -58, 0, -58, 0, 0, 0, 0, 0, 0, -58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -58, -58, -58, 0, -58, 0, 0, 0, 0, -58, 0, -58, 0, 0, 0, 0, 0, -58, -58, 0, 0, 0, -58, 0, 0, -58, -58, -58, 0, -58, 0, -58, 0, -58, 0, -58, 0, 0, 0, -58, 0, 0, 0, 0, -58,
+        // State 222
+        -59, 0, 0, -59, 0, -59, 0, 0, 0, 0, 0, 0, -59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -59, -59, -59, 0, -59, 0, 0, 0, 0, -59, 0, -59, 0, 0, 0, 0, 0, -59, -59, 0, 0, 0, -59, 0, 0, -59, -59, -59, 0, -59, 0, -59, 0, -59, 0, -59, 0, 0, 0, -59, 0, 0, 0, 0, -59,
+        // State 223
+        -61, 0, 0, -61, 0, -61, 0, 0, 0, 0, 0, 0, -61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -61, -61, -61, 0, -61, 0, 0, 0, 0, -61, 0, -61, 0, 0, 0, 0, 0, -61, -61, 0, 0, 0, -61, 0, 0, -61, -61, -61, 0, -61, 0, -61, 0, -61, 0, -61, 0, 0, 0, -61, 0, 0, 0, 0, -61,
+        // State 224
+        -57, 0, 0, -57, 0, -57, 0, 0, 0, 0, 0, 0, -57, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -57, -57, -57, 0, -57, 0, 0, 0, 0, -57, 0, -57, 0, 0, 0, 0, 0, -57, -57, 0, 0, 0, -57, 0, 0, -57, -57, -57, 0, -57, 0, -57, 0, -57, 0, -57, 0, 0, 0, -57, 0, 0, 0, 0, -57,
+        // State 225
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 300, 0, 0, 0, 0, 0, 0, 0, 0, 301, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 302, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 226
+        0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -79, 0, 0, -79, 0,
+        // State 227
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 303, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -73, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 229
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 304, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 230
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 231
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 232
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 307, 0, 0, 0, 0, 308, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 233
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 309, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 234
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 235
+        -193, 0, 0, -193, 0, -193, -193, 0, 0, 0, 0, -193, -193, 0, 0, 0, 0, 0, 0, 0, -193, 0, 0, 0, 0, 0, 0, 0, 0, -193, -193, -193, 0, -193, -193, 0, 0, 0, -193, 0, -193, 0, 0, 0, 0, 0, -193, -193, 0, 0, 0, -193, 0, -193, -193, -193, -193, 0, -193, 0, -193, 0, -193, 0, -193, 0, 0, 0, -193, -193, 0, 0, -193, -193,
+        // State 236
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 311, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 237
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 312, 0, 0, 0, 0,
+        // State 238
+        0, 0, 0, 0, 0, 0, -226, 0, 0, 0, 0, -226, 0, 0, 0, 0, 0, 0, 0, 0, -226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -226, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -226, 0, 0, -226, 0,
+        // State 239
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 240
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 241
+        0, -244, -244, -244, -244, 0, -244, -244, 0, -244, 0, -244, -244, 0, 0, 0, 0, -244, 0, 0, -244, -244, -244, -244, 0, -244, -244, -244, -244, 0, 0, 0, 0, 0, -244, -244, -244, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -244, -244, -244, -244, 0,
+        // State 242
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -224, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -224, 0, 0, 0, 0,
+        // State 243
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 244
+        0, 0, 0, 0, 0, 0, -18, 0, 0, 0, 0, -18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, 0, 0, 0, -222, 0, 0, 0, 0, -222, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 246
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 247
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 248
+        0, 0, 0, 0, 0, -164, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 249
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -26, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 250
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -239, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -239, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 251
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 252
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 319, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 253
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -199, 0, -199, 0, -199, 0, 0, 0, 0, -199, 0, 0, 0, 0, 0, 0, 0, -199, 0, 0, -199, 0, 0, 0, -199, 0, -199, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 254
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 320, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 255
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 256
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -41, 0,
+        // State 257
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -152, 0,
+        // State 258
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -95, 0, -95, 0, -95, 0, 0, 0, 0, -95, 0, 0, 0, 0, 0, 0, 0, -95, 0, 0, -95, 0, 0, 0, -95, 0, -95, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 259
+        0, 0, 0, 0, 0, 0, -236, 0, 0, 0, 0, -236, 0, 0, 0, 0, 0, 0, 0, 0, -236, 0, 0, 0, -236, 0, -236, 0, 0, 0, 0, 0, 0, 0, -236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -236, 0, 0, -236, 0,
+        // State 260
+        0, 0, 0, 0, 0, 0, -234, 0, 0, 0, 0, -234, 0, 0, 0, 0, 0, 0, 0, 0, -234, 0, 0, 0, -234, 0, -234, 0, 0, 0, 0, 0, 0, 0, -234, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -234, 0, 0, -234, 0,
+        // State 261
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 262
+        0, 0, 0, 0, 0, 0, -21, 0, 0, 0, 0, -21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 263
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 323, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 264
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -159, 0, -159, 0, -159, 0, 0, 0, 0, -159, 0, 0, 0, 0, 0, 0, 0, -159, 0, 0, -159, 0, 0, 0, -159, 0, -159, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 265
+        0, -97, -97, -97, -97, 0, -97, -97, 0, -97, 0, -97, -97, 0, 0, 0, 0, -97, 0, 0, -97, -97, -97, -97, 0, -97, -97, -97, -97, 0, 0, 0, 0, 0, -97, -97, -97, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -97, -97, -97, -97, 0,
+        // State 266
+        0, -100, -100, -100, -100, 0, -100, -100, 0, -100, 0, -100, -100, 0, 0, 0, 0, -100, 0, 0, -100, -100, -100, -100, 0, -100, -100, -100, -100, 0, 0, 0, 0, 0, -100, -100, -100, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -100, -100, -100, -100, 0,
+        // State 267
+        0, -99, -99, -99, -99, 0, -99, -99, 0, -99, 0, -99, -99, 0, 0, 0, 0, -99, 0, 0, -99, -99, -99, -99, 0, -99, -99, -99, -99, 0, 0, 0, 0, 0, -99, -99, -99, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -99, -99, -99, -99, 0,
+        // State 268
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 269
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -213, 0,
+        // State 270
+        0, -111, -111, -111, -111, 0, -111, -111, 221, -111, 222, -111, -111, 223, 0, -111, 0, -111, 224, 0, -111, -111, -111, -111, 225, -111, -111, -111, -111, 0, 0, 0, 0, -111, -111, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, -111, -111, -111, 0,
+        // State 271
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 326, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 272
+        0, 0, 0, 0, 209, 0, -190, 0, 0, 0, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -190, 0, -190, -190, 0,
+        // State 273
+        0, -62, -62, -62, -62, 0, -62, -62, 0, -62, 0, -62, -62, 0, 0, 0, 0, -62, 0, 0, -62, -62, -62, -62, 0, -62, -62, -62, -62, 0, 0, 0, 0, 0, -62, -62, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -62, -62, -62, -62, 0,
+        // State 274
+        0, -63, -63, -63, -63, 0, -63, -63, 0, -63, 0, -63, -63, 0, 0, 0, 0, -63, 0, 0, -63, -63, -63, -63, 0, -63, -63, -63, -63, 0, 0, 0, 0, 0, -63, -63, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -63, -63, -63, -63, 0,
+        // State 275
+        0, -64, -64, -64, -64, 0, -64, -64, 0, -64, 0, -64, -64, 0, 0, 0, 0, -64, 0, 0, -64, -64, -64, -64, 0, -64, -64, -64, -64, 0, 0, 0, 0, 0, -64, -64, 188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -64, -64, -64, -64, 0,
+        // State 276
+        0, -65, 193, -65, -65, 0, -65, 194, 0, -65, 0, -65, -65, 0, 0, 0, 0, 195, 0, 0, -65, -65, -65, -65, 0, -65, -65, -65, -65, 0, 0, 0, 0, 0, -65, -65, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -65, -65, -65, -65, 0,
+        // State 277
+        0, -66, 193, -66, -66, 0, -66, 194, 0, -66, 0, -66, -66, 0, 0, 0, 0, 195, 0, 0, -66, -66, -66, -66, 0, -66, -66, -66, -66, 0, 0, 0, 0, 0, -66, -66, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -66, -66, -66, -66, 0,
+        // State 278
+        0, -67, 0, -67, -67, 0, -67, 0, 0, 196, 0, -67, 197, 0, 0, 0, 0, 0, 0, 0, -67, -67, -67, -67, 0, -67, -67, -67, -67, 0, 0, 0, 0, 0, -67, -67, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -67, -67, -67, -67, 0,
+        // State 279
+        0, -68, 0, -68, -68, 0, -68, 0, 0, 196, 0, -68, 197, 0, 0, 0, 0, 0, 0, 0, -68, -68, -68, -68, 0, -68, -68, -68, -68, 0, 0, 0, 0, 0, -68, -68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -68, -68, -68, -68, 0,
+        // State 280
+        0, -69, 0, -69, -69, 0, -69, 0, 0, 0, 0, -69, 0, 0, 0, 0, 0, 0, 0, 0, -69, -69, 198, -69, 0, -69, -69, -69, 199, 0, 0, 0, 0, 0, -69, -69, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -69, -69, -69, -69, 0,
+        // State 281
+        0, -70, 0, 200, -70, 0, -70, 0, 0, 0, 0, -70, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, 0, -70, 0, -70, -70, -70, 0, 0, 0, 0, 0, 0, -70, -70, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -70, -70, -70, -70, 0,
+        // State 282
+        0, 0, 0, 0, -89, 0, -89, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -89, 208, -89, -89, 0,
+        // State 283
+        0, 0, 0, 0, -90, 0, -90, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -90, 208, -90, -90, 0,
+        // State 284
+        0, 0, 0, 0, -91, 0, -91, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -91, 208, -91, -91, 0,
+        // State 285
+        0, 0, 0, 0, -92, 0, -92, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -92, 208, -92, -92, 0,
+        // State 286
+        0, 0, 0, 0, -93, 0, -93, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -93, 208, -93, -93, 0,
+        // State 287
+        0, 0, 0, 0, -94, 0, -94, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -94, 208, -94, -94, 0,
+        // State 288
+        0, -71, 0, 0, -71, 0, -71, 0, 0, 0, 0, -71, 0, 0, 0, 0, 0, 0, 0, 0, -71, -71, 0, -71, 0, -71, -71, -71, 0, 0, 0, 0, 0, 0, -71, 201, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -71, -71, -71, -71, 0,
+        // State 289
+        0, 0, 0, 0, -191, 0, -191, 0, 0, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -191, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -191, 0, -191, -191, 0,
+        // State 290
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 327, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 291
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 292
+        0, 0, 0, 0, 0, 0, 329, 0, 0, 0, 0, 330, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 293
+        0, -106, -106, -106, -106, 0, -106, -106, 0, -106, 0, -106, -106, 0, 0, -106, 0, -106, 0, 0, -106, -106, -106, -106, 0, -106, -106, -106, -106, 0, 0, 0, 0, -106, -106, -106, -106, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -106, -106, -106, -106, 0,
+        // State 294
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 295
+        0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -54, 0, 0, -54, 0,
+        // State 296
+        0, 0, 0, 0, 0, 0, 333, 0, 0, 0, 0, 295, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 297
+        0, -53, -53, -53, -53, 0, -53, -53, 0, -53, 0, -53, -53, 0, 0, -53, 0, -53, 0, 0, -53, -53, -53, -53, 0, -53, -53, -53, -53, 0, 0, 0, 0, -53, -53, -53, -53, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -53, -53, -53, -53, 0,
+        // State 298
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 334, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 335, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 299
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 336, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 300
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 337, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 301
+        0, -114, -114, -114, -114, 0, -114, -114, 0, -114, 0, -114, -114, 0, 0, -114, 0, -114, 0, 0, -114, -114, -114, -114, 0, -114, -114, -114, -114, 0, 0, 0, 0, -114, -114, -114, -114, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -114, -114, -114, -114, 0,
+        // State 302
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 303
+        0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -72, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 304
+        -171, 0, 0, -171, 0, -171, -171, 0, 0, 0, 0, -171, -171, 0, 0, 0, 0, 0, 0, 0, -171, 0, 0, 0, 0, 0, 0, 0, 0, -171, -171, -171, 0, -171, -171, 0, 0, 0, -171, 0, -171, 0, 339, 0, 0, 0, -171, -171, 0, 0, 0, -171, 0, -171, -171, -171, -171, 0, -171, 0, -171, 0, -171, 0, -171, 0, 0, 0, -171, -171, 0, 0, -171, -171,
+        // State 305
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 341, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 306
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 307
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 308
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 344, 0, 0, 0, 0, 345, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 309
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 346, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 310
+        0, 0, 0, 0, 0, 347, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 311
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 351, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -86, 0,
+        // State 312
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 352, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 313
+        -192, 0, 0, -192, 0, -192, -192, 0, 0, 0, 0, -192, -192, 0, 0, 0, 0, 0, 0, 0, -192, 0, 0, 0, 0, 0, 0, 0, 0, -192, -192, -192, 0, -192, -192, 0, 0, 0, -192, 0, -192, 0, 0, 0, 0, 0, -192, -192, 0, 0, 0, -192, 0, -192, -192, -192, -192, 0, -192, 0, -192, 0, -192, 0, -192, 0, 0, 0, -192, -192, 0, 0, -192, -192,
+        // State 314
+        0, 0, 0, 0, 0, 0, -19, 0, 0, 0, 0, -19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 315
+        0, 0, 0, 0, 0, 0, -221, 0, 0, 0, 0, -221, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 316
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 317
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 353, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 318
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 319
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -170, 0, -170, 0, -170, 0, 0, 0, 0, -170, 0, 0, 0, 0, 0, 0, 0, -170, 0, 0, -170, 0, 0, 0, -170, 0, -170, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 320
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 355, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 321
+        0, 0, 0, 0, 0, 0, -22, 0, 0, 0, 0, -22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 322
+        0, 0, 0, 0, 0, 0, -235, 0, 0, 0, 0, -235, 0, 0, 0, 0, 0, 0, 0, 0, -235, 0, 0, 0, -235, 0, -235, 0, 0, 0, 0, 0, 0, 0, -235, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -235, 0, 0, -235, 0,
+        // State 323
+        0, -98, -98, -98, -98, 0, -98, -98, 0, -98, 0, -98, -98, 0, 0, 0, 0, -98, 0, 0, -98, -98, -98, -98, 0, -98, -98, -98, -98, 0, 0, 0, 0, 0, -98, -98, -98, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -98, -98, -98, -98, 0,
+        // State 324
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 325
+        0, -117, -117, -117, -117, 0, -117, -117, 221, -117, 222, -117, -117, 223, 0, -117, 0, -117, 224, 0, -117, -117, -117, -117, 225, -117, -117, -117, -117, 0, 0, 0, 0, -117, -117, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, -117, -117, 0,
+        // State 326
+        0, -111, -111, -111, -111, 0, -111, -111, 0, -111, 0, -111, -111, 0, 0, -111, 0, -111, 0, 0, -111, -111, -111, -111, 0, -111, -111, -111, -111, 0, 0, 0, 0, -111, -111, -111, -111, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -111, -111, -111, -111, 0,
+        // State 327
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 358, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 328
+        0, -107, -107, -107, -107, 0, -107, -107, 0, -107, 0, -107, -107, 0, 0, -107, 0, -107, 0, 0, -107, -107, -107, -107, 0, -107, -107, -107, -107, 0, 0, 0, 0, -107, -107, -107, -107, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -107, -107, -107, -107, 0,
+        // State 329
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 330
+        0, 0, 0, 0, 0, 0, -13, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 331
+        0, 0, 0, 0, 0, 0, 360, 0, 0, 0, 0, 330, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 332
+        0, -51, -51, -51, -51, 0, -51, -51, 0, -51, 0, -51, -51, 0, 0, -51, 0, -51, 0, 0, -51, -51, -51, -51, 0, -51, -51, -51, -51, 0, 0, 0, 0, -51, -51, -51, -51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -51, -51, -51, -51, 0,
+        // State 333
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 361, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 334
+        0, -115, -115, -115, -115, 0, -115, -115, 0, -115, 0, -115, -115, 0, 0, -115, 0, -115, 0, 0, -115, -115, -115, -115, 0, -115, -115, -115, -115, 0, 0, 0, 0, -115, -115, -115, -115, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -115, -115, -115, -115, 0,
+        // State 335
+        0, -112, -112, -112, -112, 0, -112, -112, 0, -112, 0, -112, -112, 0, 0, -112, 0, -112, 0, 0, -112, -112, -112, -112, 0, -112, -112, -112, -112, 0, 0, 0, 0, -112, -112, -112, -112, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -112, -112, -112, -112, 0,
+        // State 336
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 362, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 337
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 338
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 147, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0,
+        // State 339
+        0, 0, 0, 0, 0, 0, 366, 0, 0, 0, 0, 367, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 340
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 341
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 369, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 342
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 370, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 343
+        0, 0, 0, 50, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 53, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 55, 56, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 60, 0, 0, 0, 0, 0, 0,
+        // State 344
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 345
+        0, 0, 0, 0, 0, 373, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 346
+        135, 0, 0, 136, 0, 137, 375, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 347
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 351, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -88, 0,
+        // State 348
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 377, 0,
+        // State 349
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 378, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -85, 0,
+        // State 350
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 379, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 351
+        0, 0, 0, 0, 0, 380, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 352
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -168, 0, -168, 0, -168, 0, 0, 0, 0, -168, 0, 0, 0, 0, 0, 0, 0, -168, 0, 0, -168, 0, 0, 0, -168, 0, -168, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 353
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 381, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 354
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -169, 0, -169, 0, -169, 0, 0, 0, 0, -169, 0, 0, 0, 0, 0, 0, 0, -169, 0, 0, -169, 0, 0, 0, -169, 0, -169, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 355
+        0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -55, 0, 0, -55, 0,
+        // State 356
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 357
+        0, -117, -117, -117, -117, 0, -117, -117, 0, -117, 0, -117, -117, 0, 0, -117, 0, -117, 0, 0, -117, -117, -117, -117, 0, -117, -117, -117, -117, 0, 0, 0, 0, -117, -117, -117, -117, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -117, -117, -117, -117, 0,
+        // State 358
+        0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 359
+        0, -52, -52, -52, -52, 0, -52, -52, 0, -52, 0, -52, -52, 0, 0, -52, 0, -52, 0, 0, -52, -52, -52, -52, 0, -52, -52, -52, -52, 0, 0, 0, 0, -52, -52, -52, -52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -52, -52, -52, -52, 0,
+        // State 360
+        0, -113, -113, -113, -113, 0, -113, -113, 0, -113, 0, -113, -113, 0, 0, -113, 0, -113, 0, 0, -113, -113, -113, -113, 0, -113, -113, -113, -113, 0, 0, 0, 0, -113, -113, -113, -113, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -113, -113, -113, -113, 0,
+        // State 361
+        0, -116, -116, -116, -116, 0, -116, -116, 0, -116, 0, -116, -116, 0, 0, -116, 0, -116, 0, 0, -116, -116, -116, -116, 0, -116, -116, -116, -116, 0, 0, 0, 0, -116, -116, -116, -116, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -116, -116, -116, -116, 0,
+        // State 362
+        -194, 0, 0, -194, 0, -194, -194, 0, 0, 0, 0, -194, -194, 0, 0, 0, 0, 0, 0, 0, -194, 0, 0, 0, 0, 0, 0, 0, 0, -194, -194, -194, 0, -194, -194, 0, 0, 0, -194, 0, -194, 0, 0, 0, 0, 0, -194, -194, 0, 0, 0, -194, 0, -194, -194, -194, -194, 0, -194, 0, -194, 0, -194, 0, -194, 0, 0, 0, -194, -194, 0, 0, -194, -194,
+        // State 363
+        -172, 0, 0, -172, 0, -172, -172, 0, 0, 0, 0, -172, -172, 0, 0, 0, 0, 0, 0, 0, -172, 0, 0, 0, 0, 0, 0, 0, 0, -172, -172, -172, 0, -172, -172, 0, 0, 0, -172, 0, -172, 0, 0, 0, 0, 0, -172, -172, 0, 0, 0, -172, 0, -172, -172, -172, -172, 0, -172, 0, -172, 0, -172, 0, -172, 0, 0, 0, -172, -172, 0, 0, -172, -172,
+        // State 364
+        -173, 0, 0, -173, 0, -173, -173, 0, 0, 0, 0, -173, -173, 0, 0, 0, 0, 0, 0, 0, -173, 0, 0, 0, 0, 0, 0, 0, 0, -173, -173, -173, 0, -173, -173, 0, 0, 0, -173, 0, -173, 0, 0, 0, 0, 0, -173, -173, 0, 0, 0, -173, 0, -173, -173, -173, -173, 0, -173, 0, -173, 0, -173, 0, -173, 0, 0, 0, -173, -173, 0, 0, -173, -173,
+        // State 365
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 383, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 366
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 229, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 230, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 367
+        0, 0, 0, 0, 0, 0, -8, 0, 0, 0, 0, -8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 368
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 369
+        -188, 0, 0, -188, 0, -188, 0, 0, 0, 0, 0, 0, -188, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -188, -188, -188, 0, -188, 0, 0, 0, 0, -188, 0, -188, 0, 0, 0, 0, 0, -188, -188, 0, 0, 0, -188, 0, -188, -188, -188, -188, 0, -188, 0, -188, 0, -188, 0, -188, 0, 0, 0, -188, 0, 0, 0, -188, -188,
+        // State 370
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 386, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 371
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 387, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 372
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 373
+        0, 0, 0, 0, 0, 0, 389, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 374
+        0, -197, -197, -197, -197, 0, -197, -197, 0, -197, 0, -197, -197, 0, 0, -197, 0, -197, 0, 0, -197, -197, -197, -197, 0, -197, -197, -197, -197, 0, 0, 0, 0, -197, -197, -197, -197, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -197, -197, -197, -197, 0,
+        // State 375
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 390, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -87, 0,
+        // State 376
+        0, -110, -110, -110, -110, 0, -110, -110, 0, -110, 0, -110, -110, 0, 0, -110, 0, -110, 0, 0, -110, -110, -110, -110, 0, -110, -110, -110, -110, 0, 0, 0, 0, -110, -110, -110, -110, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -110, -110, -110, -110, 0,
+        // State 377
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -45, 0,
+        // State 378
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 379
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 380
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -167, 0, -167, 0, -167, 0, 0, 0, 0, -167, 0, 0, 0, 0, 0, 0, 0, -167, 0, 0, -167, 0, 0, 0, -167, 0, -167, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 381
+        0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -56, 0, 0, -56, 0,
+        // State 382
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 383
+        0, 0, 0, 0, 0, 0, -9, 0, 0, 0, 0, -9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 384
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 394, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 385
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 386
+        -186, 0, 0, -186, 0, -186, 0, 0, 0, 0, 0, 0, -186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -186, -186, -186, 0, -186, 0, 0, 0, 0, -186, 0, -186, 0, 0, 0, 0, 0, -186, -186, 0, 0, 0, -186, 0, -186, -186, -186, -186, 0, -186, 0, -186, 0, -186, 0, -186, 0, 0, 0, -186, 0, 0, 0, -186, -186,
+        // State 387
+        0, 0, 0, 0, 0, 0, -203, 0, 0, 0, 0, 397, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 388
+        0, -198, -198, -198, -198, 0, -198, -198, 0, -198, 0, -198, -198, 0, 0, -198, 0, -198, 0, 0, -198, -198, -198, -198, 0, -198, -198, -198, -198, 0, 0, 0, 0, -198, -198, -198, -198, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -198, -198, -198, -198, 0,
+        // State 389
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -46, 0,
+        // State 390
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -155, 0,
+        // State 391
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 398, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 392
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 399, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 393
+        -187, 0, 0, -187, 0, -187, 0, 0, 0, 0, 0, 0, -187, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -187, -187, -187, 0, -187, 0, 0, 0, 0, -187, 0, -187, 0, 0, 0, 0, 0, -187, -187, 0, 0, 0, -187, 0, -187, -187, -187, -187, 0, -187, 0, -187, 0, -187, 0, -187, 0, 0, 0, -187, 0, 0, 0, -187, -187,
+        // State 394
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 400, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 395
+        0, 0, 0, 0, 0, 0, 401, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 396
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 402, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 397
+        135, 0, 0, 136, 0, 137, 0, 0, 0, 0, 0, 0, 138, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 139, 140, 141, 0, 142, 0, 0, 0, 0, 143, 0, 144, 0, 0, 0, 0, 0, 145, 146, 0, 0, 0, 147, 0, 0, 149, 150, 151, 0, 152, 0, 153, 0, 154, 0, 155, 0, 0, 0, 156, 0, 0, 0, 0, 157,
+        // State 398
+        -189, 0, 0, -189, 0, -189, 0, 0, 0, 0, 0, 0, -189, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -189, -189, -189, 0, -189, 0, 0, 0, 0, -189, 0, -189, 0, 0, 0, 0, 0, -189, -189, 0, 0, 0, -189, 0, -189, -189, -189, -189, 0, -189, 0, -189, 0, -189, 0, -189, 0, 0, 0, -189, 0, 0, 0, -189, -189,
+        // State 399
+        -185, 0, 0, -185, 0, -185, 0, 0, 0, 0, 0, 0, -185, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -185, -185, -185, 0, -185, 0, 0, 0, 0, -185, 0, -185, 0, 0, 0, 0, 0, -185, -185, 0, 0, 0, -185, 0, -185, -185, -185, -185, 0, -185, 0, -185, 0, -185, 0, -185, 0, 0, 0, -185, 0, 0, 0, -185, -185,
+        // State 400
+        0, -195, -195, -195, -195, 0, -195, -195, 0, -195, 0, -195, -195, 0, 0, -195, 0, -195, 0, 0, -195, -195, -195, -195, 0, -195, -195, -195, -195, 0, 0, 0, 0, -195, -195, -195, -195, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -195, -195, -195, -195, 0,
+        // State 401
+        0, 0, 0, 0, 0, 0, -204, 0, 0, 0, 0, 404, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 402
+        0, 0, 0, 0, 0, 0, -203, 0, 0, 0, 0, 397, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 403
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 406, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 404
+        0, 0, 0, 0, 0, 0, 407, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 405
+        0, 0, 0, 0, 0, 0, -205, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 406
+        0, -196, -196, -196, -196, 0, -196, -196, 0, -196, 0, -196, -196, 0, 0, -196, 0, -196, 0, 0, -196, -196, -196, -196, 0, -196, -196, -196, -196, 0, 0, 0, 0, -196, -196, -196, -196, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -196, -196, -196, -196, 0,
     ];
-    const __EOF_ACTION: &'static [i8] = &[
+    const __EOF_ACTION: &'static [i16] = &[
         // State 0
-        -39,
+        -208,
         // State 1
-        -34,
+        -179,
         // State 2
-        -37,
+        -207,
         // State 3
-        -40,
+        -178,
         // State 4
-        -51,
+        -183,
         // State 5
-        0,
+        -209,
         // State 6
-        0,
+        -177,
         // State 7
-        -38,
+        -175,
         // State 8
-        0,
+        -246,
         // State 9
-        -33,
+        -180,
         // State 10
-        0,
+        -176,
         // State 11
         0,
         // State 12
-        -31,
+        0,
         // State 13
         0,
         // State 14
@@ -205,13 +929,13 @@ mod __parse__Module {
         // State 18
         0,
         // State 19
-        0,
+        -184,
         // State 20
         0,
         // State 21
         0,
         // State 22
-        0,
+        -206,
         // State 23
         0,
         // State 24
@@ -245,21 +969,21 @@ mod __parse__Module {
         // State 38
         0,
         // State 39
-        -17,
+        0,
         // State 40
         0,
         // State 41
         0,
         // State 42
-        0,
+        -202,
         // State 43
         0,
         // State 44
-        -30,
+        0,
         // State 45
         0,
         // State 46
-        -18,
+        0,
         // State 47
         0,
         // State 48
@@ -286,337 +1010,2031 @@ mod __parse__Module {
         0,
         // State 59
         0,
-    ];
-    const __GOTO: &'static [i8] = &[
-        // State 0
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 3, 0, 4, 5, 0, 0, 0, 0,
-        // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 8, 0, 0, 0, 0, 0, 0, 0,
-        // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0,
-        // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0,
-        // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0,
-        // State 14
-        0, 0, 0, 0, 0, 26, 0, 27, 28, 29, 30, 0, 31, 32, 33, 34, 35, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 15
-        0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 25
-        0, 0, 0, 0, 0, 0, 0, 27, 28, 29, 30, 0, 46, 32, 33, 34, 35, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 31
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 33
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 38
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 54, 0, 0, 0,
-        // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0,
-        // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 45
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 35, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 0, 0, 35, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 49
-        0, 0, 0, 0, 0, 0, 0, 27, 28, 0, 0, 0, 0, 32, 58, 0, 35, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 50
-        0, 0, 0, 0, 0, 0, 0, 27, 28, 0, 0, 0, 0, 32, 59, 0, 35, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0,
-        // State 53
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 54
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 55
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 56
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 57
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 58
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        // State 59
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    ];
-    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
-        const __TERMINAL: &'static [&'static str] = &[
-            r###""(""###,
-            r###"")""###,
-            r###""*""###,
-            r###""+""###,
-            r###"",""###,
-            r###""-""###,
-            r###""->""###,
-            r###""/""###,
-            r###"":""###,
-            r###""Float""###,
-            r###""Id""###,
-            r###""Int""###,
-            r###""WS""###,
-            r###""def""###,
-            r###""export""###,
-            r###""f32""###,
-            r###""f64""###,
-            r###""i32""###,
-            r###""i64""###,
-            r###""u32""###,
-            r###""u64""###,
-            r###""{""###,
-            r###""}""###,
-        ];
-        __ACTION[(__state * 23)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
-            if state == 0 {
-                None
-            } else {
-                Some(terminal.to_string())
-            }
-        }).collect()
-    }
-    pub struct ModuleParser {
-        _priv: (),
-    }
-
-    impl ModuleParser {
-        pub fn new() -> ModuleParser {
-            ModuleParser {
-                _priv: (),
-            }
-        }
-
-        #[allow(dead_code)]
-        pub fn parse<
-            __TOKEN: __ToTriple<Error=crate::CompileError>,
-            __TOKENS: IntoIterator<Item=__TOKEN>,
-        >(
-            &self,
-            __tokens0: __TOKENS,
-        ) -> Result<Module, __lalrpop_util::ParseError<usize, Spanned<Tok>, crate::CompileError>>
-        {
-            let __tokens = __tokens0.into_iter();
-            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
-            let mut __states = vec![0_i8];
-            let mut __symbols = vec![];
-            let mut __integer;
-            let mut __lookahead;
-            let __last_location = &mut Default::default();
-            '__shift: loop {
-                __lookahead = match __tokens.next() {
-                    Some(Ok(v)) => v,
-                    None => break '__shift,
-                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
-                };
-                *__last_location = __lookahead.2.clone();
-                __integer = match __lookahead.1 {
-                    Spanned { node: Tok::OpenParen, .. } if true => 0,
-                    Spanned { node: Tok::CloseParen, .. } if true => 1,
-                    Spanned { node: Tok::Mul, .. } if true => 2,
-                    Spanned { node: Tok::Add, .. } if true => 3,
-                    Spanned { node: Tok::Comma, .. } if true => 4,
-                    Spanned { node: Tok::Sub, .. } if true => 5,
-                    Spanned { node: Tok::Arrow, .. } if true => 6,
-                    Spanned { node: Tok::Div, .. } if true => 7,
-                    Spanned { node: Tok::Colon, .. } if true => 8,
-                    Spanned { node: Tok::Float(..), .. } if true => 9,
-                    Spanned { node: Tok::Id(..), .. } if true => 10,
-                    Spanned { node: Tok::Int(..), .. } if true => 11,
-                    Spanned { node: Tok::WS, .. } if true => 12,
-                    Spanned { node: Tok::Def, .. } if true => 13,
-                    Spanned { node: Tok::Export, .. } if true => 14,
-                    Spanned { node: Tok::F32, .. } if true => 15,
-                    Spanned { node: Tok::F64, .. } if true => 16,
-                    Spanned { node: Tok::I32, .. } if true => 17,
-                    Spanned { node: Tok::I64, .. } if true => 18,
-                    Spanned { node: Tok::U32, .. } if true => 19,
-                    Spanned { node: Tok::U64, .. } if true => 20,
-                    Spanned { node: Tok::OpenBrace, .. } if true => 21,
-                    Spanned { node: Tok::CloseBrace, .. } if true => 22,
-                    _ => {
-                        let __state = *__states.last().unwrap() as usize;
-                        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                            token: Some(__lookahead),
-                            expected: __expected_tokens(__state),
-                        };
-                        return Err(__error);
-                    }
-                };
-                '__inner: loop {
-                    let __state = *__states.last().unwrap() as usize;
-                    let __action = __ACTION[__state * 23 + __integer];
-                    if __action > 0 {
-                        let __symbol = match __integer {
-                            0 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::OpenParen, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            1 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::CloseParen, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            2 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Mul, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            3 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Add, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            4 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Comma, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            5 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Sub, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            6 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Arrow, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            7 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Div, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            8 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Colon, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            9 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Float(..), .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            10 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Id(..), .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            11 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Int(..), .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            12 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::WS, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            13 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Def, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            14 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::Export, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            15 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::F32, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            16 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::F64, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            17 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::I32, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            18 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::I64, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            19 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::U32, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            20 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::U64, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            21 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::OpenBrace, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            22 => match __lookahead.1 {
-                                __tok @ Spanned { node: Tok::CloseBrace, .. } => __Symbol::Variant0((__tok)),
-                                _ => unreachable!(),
-                            },
-                            _ => unreachable!(),
-                        };
-                        __states.push(__action - 1);
-                        __symbols.push((__lookahead.0, __symbol, __lookahead.2));
-                        continue '__shift;
-                    } else if __action < 0 {
-                        if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                            if r.is_err() {
-                                return r;
-                            }
+        // State 60
+        0,
+        // State 61
+        -162,
+        // State 62
+        0,
+        // State 63
+        0,
+        // State 64
+        0,
+        // State 65
+        0,
+        // State 66
+        0,
+        // State 67
+        0,
+        // State 68
+        0,
+        // State 69
+        0,
+        // State 70
+        0,
+        // State 71
+        0,
+        // State 72
+        0,
+        // State 73
+        -200,
+        // State 74
+        0,
+        // State 75
+        0,
+        // State 76
+        0,
+        // State 77
+        0,
+        // State 78
+        0,
+        // State 79
+        0,
+        // State 80
+        0,
+        // State 81
+        0,
+        // State 82
+        0,
+        // State 83
+        0,
+        // State 84
+        0,
+        // State 85
+        0,
+        // State 86
+        0,
+        // State 87
+        -161,
+        // State 88
+        0,
+        // State 89
+        0,
+        // State 90
+        0,
+        // State 91
+        0,
+        // State 92
+        0,
+        // State 93
+        0,
+        // State 94
+        0,
+        // State 95
+        0,
+        // State 96
+        0,
+        // State 97
+        0,
+        // State 98
+        0,
+        // State 99
+        0,
+        // State 100
+        0,
+        // State 101
+        0,
+        // State 102
+        0,
+        // State 103
+        0,
+        // State 104
+        0,
+        // State 105
+        0,
+        // State 106
+        0,
+        // State 107
+        0,
+        // State 108
+        0,
+        // State 109
+        0,
+        // State 110
+        0,
+        // State 111
+        0,
+        // State 112
+        0,
+        // State 113
+        0,
+        // State 114
+        0,
+        // State 115
+        0,
+        // State 116
+        0,
+        // State 117
+        0,
+        // State 118
+        0,
+        // State 119
+        0,
+        // State 120
+        0,
+        // State 121
+        0,
+        // State 122
+        0,
+        // State 123
+        0,
+        // State 124
+        0,
+        // State 125
+        0,
+        // State 126
+        0,
+        // State 127
+        0,
+        // State 128
+        0,
+        // State 129
+        0,
+        // State 130
+        0,
+        // State 131
+        0,
+        // State 132
+        0,
+        // State 133
+        0,
+        // State 134
+        0,
+        // State 135
+        0,
+        // State 136
+        0,
+        // State 137
+        0,
+        // State 138
+        0,
+        // State 139
+        0,
+        // State 140
+        0,
+        // State 141
+        0,
+        // State 142
+        0,
+        // State 143
+        0,
+        // State 144
+        0,
+        // State 145
+        0,
+        // State 146
+        0,
+        // State 147
+        0,
+        // State 148
+        0,
+        // State 149
+        0,
+        // State 150
+        0,
+        // State 151
+        0,
+        // State 152
+        0,
+        // State 153
+        0,
+        // State 154
+        0,
+        // State 155
+        0,
+        // State 156
+        0,
+        // State 157
+        0,
+        // State 158
+        0,
+        // State 159
+        0,
+        // State 160
+        0,
+        // State 161
+        0,
+        // State 162
+        0,
+        // State 163
+        0,
+        // State 164
+        0,
+        // State 165
+        0,
+        // State 166
+        0,
+        // State 167
+        0,
+        // State 168
+        0,
+        // State 169
+        0,
+        // State 170
+        0,
+        // State 171
+        -201,
+        // State 172
+        -238,
+        // State 173
+        0,
+        // State 174
+        -230,
+        // State 175
+        0,
+        // State 176
+        0,
+        // State 177
+        -237,
+        // State 178
+        0,
+        // State 179
+        0,
+        // State 180
+        0,
+        // State 181
+        0,
+        // State 182
+        0,
+        // State 183
+        0,
+        // State 184
+        0,
+        // State 185
+        -160,
+        // State 186
+        0,
+        // State 187
+        0,
+        // State 188
+        0,
+        // State 189
+        0,
+        // State 190
+        0,
+        // State 191
+        0,
+        // State 192
+        0,
+        // State 193
+        0,
+        // State 194
+        0,
+        // State 195
+        0,
+        // State 196
+        0,
+        // State 197
+        0,
+        // State 198
+        0,
+        // State 199
+        0,
+        // State 200
+        0,
+        // State 201
+        0,
+        // State 202
+        0,
+        // State 203
+        0,
+        // State 204
+        0,
+        // State 205
+        0,
+        // State 206
+        0,
+        // State 207
+        0,
+        // State 208
+        0,
+        // State 209
+        0,
+        // State 210
+        -74,
+        // State 211
+        0,
+        // State 212
+        0,
+        // State 213
+        0,
+        // State 214
+        0,
+        // State 215
+        0,
+        // State 216
+        0,
+        // State 217
+        0,
+        // State 218
+        0,
+        // State 219
+        0,
+        // State 220
+        0,
+        // State 221
+        0,
+        // State 222
+        0,
+        // State 223
+        0,
+        // State 224
+        0,
+        // State 225
+        0,
+        // State 226
+        0,
+        // State 227
+        0,
+        // State 228
+        0,
+        // State 229
+        0,
+        // State 230
+        0,
+        // State 231
+        0,
+        // State 232
+        0,
+        // State 233
+        0,
+        // State 234
+        0,
+        // State 235
+        0,
+        // State 236
+        0,
+        // State 237
+        0,
+        // State 238
+        0,
+        // State 239
+        0,
+        // State 240
+        0,
+        // State 241
+        0,
+        // State 242
+        0,
+        // State 243
+        0,
+        // State 244
+        0,
+        // State 245
+        0,
+        // State 246
+        0,
+        // State 247
+        0,
+        // State 248
+        0,
+        // State 249
+        0,
+        // State 250
+        0,
+        // State 251
+        0,
+        // State 252
+        0,
+        // State 253
+        -199,
+        // State 254
+        0,
+        // State 255
+        0,
+        // State 256
+        0,
+        // State 257
+        0,
+        // State 258
+        -95,
+        // State 259
+        0,
+        // State 260
+        0,
+        // State 261
+        0,
+        // State 262
+        0,
+        // State 263
+        0,
+        // State 264
+        -159,
+        // State 265
+        0,
+        // State 266
+        0,
+        // State 267
+        0,
+        // State 268
+        0,
+        // State 269
+        0,
+        // State 270
+        0,
+        // State 271
+        0,
+        // State 272
+        0,
+        // State 273
+        0,
+        // State 274
+        0,
+        // State 275
+        0,
+        // State 276
+        0,
+        // State 277
+        0,
+        // State 278
+        0,
+        // State 279
+        0,
+        // State 280
+        0,
+        // State 281
+        0,
+        // State 282
+        0,
+        // State 283
+        0,
+        // State 284
+        0,
+        // State 285
+        0,
+        // State 286
+        0,
+        // State 287
+        0,
+        // State 288
+        0,
+        // State 289
+        0,
+        // State 290
+        0,
+        // State 291
+        0,
+        // State 292
+        0,
+        // State 293
+        0,
+        // State 294
+        0,
+        // State 295
+        0,
+        // State 296
+        0,
+        // State 297
+        0,
+        // State 298
+        0,
+        // State 299
+        0,
+        // State 300
+        0,
+        // State 301
+        0,
+        // State 302
+        0,
+        // State 303
+        0,
+        // State 304
+        0,
+        // State 305
+        0,
+        // State 306
+        0,
+        // State 307
+        0,
+        // State 308
+        0,
+        // State 309
+        0,
+        // State 310
+        0,
+        // State 311
+        0,
+        // State 312
+        0,
+        // State 313
+        0,
+        // State 314
+        0,
+        // State 315
+        0,
+        // State 316
+        0,
+        // State 317
+        0,
+        // State 318
+        0,
+        // State 319
+        -170,
+        // State 320
+        0,
+        // State 321
+        0,
+        // State 322
+        0,
+        // State 323
+        0,
+        // State 324
+        0,
+        // State 325
+        0,
+        // State 326
+        0,
+        // State 327
+        0,
+        // State 328
+        0,
+        // State 329
+        0,
+        // State 330
+        0,
+        // State 331
+        0,
+        // State 332
+        0,
+        // State 333
+        0,
+        // State 334
+        0,
+        // State 335
+        0,
+        // State 336
+        0,
+        // State 337
+        0,
+        // State 338
+        0,
+        // State 339
+        0,
+        // State 340
+        0,
+        // State 341
+        0,
+        // State 342
+        0,
+        // State 343
+        0,
+        // State 344
+        0,
+        // State 345
+        0,
+        // State 346
+        0,
+        // State 347
+        0,
+        // State 348
+        0,
+        // State 349
+        0,
+        // State 350
+        0,
+        // State 351
+        0,
+        // State 352
+        -168,
+        // State 353
+        0,
+        // State 354
+        -169,
+        // State 355
+        0,
+        // State 356
+        0,
+        // State 357
+        0,
+        // State 358
+        0,
+        // State 359
+        0,
+        // State 360
+        0,
+        // State 361
+        0,
+        // State 362
+        0,
+        // State 363
+        0,
+        // State 364
+        0,
+        // State 365
+        0,
+        // State 366
+        0,
+        // State 367
+        0,
+        // State 368
+        0,
+        // State 369
+        0,
+        // State 370
+        0,
+        // State 371
+        0,
+        // State 372
+        0,
+        // State 373
+        0,
+        // State 374
+        0,
+        // State 375
+        0,
+        // State 376
+        0,
+        // State 377
+        0,
+        // State 378
+        0,
+        // State 379
+        0,
+        // State 380
+        -167,
+        // State 381
+        0,
+        // State 382
+        0,
+        // State 383
+        0,
+        // State 384
+        0,
+        // State 385
+        0,
+        // State 386
+        0,
+        // State 387
+        0,
+        // State 388
+        0,
+        // State 389
+        0,
+        // State 390
+        0,
+        // State 391
+        0,
+        // State 392
+        0,
+        // State 393
+        0,
+        // State 394
+        0,
+        // State 395
+        0,
+        // State 396
+        0,
+        // State 397
+        0,
+        // State 398
+        0,
+        // State 399
+        0,
+        // State 400
+        0,
+        // State 401
+        0,
+        // State 402
+        0,
+        // State 403
+        0,
+        // State 404
+        0,
+        // State 405
+        0,
+        // State 406
+        0,
+    ];
+    const __GOTO: &'static [i16] = &[
+        // State 0
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 5, 0, 6, 0, 0, 0, 0, 0, 7, 0, 8, 9, 0, 0, 0, 0, 0, 0, 0, 10, 0, 11, 0, 0, 0,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 4, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 7, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 11, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 28
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 30
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0,
+        // State 32
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 65, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 69, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 39
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 75, 0, 0, 0, 0,
+        // State 40
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 43
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 78, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 79, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 80, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 81, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 83, 0, 0, 0, 0,
+        // State 46
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 50
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 86, 0, 0, 0, 0,
+        // State 51
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 87, 0, 0, 0, 0,
+        // State 53
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 88, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 90, 0, 0, 0, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 102, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 126, 127, 128, 129, 130, 0, 0, 0, 0, 0, 131, 0, 0, 0, 132, 133, 0, 0, 0, 0, 134, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 158, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 65
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 66
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 67
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 68
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 163, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 69
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 70
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 167, 0, 0, 0, 0,
+        // State 71
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 72
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 73
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 74
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 75
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 171, 0, 0, 0, 0,
+        // State 76
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 77
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 78
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 174, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 79
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 80
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 81
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 82
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 83
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 180, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 84
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 181, 0, 0, 0, 0,
+        // State 85
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 182, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 86
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 87
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 88
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 185, 0, 0, 0, 0,
+        // State 89
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 186, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 90
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 91
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 92
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 93
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 94
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 95
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 96
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 97
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 98
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 99
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 100
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 101
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 102, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 126, 127, 128, 129, 130, 0, 0, 0, 0, 0, 187, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 102
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 103
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 104
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 105
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 106
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 107
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 108
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 109
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 110
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 111
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 112
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 113
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 114
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 115
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 116
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 117
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 118
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 119
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 120
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 121
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 122
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 123
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 124
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 125
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 102, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 126, 127, 128, 129, 130, 0, 0, 0, 0, 0, 131, 0, 0, 0, 132, 210, 0, 0, 0, 0, 134, 0,
+        // State 126
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 127
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 128
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 129
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 130
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 131
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 132
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 133
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 134
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 213, 0,
+        // State 135
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 215, 0,
+        // State 136
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 216, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 137
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 217, 0,
+        // State 138
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 139
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 218, 0, 219, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 140
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 141
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 226, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 142
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 227, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 143
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 144
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 145
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 228, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 146
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 231, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 147
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 148
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 149
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 236, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 150
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 151
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 152
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 239, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 153
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 154
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 155
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 241, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 156
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 242, 0,
+        // State 157
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 158
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 159
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 245, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 160
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 246, 0, 0, 0, 0,
+        // State 161
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 162
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 163
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 250, 0, 0,
+        // State 164
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 165
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 166
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 167
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 253, 0, 0, 0, 0,
+        // State 168
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 169
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 255, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 170
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 171
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 172
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 173
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 174
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 175
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 176
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 258, 0, 0, 0, 0,
+        // State 177
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 178
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 179
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 180
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 181
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 182
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 263, 0, 0, 0, 0,
+        // State 183
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 184
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 265, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 185
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 186
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 187
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 266, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 267, 0, 268, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 188
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 102, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 112, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 126, 127, 128, 129, 130, 0, 0, 0, 0, 0, 131, 0, 0, 0, 132, 270, 0, 0, 0, 0, 134, 0,
+        // State 189
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 190
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 272, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 191
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 120, 121, 273, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 128, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 192
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 274, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 193
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 275, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 194
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 276, 0, 0, 212, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 195
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 277, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 196
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 278, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 197
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 279, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 198
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 0, 0, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 280, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 199
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 0, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 281, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 200
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 0, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 282, 0, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 201
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 283, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 202
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 284, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 203
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 285, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 204
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 286, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 205
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 287, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 206
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 288, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 207
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 0, 0, 0, 0, 103, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 289, 0, 0, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 208
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 0, 103, 0, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 0, 212, 0, 115, 116, 117, 118, 119, 120, 290, 0, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 0, 125, 0, 0, 0, 0, 0, 0, 0, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 134, 0,
+        // State 209
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 210
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 211
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 212
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 213
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 218, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 214
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 215
+        0, 0, 0, 0, 0, 0, 0, 293, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 216
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 217
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 218
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 296, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 219
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 297, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 220
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 221
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 222
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 223
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 224
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 225
+        0, 0, 0, 0, 0, 0, 0, 299, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 226
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 227
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 228
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 229
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 230
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 305, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 231
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 306, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 232
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 233
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 234
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 310, 0, 0, 0, 0,
+        // State 235
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 236
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 237
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 238
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 239
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 313, 0, 0, 0, 0,
+        // State 240
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 314, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 241
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 242
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 243
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 315, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 244
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 245
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 246
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 316, 0, 0, 0, 0,
+        // State 247
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 317, 0, 0,
+        // State 248
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 249
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 250
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 251
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 318, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 252
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 253
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 254
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 255
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 321, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 256
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 257
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 258
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 259
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 260
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 261
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 322, 0, 0, 0, 0,
+        // State 262
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 263
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 264
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 265
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 266
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 267
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 268
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 324, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 267, 0, 268, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 269
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 270
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 325, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 271
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 272
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 273
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 274
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 275
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 276
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 277
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 278
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 279
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 280
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 281
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 282
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 283
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 284
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 285
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 286
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 287
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 288
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 289
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 290
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 291
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 328, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 292
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 293
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 294
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 331, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 295
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 296
+        0, 0, 0, 0, 0, 0, 0, 332, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 297
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 298
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 299
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 331, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 300
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 301
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 302
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 338, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 303
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 304
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 305
+        0, 0, 0, 0, 340, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 306
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 342, 0, 0, 0, 0,
+        // State 307
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 343, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 308
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 309
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 310
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 311
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 348, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 349, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 350, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 312
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 313
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 314
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 315
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 316
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 317
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 318
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 354, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 319
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 320
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 321
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 322
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 323
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 324
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 356, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 325
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 357, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 326
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 327
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 328
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 329
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 359, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 330
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 331
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 332
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 333
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 359, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 334
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 335
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 336
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 337
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 363, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 338
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 364, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 365, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 339
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 340
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 368, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 341
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 342
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 343
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 48, 0, 0, 0, 0, 0, 371, 0, 0, 0, 0,
+        // State 344
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 372, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 345
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 346
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 374, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 347
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 376, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 348
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 349
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 350
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 351
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 352
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 353
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 354
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 355
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 356
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 382, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 357
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 358
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 359
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 360
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 361
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 362
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 363
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 364
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 365
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 366
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 384, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 367
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 368
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 385, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 369
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 370
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 371
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 372
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 388, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 373
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 374
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 375
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 376
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 377
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 378
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 391, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 379
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 392, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 380
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 381
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 382
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 393, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 383
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 384
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 385
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 395, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 386
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 387
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 396, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 388
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 389
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 390
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 391
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 392
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 393
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 394
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 395
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 396
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 397
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 91, 0, 92, 93, 94, 95, 96, 97, 98, 99, 100, 101, 0, 0, 179, 103, 104, 0, 0, 105, 106, 107, 108, 109, 110, 0, 111, 0, 403, 113, 114, 115, 116, 117, 118, 119, 120, 121, 122, 0, 0, 0, 0, 0, 123, 0, 0, 0, 0, 124, 125, 0, 0, 0, 0, 127, 128, 129, 130, 0, 0, 0, 0, 0, 0, 0, 0, 0, 132, 0, 0, 0, 0, 0, 134, 0,
+        // State 398
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 399
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 400
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 401
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 402
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 405, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 403
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 404
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 405
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 406
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""!""###,
+            r###""!=""###,
+            r###""%""###,
+            r###""&""###,
+            r###""&&""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""*=""###,
+            r###""+""###,
+            r###""+=""###,
+            r###"",""###,
+            r###""-""###,
+            r###""-=""###,
+            r###""->""###,
+            r###"".""###,
+            r###""..""###,
+            r###""/""###,
+            r###""/=""###,
+            r###"":""###,
+            r###"";""###,
+            r###""<""###,
+            r###""<<""###,
+            r###""<=""###,
+            r###""=""###,
+            r###""==""###,
+            r###"">""###,
+            r###"">=""###,
+            r###"">>""###,
+            r###""Float""###,
+            r###""Id""###,
+            r###""Int""###,
+            r###""WS""###,
+            r###""[""###,
+            r###""]""###,
+            r###""^""###,
+            r###""as""###,
+            r###""bool""###,
+            r###""break""###,
+            r###""const""###,
+            r###""continue""###,
+            r###""def""###,
+            r###""else""###,
+            r###""export""###,
+            r###""f32""###,
+            r###""f64""###,
+            r###""false""###,
+            r###""for""###,
+            r###""global""###,
+            r###""i32""###,
+            r###""i64""###,
+            r###""if""###,
+            r###""in""###,
+            r###""let""###,
+            r###""load""###,
+            r###""loop""###,
+            r###""memory""###,
+            r###""mut""###,
+            r###""new""###,
+            r###""newtype""###,
+            r###""return""###,
+            r###""saturating""###,
+            r###""store""###,
+            r###""struct""###,
+            r###""true""###,
+            r###""type""###,
+            r###""u32""###,
+            r###""u64""###,
+            r###""while""###,
+            r###""{""###,
+            r###""|""###,
+            r###""||""###,
+            r###""}""###,
+            r###""~""###,
+        ];
+        __ACTION[(__state * 74)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    pub struct ModuleParser {
+        _priv: (),
+    }
+
+    impl ModuleParser {
+        pub fn new() -> ModuleParser {
+            ModuleParser {
+                _priv: (),
+            }
+        }
+
+        #[allow(dead_code)]
+        pub fn parse<
+            __TOKEN: __ToTriple<Error=crate::CompileError>,
+            __TOKENS: IntoIterator<Item=__TOKEN>,
+        >(
+            &self,
+            __tokens0: __TOKENS,
+        ) -> Result<Module, __lalrpop_util::ParseError<usize, Spanned<Tok>, crate::CompileError>>
+        {
+            let __tokens = __tokens0.into_iter();
+            let mut __tokens = __tokens.map(|t| __ToTriple::to_triple(t));
+            let mut __states = vec![0_i16];
+            let mut __symbols = vec![];
+            let mut __integer;
+            let mut __lookahead;
+            let __last_location = &mut Default::default();
+            '__shift: loop {
+                __lookahead = match __tokens.next() {
+                    Some(Ok(v)) => v,
+                    None => break '__shift,
+                    Some(Err(e)) => return Err(__lalrpop_util::ParseError::User { error: e }),
+                };
+                *__last_location = __lookahead.2.clone();
+                __integer = match __lookahead.1 {
+                    Spanned { node: Tok::Not, .. } if true => 0,
+                    Spanned { node: Tok::NotEqual, .. } if true => 1,
+                    Spanned { node: Tok::Rem, .. } if true => 2,
+                    Spanned { node: Tok::BitAnd, .. } if true => 3,
+                    Spanned { node: Tok::AndAnd, .. } if true => 4,
+                    Spanned { node: Tok::OpenParen, .. } if true => 5,
+                    Spanned { node: Tok::CloseParen, .. } if true => 6,
+                    Spanned { node: Tok::Mul, .. } if true => 7,
+                    Spanned { node: Tok::MulAssign, .. } if true => 8,
+                    Spanned { node: Tok::Add, .. } if true => 9,
+                    Spanned { node: Tok::AddAssign, .. } if true => 10,
+                    Spanned { node: Tok::Comma, .. } if true => 11,
+                    Spanned { node: Tok::Sub, .. } if true => 12,
+                    Spanned { node: Tok::SubAssign, .. } if true => 13,
+                    Spanned { node: Tok::Arrow, .. } if true => 14,
+                    Spanned { node: Tok::Dot, .. } if true => 15,
+                    Spanned { node: Tok::DotDot, .. } if true => 16,
+                    Spanned { node: Tok::Div, .. } if true => 17,
+                    Spanned { node: Tok::DivAssign, .. } if true => 18,
+                    Spanned { node: Tok::Colon, .. } if true => 19,
+                    Spanned { node: Tok::Semicolon, .. } if true => 20,
+                    Spanned { node: Tok::Less, .. } if true => 21,
+                    Spanned { node: Tok::ShiftLeft, .. } if true => 22,
+                    Spanned { node: Tok::LessEqual, .. } if true => 23,
+                    Spanned { node: Tok::Equals, .. } if true => 24,
+                    Spanned { node: Tok::EqualEqual, .. } if true => 25,
+                    Spanned { node: Tok::Greater, .. } if true => 26,
+                    Spanned { node: Tok::GreaterEqual, .. } if true => 27,
+                    Spanned { node: Tok::ShiftRight, .. } if true => 28,
+                    Spanned { node: Tok::Float(..), .. } if true => 29,
+                    Spanned { node: Tok::Id(..), .. } if true => 30,
+                    Spanned { node: Tok::Int(..), .. } if true => 31,
+                    Spanned { node: Tok::WS, .. } if true => 32,
+                    Spanned { node: Tok::OpenBracket, .. } if true => 33,
+                    Spanned { node: Tok::CloseBracket, .. } if true => 34,
+                    Spanned { node: Tok::BitXor, .. } if true => 35,
+                    Spanned { node: Tok::As, .. } if true => 36,
+                    Spanned { node: Tok::Bool, .. } if true => 37,
+                    Spanned { node: Tok::Break, .. } if true => 38,
+                    Spanned { node: Tok::Const, .. } if true => 39,
+                    Spanned { node: Tok::Continue, .. } if true => 40,
+                    Spanned { node: Tok::Def, .. } if true => 41,
+                    Spanned { node: Tok::Else, .. } if true => 42,
+                    Spanned { node: Tok::Export, .. } if true => 43,
+                    Spanned { node: Tok::F32, .. } if true => 44,
+                    Spanned { node: Tok::F64, .. } if true => 45,
+                    Spanned { node: Tok::False, .. } if true => 46,
+                    Spanned { node: Tok::For, .. } if true => 47,
+                    Spanned { node: Tok::Global, .. } if true => 48,
+                    Spanned { node: Tok::I32, .. } if true => 49,
+                    Spanned { node: Tok::I64, .. } if true => 50,
+                    Spanned { node: Tok::If, .. } if true => 51,
+                    Spanned { node: Tok::In, .. } if true => 52,
+                    Spanned { node: Tok::Let, .. } if true => 53,
+                    Spanned { node: Tok::Load, .. } if true => 54,
+                    Spanned { node: Tok::Loop, .. } if true => 55,
+                    Spanned { node: Tok::Memory, .. } if true => 56,
+                    Spanned { node: Tok::Mut, .. } if true => 57,
+                    Spanned { node: Tok::New, .. } if true => 58,
+                    Spanned { node: Tok::Newtype, .. } if true => 59,
+                    Spanned { node: Tok::Return, .. } if true => 60,
+                    Spanned { node: Tok::Saturating, .. } if true => 61,
+                    Spanned { node: Tok::Store, .. } if true => 62,
+                    Spanned { node: Tok::Struct, .. } if true => 63,
+                    Spanned { node: Tok::True, .. } if true => 64,
+                    Spanned { node: Tok::Type, .. } if true => 65,
+                    Spanned { node: Tok::U32, .. } if true => 66,
+                    Spanned { node: Tok::U64, .. } if true => 67,
+                    Spanned { node: Tok::While, .. } if true => 68,
+                    Spanned { node: Tok::OpenBrace, .. } if true => 69,
+                    Spanned { node: Tok::BitOr, .. } if true => 70,
+                    Spanned { node: Tok::OrOr, .. } if true => 71,
+                    Spanned { node: Tok::CloseBrace, .. } if true => 72,
+                    Spanned { node: Tok::Tilde, .. } if true => 73,
+                    _ => {
+                        let __state = *__states.last().unwrap() as usize;
+                        let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                            token: Some(__lookahead),
+                            expected: __expected_tokens(__state),
+                        };
+                        return Err(__error);
+                    }
+                };
+                '__inner: loop {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __action = __ACTION[__state * 74 + __integer];
+                    if __action > 0 {
+                        let __symbol = match __integer {
+                            0 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Not, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            1 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::NotEqual, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            2 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Rem, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            3 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::BitAnd, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            4 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::AndAnd, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            5 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::OpenParen, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            6 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::CloseParen, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            7 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Mul, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            8 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::MulAssign, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            9 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Add, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            10 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::AddAssign, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            11 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Comma, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            12 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Sub, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            13 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::SubAssign, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            14 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Arrow, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            15 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Dot, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            16 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::DotDot, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            17 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Div, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            18 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::DivAssign, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            19 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Colon, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            20 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Semicolon, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            21 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Less, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            22 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::ShiftLeft, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            23 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::LessEqual, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            24 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Equals, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            25 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::EqualEqual, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            26 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Greater, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            27 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::GreaterEqual, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            28 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::ShiftRight, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            29 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Float(..), .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            30 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Id(..), .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            31 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Int(..), .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            32 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::WS, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            33 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::OpenBracket, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            34 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::CloseBracket, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            35 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::BitXor, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            36 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::As, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            37 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Bool, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            38 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Break, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            39 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Const, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            40 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Continue, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            41 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Def, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            42 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Else, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            43 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Export, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            44 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::F32, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            45 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::F64, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            46 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::False, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            47 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::For, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            48 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Global, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            49 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::I32, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            50 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::I64, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            51 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::If, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            52 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::In, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            53 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Let, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            54 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Load, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            55 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Loop, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            56 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Memory, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            57 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Mut, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            58 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::New, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            59 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Newtype, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            60 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Return, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            61 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Saturating, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            62 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Store, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            63 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Struct, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            64 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::True, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            65 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Type, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            66 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::U32, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            67 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::U64, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            68 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::While, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            69 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::OpenBrace, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            70 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::BitOr, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            71 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::OrOr, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            72 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::CloseBrace, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            73 => match __lookahead.1 {
+                                __tok @ Spanned { node: Tok::Tilde, .. } => __Symbol::Variant0((__tok)),
+                                _ => unreachable!(),
+                            },
+                            _ => unreachable!(),
+                        };
+                        __states.push(__action - 1);
+                        __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                        continue '__shift;
+                    } else if __action < 0 {
+                        if let Some(r) = __reduce(__action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                            if r.is_err() {
+                                return r;
+                            }
                             return Err(__lalrpop_util::ParseError::ExtraToken { token: __lookahead });
                         }
                     } else {
@@ -631,1825 +3049,9562 @@ mod __parse__Module {
                     }
                 }
             }
-            loop {
-                let __state = *__states.last().unwrap() as usize;
-                let __action = __EOF_ACTION[__state];
-                if __action < 0 {
-                    if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
-                        return r;
-                    }
-                } else {
-                    let mut __err_lookahead = None;
-                    let mut __err_integer: Option<usize> = None;
-                    let __state = *__states.last().unwrap() as usize;
-                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
-                        token: __err_lookahead,
-                        expected: __expected_tokens(__state),
-                    };
-                    return Err(__error)
-                }
+            loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __EOF_ACTION[__state];
+                if __action < 0 {
+                    if let Some(r) = __reduce(__action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                } else {
+                    let mut __err_lookahead = None;
+                    let mut __err_integer: Option<usize> = None;
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: __err_lookahead,
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+    }
+    pub(crate) fn __reduce<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<Module,__lalrpop_util::ParseError<usize, Spanned<Tok>, crate::CompileError>>>
+    {
+        let (__pop_states, __symbol, __nonterminal) = match -__action {
+            1 => {
+                __reduce1(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            2 => {
+                __reduce2(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            3 => {
+                __reduce3(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            4 => {
+                __reduce4(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            5 => {
+                __reduce5(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            6 => {
+                __reduce6(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            7 => {
+                __reduce7(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            8 => {
+                __reduce8(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            9 => {
+                __reduce9(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            10 => {
+                __reduce10(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            11 => {
+                __reduce11(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            12 => {
+                __reduce12(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            13 => {
+                __reduce13(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            14 => {
+                __reduce14(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            15 => {
+                __reduce15(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            16 => {
+                __reduce16(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            17 => {
+                __reduce17(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            18 => {
+                __reduce18(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            19 => {
+                __reduce19(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            20 => {
+                __reduce20(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            21 => {
+                __reduce21(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            22 => {
+                __reduce22(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            23 => {
+                __reduce23(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            24 => {
+                __reduce24(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            25 => {
+                __reduce25(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            26 => {
+                __reduce26(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            27 => {
+                __reduce27(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            28 => {
+                __reduce28(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            29 => {
+                __reduce29(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            30 => {
+                __reduce30(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            31 => {
+                __reduce31(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            32 => {
+                __reduce32(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            33 => {
+                __reduce33(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            34 => {
+                __reduce34(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            35 => {
+                __reduce35(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            36 => {
+                __reduce36(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            37 => {
+                __reduce37(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            38 => {
+                __reduce38(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            39 => {
+                __reduce39(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            40 => {
+                __reduce40(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            41 => {
+                __reduce41(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            42 => {
+                __reduce42(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            43 => {
+                __reduce43(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            44 => {
+                __reduce44(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            45 => {
+                __reduce45(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            46 => {
+                __reduce46(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            47 => {
+                __reduce47(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            48 => {
+                __reduce48(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            49 => {
+                __reduce49(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            50 => {
+                __reduce50(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            51 => {
+                __reduce51(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            52 => {
+                __reduce52(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            53 => {
+                __reduce53(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            54 => {
+                __reduce54(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            55 => {
+                __reduce55(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            56 => {
+                __reduce56(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            57 => {
+                __reduce57(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            58 => {
+                __reduce58(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            59 => {
+                __reduce59(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            60 => {
+                __reduce60(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            61 => {
+                __reduce61(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            62 => {
+                __reduce62(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            63 => {
+                __reduce63(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            64 => {
+                __reduce64(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            65 => {
+                __reduce65(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            66 => {
+                __reduce66(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            67 => {
+                __reduce67(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            68 => {
+                __reduce68(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            69 => {
+                __reduce69(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            70 => {
+                __reduce70(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            71 => {
+                __reduce71(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            72 => {
+                __reduce72(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            73 => {
+                __reduce73(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            74 => {
+                __reduce74(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            75 => {
+                __reduce75(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            76 => {
+                __reduce76(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            77 => {
+                __reduce77(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            78 => {
+                __reduce78(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            79 => {
+                __reduce79(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            80 => {
+                __reduce80(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            81 => {
+                __reduce81(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            82 => {
+                __reduce82(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            83 => {
+                __reduce83(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            84 => {
+                __reduce84(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            85 => {
+                __reduce85(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            86 => {
+                __reduce86(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            87 => {
+                __reduce87(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            88 => {
+                __reduce88(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            89 => {
+                __reduce89(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            90 => {
+                __reduce90(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            91 => {
+                __reduce91(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            92 => {
+                __reduce92(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            93 => {
+                __reduce93(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            94 => {
+                __reduce94(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            95 => {
+                __reduce95(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            96 => {
+                __reduce96(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            97 => {
+                __reduce97(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            98 => {
+                __reduce98(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            99 => {
+                __reduce99(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            100 => {
+                __reduce100(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            101 => {
+                __reduce101(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            102 => {
+                __reduce102(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            103 => {
+                __reduce103(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            104 => {
+                __reduce104(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            105 => {
+                __reduce105(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            106 => {
+                __reduce106(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            107 => {
+                __reduce107(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            108 => {
+                __reduce108(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            109 => {
+                __reduce109(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            110 => {
+                __reduce110(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            111 => {
+                __reduce111(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            112 => {
+                __reduce112(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            113 => {
+                __reduce113(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            114 => {
+                __reduce114(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            115 => {
+                __reduce115(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            116 => {
+                // Expression1 = "[", Expression, ";", "Int", "]" => ActionFn(79);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant0(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant4(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = match super::__action79::<>(__sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+                (5, __symbol, 61)
+            }
+            117 => {
+                __reduce117(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            118 => {
+                __reduce118(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            119 => {
+                __reduce119(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            120 => {
+                __reduce120(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            121 => {
+                __reduce121(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            122 => {
+                __reduce122(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            123 => {
+                __reduce123(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            124 => {
+                __reduce124(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            125 => {
+                __reduce125(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            126 => {
+                __reduce126(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            127 => {
+                __reduce127(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            128 => {
+                __reduce128(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            129 => {
+                __reduce129(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            130 => {
+                __reduce130(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            131 => {
+                __reduce131(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            132 => {
+                __reduce132(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            133 => {
+                __reduce133(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            134 => {
+                __reduce134(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            135 => {
+                __reduce135(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            136 => {
+                __reduce136(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            137 => {
+                __reduce137(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            138 => {
+                __reduce138(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
             }
+            139 => {
+                __reduce139(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            140 => {
+                __reduce140(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            141 => {
+                __reduce141(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            142 => {
+                __reduce142(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            143 => {
+                __reduce143(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            144 => {
+                __reduce144(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            145 => {
+                __reduce145(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            146 => {
+                __reduce146(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            147 => {
+                __reduce147(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            148 => {
+                __reduce148(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            149 => {
+                __reduce149(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            150 => {
+                __reduce150(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            151 => {
+                __reduce151(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            152 => {
+                __reduce152(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            153 => {
+                __reduce153(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            154 => {
+                __reduce154(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            155 => {
+                __reduce155(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            156 => {
+                __reduce156(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            157 => {
+                __reduce157(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            158 => {
+                __reduce158(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            159 => {
+                __reduce159(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            160 => {
+                __reduce160(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            161 => {
+                __reduce161(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            162 => {
+                __reduce162(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            163 => {
+                __reduce163(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            164 => {
+                __reduce164(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            165 => {
+                __reduce165(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            166 => {
+                __reduce166(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            167 => {
+                __reduce167(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            168 => {
+                __reduce168(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            169 => {
+                __reduce169(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            170 => {
+                __reduce170(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            171 => {
+                __reduce171(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            172 => {
+                __reduce172(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            173 => {
+                __reduce173(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            174 => {
+                __reduce174(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            175 => {
+                __reduce175(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            176 => {
+                __reduce176(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            177 => {
+                __reduce177(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            178 => {
+                __reduce178(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            179 => {
+                __reduce179(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            180 => {
+                __reduce180(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            181 => {
+                __reduce181(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            182 => {
+                __reduce182(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            183 => {
+                __reduce183(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            184 => {
+                __reduce184(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            185 => {
+                __reduce185(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            186 => {
+                __reduce186(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            187 => {
+                __reduce187(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            188 => {
+                __reduce188(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            189 => {
+                __reduce189(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            190 => {
+                __reduce190(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            191 => {
+                __reduce191(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            192 => {
+                __reduce192(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            193 => {
+                __reduce193(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            194 => {
+                __reduce194(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            195 => {
+                __reduce195(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            196 => {
+                __reduce196(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            197 => {
+                __reduce197(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            198 => {
+                __reduce198(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            199 => {
+                __reduce199(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            200 => {
+                __reduce200(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            201 => {
+                __reduce201(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            202 => {
+                __reduce202(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            203 => {
+                __reduce203(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            204 => {
+                __reduce204(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            205 => {
+                __reduce205(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            206 => {
+                __reduce206(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            207 => {
+                __reduce207(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            208 => {
+                __reduce208(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            209 => {
+                __reduce209(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            210 => {
+                __reduce210(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            211 => {
+                __reduce211(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            212 => {
+                __reduce212(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            213 => {
+                __reduce213(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            214 => {
+                __reduce214(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            215 => {
+                __reduce215(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            216 => {
+                __reduce216(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            217 => {
+                __reduce217(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            218 => {
+                __reduce218(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            219 => {
+                __reduce219(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            220 => {
+                __reduce220(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            221 => {
+                __reduce221(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            222 => {
+                __reduce222(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            223 => {
+                __reduce223(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            224 => {
+                __reduce224(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            225 => {
+                __reduce225(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            226 => {
+                __reduce226(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            227 => {
+                __reduce227(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            228 => {
+                __reduce228(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            229 => {
+                __reduce229(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            230 => {
+                __reduce230(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            231 => {
+                __reduce231(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            232 => {
+                __reduce232(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            233 => {
+                __reduce233(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            234 => {
+                __reduce234(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            235 => {
+                // Type = "[", Type, ";", "Int", "]" => ActionFn(285);
+                let __sym4 = __pop_Variant0(__symbols);
+                let __sym3 = __pop_Variant0(__symbols);
+                let __sym2 = __pop_Variant0(__symbols);
+                let __sym1 = __pop_Variant8(__symbols);
+                let __sym0 = __pop_Variant0(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym4.2.clone();
+                let __nt = match super::__action285::<>(__sym0, __sym1, __sym2, __sym3, __sym4) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+                (5, __symbol, 103)
+            }
+            236 => {
+                __reduce236(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            237 => {
+                __reduce237(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            238 => {
+                __reduce238(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            239 => {
+                __reduce239(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            240 => {
+                __reduce240(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            241 => {
+                __reduce241(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            242 => {
+                __reduce242(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            243 => {
+                __reduce243(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            244 => {
+                __reduce244(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            245 => {
+                __reduce245(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
+            }
+            246 => {
+                // __Module = Module => ActionFn(0);
+                let __sym0 = __pop_Variant34(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(__sym0);
+                return Some(Ok(__nt));
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __states_len = __states.len();
+        __states.truncate(__states_len - __pop_states);
+        __symbols.push(__symbol);
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 108 + __nonterminal] - 1;
+        __states.push(__next_state);
+        None
+    }
+    fn __pop_Variant35<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, (Vec<Expression>, bool), usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant35(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant2<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Binding, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant2(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant20<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Block, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant20(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant23<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ConstDeclaration, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant4<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Expression, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant4(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant13<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, FieldDeclaration, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant13(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant15<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, FieldInit, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant15(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant27<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Function, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant27(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant30<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, GlobalDeclaration, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant30(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant31<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Item, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant31(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant32<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, MemoryDeclaration, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant32(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant33<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, MemoryImmediates, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant33(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant34<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Module, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant34(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant6<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Parameter, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant6(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant36<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Parameters, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant36(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant0<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Spanned<Tok>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant0(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant8<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Spanned<Type>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant8(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant37<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, StructDeclaration, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant37(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant38<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, TypeDeclaration, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant38(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant10<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, TypeParameter, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant10(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant19<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<Expression>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant19(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant21<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<FieldDeclaration>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant21(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant22<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<FieldInit>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant28<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, Vec<TypeParameter>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant28(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant18<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, usize, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant18(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant24<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::option::Option<Expression>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant24(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant25<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::option::Option<FieldDeclaration>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant25(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant26<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::option::Option<FieldInit>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant26(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant1<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::option::Option<Spanned<Tok>>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant1(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant12<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::option::Option<Spanned<Type>>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant12(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant29<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::option::Option<Vec<TypeParameter>>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant29(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant3<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<Binding>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant3(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant5<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<Expression>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant5(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant14<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<FieldDeclaration>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant14(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant16<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<FieldInit>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant16(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant17<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<Item>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant17(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant7<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<Parameter>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant7(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant9<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<Spanned<Type>>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant9(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Variant11<
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
+    ) -> (usize, ::std::vec::Vec<TypeParameter>, usize)
+     {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Variant11(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
         }
     }
-    pub(crate) fn __reduce<
+    pub(crate) fn __reduce1<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ","? = "," => ActionFn(153);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action153::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (1, __symbol, 0)
+    }
+    pub(crate) fn __reduce2<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ","? =  => ActionFn(154);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action154::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (0, __symbol, 0)
+    }
+    pub(crate) fn __reduce3<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // "export"? = "export" => ActionFn(190);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action190::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (1, __symbol, 1)
+    }
+    pub(crate) fn __reduce4<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // "export"? =  => ActionFn(191);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action191::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (0, __symbol, 1)
+    }
+    pub(crate) fn __reduce5<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // "mut"? = "mut" => ActionFn(188);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action188::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (1, __symbol, 2)
+    }
+    pub(crate) fn __reduce6<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // "mut"? =  => ActionFn(189);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action189::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (0, __symbol, 2)
+    }
+    pub(crate) fn __reduce7<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Binding>) = ",", Binding => ActionFn(165);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action165::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
+        (2, __symbol, 3)
+    }
+    pub(crate) fn __reduce8<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Binding>)+ = ",", Binding => ActionFn(232);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action232::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (2, __symbol, 4)
+    }
+    pub(crate) fn __reduce9<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Binding>)+ = ("," <Binding>)+, ",", Binding => ActionFn(233);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant3(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action233::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
+        (3, __symbol, 4)
+    }
+    pub(crate) fn __reduce10<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Expression>) = ",", Expression => ActionFn(160);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action160::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 5)
+    }
+    pub(crate) fn __reduce11<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Expression>)* =  => ActionFn(155);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action155::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (0, __symbol, 6)
+    }
+    pub(crate) fn __reduce12<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Expression>)* = ("," <Expression>)+ => ActionFn(156);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action156::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (1, __symbol, 6)
+    }
+    pub(crate) fn __reduce13<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Expression>)+ = ",", Expression => ActionFn(234);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action234::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (2, __symbol, 7)
+    }
+    pub(crate) fn __reduce14<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Expression>)+ = ("," <Expression>)+, ",", Expression => ActionFn(235);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant5(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action235::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
+        (3, __symbol, 7)
+    }
+    pub(crate) fn __reduce15<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Parameter>) = ",", Parameter => ActionFn(176);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action176::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (2, __symbol, 8)
+    }
+    pub(crate) fn __reduce16<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Parameter>)* =  => ActionFn(174);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action174::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (0, __symbol, 9)
+    }
+    pub(crate) fn __reduce17<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Parameter>)* = ("," <Parameter>)+ => ActionFn(175);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action175::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (1, __symbol, 9)
+    }
+    pub(crate) fn __reduce18<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Parameter>)+ = ",", Parameter => ActionFn(242);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action242::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (2, __symbol, 10)
+    }
+    pub(crate) fn __reduce19<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Parameter>)+ = ("," <Parameter>)+, ",", Parameter => ActionFn(243);
+        let __sym2 = __pop_Variant6(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant7(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action243::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
+        (3, __symbol, 10)
+    }
+    pub(crate) fn __reduce20<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Type>) = ",", Type => ActionFn(172);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action172::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (2, __symbol, 11)
+    }
+    pub(crate) fn __reduce21<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Type>)+ = ",", Type => ActionFn(246);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action246::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
+        (2, __symbol, 12)
+    }
+    pub(crate) fn __reduce22<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <Type>)+ = ("," <Type>)+, ",", Type => ActionFn(247);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant9(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action247::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
+        (3, __symbol, 12)
+    }
+    pub(crate) fn __reduce23<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <TypeParameter>) = ",", TypeParameter => ActionFn(182);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action182::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (2, __symbol, 13)
+    }
+    pub(crate) fn __reduce24<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <TypeParameter>)* =  => ActionFn(180);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action180::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (0, __symbol, 14)
+    }
+    pub(crate) fn __reduce25<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <TypeParameter>)* = ("," <TypeParameter>)+ => ActionFn(181);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action181::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (1, __symbol, 14)
+    }
+    pub(crate) fn __reduce26<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <TypeParameter>)+ = ",", TypeParameter => ActionFn(248);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action248::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (2, __symbol, 15)
+    }
+    pub(crate) fn __reduce27<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ("," <TypeParameter>)+ = ("," <TypeParameter>)+, ",", TypeParameter => ActionFn(249);
+        let __sym2 = __pop_Variant10(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant11(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action249::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant11(__nt), __end);
+        (3, __symbol, 15)
+    }
+    pub(crate) fn __reduce28<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (".." <"Int">) = "..", "Int" => ActionFn(187);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action187::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant0(__nt), __end);
+        (2, __symbol, 16)
+    }
+    pub(crate) fn __reduce29<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (".." <"Int">)? = "..", "Int" => ActionFn(252);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action252::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (2, __symbol, 17)
+    }
+    pub(crate) fn __reduce30<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (".." <"Int">)? =  => ActionFn(186);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action186::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (0, __symbol, 17)
+    }
+    pub(crate) fn __reduce31<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (":" <"Id">) = ":", "Id" => ActionFn(179);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action179::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant0(__nt), __end);
+        (2, __symbol, 18)
+    }
+    pub(crate) fn __reduce32<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (":" <"Id">)? = ":", "Id" => ActionFn(257);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action257::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (2, __symbol, 19)
+    }
+    pub(crate) fn __reduce33<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (":" <"Id">)? =  => ActionFn(178);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action178::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
+        (0, __symbol, 19)
+    }
+    pub(crate) fn __reduce34<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (":" <Type>) = ":", Type => ActionFn(168);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action168::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (2, __symbol, 20)
+    }
+    pub(crate) fn __reduce35<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (":" <Type>)? = ":", Type => ActionFn(260);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action260::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (2, __symbol, 21)
+    }
+    pub(crate) fn __reduce36<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (":" <Type>)? =  => ActionFn(167);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action167::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant12(__nt), __end);
+        (0, __symbol, 21)
+    }
+    pub(crate) fn __reduce37<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldDeclaration> ",") = FieldDeclaration, "," => ActionFn(202);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action202::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (2, __symbol, 22)
+    }
+    pub(crate) fn __reduce38<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldDeclaration> ",")* =  => ActionFn(200);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action200::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (0, __symbol, 23)
+    }
+    pub(crate) fn __reduce39<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldDeclaration> ",")* = (<FieldDeclaration> ",")+ => ActionFn(201);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action201::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (1, __symbol, 23)
+    }
+    pub(crate) fn __reduce40<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldDeclaration> ",")+ = FieldDeclaration, "," => ActionFn(265);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action265::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (2, __symbol, 24)
+    }
+    pub(crate) fn __reduce41<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldDeclaration> ",")+ = (<FieldDeclaration> ",")+, FieldDeclaration, "," => ActionFn(266);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action266::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant14(__nt), __end);
+        (3, __symbol, 24)
+    }
+    pub(crate) fn __reduce42<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldInit> ",") = FieldInit, "," => ActionFn(211);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action211::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (2, __symbol, 25)
+    }
+    pub(crate) fn __reduce43<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldInit> ",")* =  => ActionFn(209);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action209::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (0, __symbol, 26)
+    }
+    pub(crate) fn __reduce44<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldInit> ",")* = (<FieldInit> ",")+ => ActionFn(210);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action210::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (1, __symbol, 26)
+    }
+    pub(crate) fn __reduce45<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldInit> ",")+ = FieldInit, "," => ActionFn(269);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action269::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (2, __symbol, 27)
+    }
+    pub(crate) fn __reduce46<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<FieldInit> ",")+ = (<FieldInit> ",")+, FieldInit, "," => ActionFn(270);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action270::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant16(__nt), __end);
+        (3, __symbol, 27)
+    }
+    pub(crate) fn __reduce47<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<Item*>) =  => ActionFn(273);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action273::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (0, __symbol, 28)
+    }
+    pub(crate) fn __reduce48<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // (<Item*>) = Item+ => ActionFn(274);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action274::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 28)
+    }
+    pub(crate) fn __reduce49<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // @L =  => ActionFn(173);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action173::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant18(__nt), __end);
+        (0, __symbol, 29)
+    }
+    pub(crate) fn __reduce50<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // @R =  => ActionFn(169);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action169::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant18(__nt), __end);
+        (0, __symbol, 30)
+    }
+    pub(crate) fn __reduce51<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Arguments = "(", Expression, ")" => ActionFn(236);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action236::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant19(__nt), __end);
+        (3, __symbol, 31)
+    }
+    pub(crate) fn __reduce52<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Arguments = "(", Expression, ("," <Expression>)+, ")" => ActionFn(237);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action237::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant19(__nt), __end);
+        (4, __symbol, 31)
+    }
+    pub(crate) fn __reduce53<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Arguments = "(", ")" => ActionFn(88);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action88::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant19(__nt), __end);
+        (2, __symbol, 31)
+    }
+    pub(crate) fn __reduce54<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Assign = "Id", AssignOperator, Expression => ActionFn(49);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action49::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 32)
+    }
+    pub(crate) fn __reduce55<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Assign = Expression1, ".", "Id", AssignOperator, Expression => ActionFn(50);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action50::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 32)
+    }
+    pub(crate) fn __reduce56<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Assign = Expression1, "[", Expression, "]", AssignOperator, Expression => ActionFn(51);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action51::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (6, __symbol, 32)
+    }
+    pub(crate) fn __reduce57<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // AssignOperator = "=" => ActionFn(52);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action52::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant0(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce58<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // AssignOperator = "+=" => ActionFn(53);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action53::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant0(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce59<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // AssignOperator = "-=" => ActionFn(54);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action54::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant0(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce60<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // AssignOperator = "*=" => ActionFn(55);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action55::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant0(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce61<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // AssignOperator = "/=" => ActionFn(56);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action56::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant0(__nt), __end);
+        (1, __symbol, 33)
+    }
+    pub(crate) fn __reduce62<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression2, "%", Conversion> = Expression2, "%", Conversion => ActionFn(150);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action150::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 34)
+    }
+    pub(crate) fn __reduce63<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression2, "*", Conversion> = Expression2, "*", Conversion => ActionFn(152);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action152::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 35)
+    }
+    pub(crate) fn __reduce64<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression2, "/", Conversion> = Expression2, "/", Conversion => ActionFn(151);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action151::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 36)
+    }
+    pub(crate) fn __reduce65<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression3, "+", Expression2> = Expression3, "+", Expression2 => ActionFn(149);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action149::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 37)
+    }
+    pub(crate) fn __reduce66<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression3, "-", Expression2> = Expression3, "-", Expression2 => ActionFn(148);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action148::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 38)
+    }
+    pub(crate) fn __reduce67<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression4, "<<", Expression3> = Expression4, "<<", Expression3 => ActionFn(147);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action147::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 39)
+    }
+    pub(crate) fn __reduce68<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression4, ">>", Expression3> = Expression4, ">>", Expression3 => ActionFn(146);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action146::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 40)
+    }
+    pub(crate) fn __reduce69<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression5, "&", Expression4> = Expression5, "&", Expression4 => ActionFn(145);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action145::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 41)
+    }
+    pub(crate) fn __reduce70<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression6, "^", Expression5> = Expression6, "^", Expression5 => ActionFn(144);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action144::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 42)
+    }
+    pub(crate) fn __reduce71<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binary<Expression7, "|", Expression6> = Expression7, "|", Expression6 => ActionFn(143);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action143::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 43)
+    }
+    pub(crate) fn __reduce72<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binding = "mut", "Id" => ActionFn(222);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action222::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
+        (2, __symbol, 44)
+    }
+    pub(crate) fn __reduce73<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Binding = "Id" => ActionFn(223);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action223::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
+        (1, __symbol, 44)
+    }
+    pub(crate) fn __reduce74<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Block = "{", Statements, "}" => ActionFn(282);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant35(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action282::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant20(__nt), __end);
+        (3, __symbol, 45)
+    }
+    pub(crate) fn __reduce75<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // BlockLike = If => ActionFn(62);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action62::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 46)
+    }
+    pub(crate) fn __reduce76<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // BlockLike = Loop => ActionFn(63);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action63::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 46)
+    }
+    pub(crate) fn __reduce77<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Bool = "true" => ActionFn(133);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action133::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 47)
+    }
+    pub(crate) fn __reduce78<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Bool = "false" => ActionFn(134);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action134::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 47)
+    }
+    pub(crate) fn __reduce79<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Break = "break", Expression => ActionFn(287);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action287::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 48)
+    }
+    pub(crate) fn __reduce80<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Break = "break" => ActionFn(288);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action288::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 48)
+    }
+    pub(crate) fn __reduce81<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldDeclaration> = FieldDeclaration => ActionFn(291);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action291::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant21(__nt), __end);
+        (1, __symbol, 49)
+    }
+    pub(crate) fn __reduce82<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldDeclaration> =  => ActionFn(292);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action292::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant21(__nt), __end);
+        (0, __symbol, 49)
+    }
+    pub(crate) fn __reduce83<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldDeclaration> = (<FieldDeclaration> ",")+, FieldDeclaration => ActionFn(293);
+        let __sym1 = __pop_Variant13(__symbols);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action293::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant21(__nt), __end);
+        (2, __symbol, 49)
+    }
+    pub(crate) fn __reduce84<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldDeclaration> = (<FieldDeclaration> ",")+ => ActionFn(294);
+        let __sym0 = __pop_Variant14(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action294::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant21(__nt), __end);
+        (1, __symbol, 49)
+    }
+    pub(crate) fn __reduce85<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldInit> = FieldInit => ActionFn(295);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action295::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant22(__nt), __end);
+        (1, __symbol, 50)
+    }
+    pub(crate) fn __reduce86<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldInit> =  => ActionFn(296);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action296::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant22(__nt), __end);
+        (0, __symbol, 50)
+    }
+    pub(crate) fn __reduce87<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldInit> = (<FieldInit> ",")+, FieldInit => ActionFn(297);
+        let __sym1 = __pop_Variant15(__symbols);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action297::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant22(__nt), __end);
+        (2, __symbol, 50)
+    }
+    pub(crate) fn __reduce88<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comma<FieldInit> = (<FieldInit> ",")+ => ActionFn(298);
+        let __sym0 = __pop_Variant16(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action298::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant22(__nt), __end);
+        (1, __symbol, 50)
+    }
+    pub(crate) fn __reduce89<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comparison<Expression7, "!=", Expression7> = Expression7, "!=", Expression7 => ActionFn(141);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action141::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 51)
+    }
+    pub(crate) fn __reduce90<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comparison<Expression7, "<", Expression7> = Expression7, "<", Expression7 => ActionFn(140);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action140::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 52)
+    }
+    pub(crate) fn __reduce91<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comparison<Expression7, "<=", Expression7> = Expression7, "<=", Expression7 => ActionFn(139);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action139::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 53)
+    }
+    pub(crate) fn __reduce92<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comparison<Expression7, "==", Expression7> = Expression7, "==", Expression7 => ActionFn(142);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action142::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 54)
+    }
+    pub(crate) fn __reduce93<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comparison<Expression7, ">", Expression7> = Expression7, ">", Expression7 => ActionFn(138);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action138::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 55)
+    }
+    pub(crate) fn __reduce94<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Comparison<Expression7, ">=", Expression7> = Expression7, ">=", Expression7 => ActionFn(137);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action137::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 56)
+    }
+    pub(crate) fn __reduce95<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ConstDeclaration = "const", "Id", ":", Type, "=", Expression, ";" => ActionFn(10);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action10::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __symbol = (__start, __Symbol::Variant23(__nt), __end);
+        (7, __symbol, 57)
+    }
+    pub(crate) fn __reduce96<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Conversion = Unary => ActionFn(101);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action101::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 58)
+    }
+    pub(crate) fn __reduce97<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Conversion = Conversion, "as", ConversionType => ActionFn(102);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action102::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 58)
+    }
+    pub(crate) fn __reduce98<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Conversion = Conversion, "as", "saturating", ConversionType => ActionFn(103);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action103::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (4, __symbol, 58)
+    }
+    pub(crate) fn __reduce99<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ConversionType = NumericType => ActionFn(30);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action30::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 59)
+    }
+    pub(crate) fn __reduce100<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ConversionType = NamedType => ActionFn(31);
+        let __sym0 = __pop_Variant8(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action31::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 59)
+    }
+    pub(crate) fn __reduce101<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression = Assign => ActionFn(57);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action57::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 60)
+    }
+    pub(crate) fn __reduce102<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression = Break => ActionFn(58);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action58::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 60)
+    }
+    pub(crate) fn __reduce103<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression = Return => ActionFn(59);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action59::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 60)
+    }
+    pub(crate) fn __reduce104<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression = BlockLike => ActionFn(60);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action60::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 60)
+    }
+    pub(crate) fn __reduce105<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression = Expression10 => ActionFn(61);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action61::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 60)
+    }
+    pub(crate) fn __reduce106<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "(", Expression, ")" => ActionFn(72);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action72::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 61)
+    }
+    pub(crate) fn __reduce107<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "(", Expression, ("," <Expression>)+, ")" => ActionFn(73);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action73::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (4, __symbol, 61)
+    }
+    pub(crate) fn __reduce108<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "continue" => ActionFn(74);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action74::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 61)
+    }
+    pub(crate) fn __reduce109<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = MemoryAccess => ActionFn(75);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action75::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 61)
+    }
+    pub(crate) fn __reduce110<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "new", "Id", "{", Comma<FieldInit>, "}" => ActionFn(76);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant22(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action76::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 61)
+    }
+    pub(crate) fn __reduce111<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = Expression1, ".", "Id" => ActionFn(77);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action77::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 61)
+    }
+    pub(crate) fn __reduce112<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "[", Expression, ",", "]" => ActionFn(238);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action238::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (4, __symbol, 61)
+    }
+    pub(crate) fn __reduce113<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "[", Expression, ("," <Expression>)+, ",", "]" => ActionFn(239);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action239::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 61)
+    }
+    pub(crate) fn __reduce114<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "[", Expression, "]" => ActionFn(240);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action240::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 61)
+    }
+    pub(crate) fn __reduce115<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "[", Expression, ("," <Expression>)+, "]" => ActionFn(241);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant5(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action241::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (4, __symbol, 61)
+    }
+    pub(crate) fn __reduce117<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = Expression1, "[", Expression, "]" => ActionFn(80);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action80::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (4, __symbol, 61)
+    }
+    pub(crate) fn __reduce118<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "Id", Arguments => ActionFn(81);
+        let __sym1 = __pop_Variant19(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action81::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 61)
+    }
+    pub(crate) fn __reduce119<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = "Id" => ActionFn(82);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action82::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 61)
+    }
+    pub(crate) fn __reduce120<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = Integer => ActionFn(83);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action83::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 61)
+    }
+    pub(crate) fn __reduce121<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = Float => ActionFn(84);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action84::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 61)
+    }
+    pub(crate) fn __reduce122<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression1 = Bool => ActionFn(85);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action85::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 61)
+    }
+    pub(crate) fn __reduce123<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression10 = Expression9 => ActionFn(129);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action129::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 62)
+    }
+    pub(crate) fn __reduce124<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression10 = Logical<Expression10, "||", Expression9> => ActionFn(130);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action130::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 62)
+    }
+    pub(crate) fn __reduce125<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression2 = Conversion => ActionFn(104);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action104::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 63)
+    }
+    pub(crate) fn __reduce126<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression2 = Binary<Expression2, "*", Conversion> => ActionFn(105);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action105::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 63)
+    }
+    pub(crate) fn __reduce127<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression2 = Binary<Expression2, "/", Conversion> => ActionFn(106);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action106::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 63)
+    }
+    pub(crate) fn __reduce128<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression2 = Binary<Expression2, "%", Conversion> => ActionFn(107);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action107::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 63)
+    }
+    pub(crate) fn __reduce129<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression3 = Expression2 => ActionFn(108);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action108::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 64)
+    }
+    pub(crate) fn __reduce130<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression3 = Binary<Expression3, "+", Expression2> => ActionFn(109);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action109::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 64)
+    }
+    pub(crate) fn __reduce131<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression3 = Binary<Expression3, "-", Expression2> => ActionFn(110);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action110::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 64)
+    }
+    pub(crate) fn __reduce132<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression4 = Expression3 => ActionFn(111);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action111::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 65)
+    }
+    pub(crate) fn __reduce133<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression4 = Binary<Expression4, "<<", Expression3> => ActionFn(112);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action112::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 65)
+    }
+    pub(crate) fn __reduce134<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression4 = Binary<Expression4, ">>", Expression3> => ActionFn(113);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action113::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 65)
+    }
+    pub(crate) fn __reduce135<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression5 = Expression4 => ActionFn(114);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action114::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 66)
+    }
+    pub(crate) fn __reduce136<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression5 = Binary<Expression5, "&", Expression4> => ActionFn(115);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action115::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 66)
+    }
+    pub(crate) fn __reduce137<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression6 = Expression5 => ActionFn(116);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action116::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 67)
+    }
+    pub(crate) fn __reduce138<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression6 = Binary<Expression6, "^", Expression5> => ActionFn(117);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action117::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 67)
+    }
+    pub(crate) fn __reduce139<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression7 = Expression6 => ActionFn(118);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action118::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 68)
+    }
+    pub(crate) fn __reduce140<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression7 = Binary<Expression7, "|", Expression6> => ActionFn(119);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action119::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 68)
+    }
+    pub(crate) fn __reduce141<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression8 = Expression7 => ActionFn(120);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action120::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 69)
+    }
+    pub(crate) fn __reduce142<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression8 = Comparison<Expression7, "==", Expression7> => ActionFn(121);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action121::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 69)
+    }
+    pub(crate) fn __reduce143<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression8 = Comparison<Expression7, "!=", Expression7> => ActionFn(122);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action122::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 69)
+    }
+    pub(crate) fn __reduce144<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression8 = Comparison<Expression7, "<", Expression7> => ActionFn(123);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action123::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 69)
+    }
+    pub(crate) fn __reduce145<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression8 = Comparison<Expression7, "<=", Expression7> => ActionFn(124);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action124::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 69)
+    }
+    pub(crate) fn __reduce146<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression8 = Comparison<Expression7, ">", Expression7> => ActionFn(125);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action125::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 69)
+    }
+    pub(crate) fn __reduce147<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression8 = Comparison<Expression7, ">=", Expression7> => ActionFn(126);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action126::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 69)
+    }
+    pub(crate) fn __reduce148<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression9 = Expression8 => ActionFn(127);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action127::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 70)
+    }
+    pub(crate) fn __reduce149<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression9 = Logical<Expression9, "&&", Expression8> => ActionFn(128);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action128::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 70)
+    }
+    pub(crate) fn __reduce150<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression? = Expression => ActionFn(161);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action161::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant24(__nt), __end);
+        (1, __symbol, 71)
+    }
+    pub(crate) fn __reduce151<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Expression? =  => ActionFn(162);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action162::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant24(__nt), __end);
+        (0, __symbol, 71)
+    }
+    pub(crate) fn __reduce152<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // FieldDeclaration = "Id", ":", Type => ActionFn(9);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action9::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant13(__nt), __end);
+        (3, __symbol, 72)
+    }
+    pub(crate) fn __reduce153<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // FieldDeclaration? = FieldDeclaration => ActionFn(198);
+        let __sym0 = __pop_Variant13(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action198::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant25(__nt), __end);
+        (1, __symbol, 73)
+    }
+    pub(crate) fn __reduce154<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // FieldDeclaration? =  => ActionFn(199);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action199::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant25(__nt), __end);
+        (0, __symbol, 73)
+    }
+    pub(crate) fn __reduce155<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // FieldInit = "Id", ":", Expression => ActionFn(86);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action86::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant15(__nt), __end);
+        (3, __symbol, 74)
+    }
+    pub(crate) fn __reduce156<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // FieldInit? = FieldInit => ActionFn(207);
+        let __sym0 = __pop_Variant15(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action207::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant26(__nt), __end);
+        (1, __symbol, 75)
+    }
+    pub(crate) fn __reduce157<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // FieldInit? =  => ActionFn(208);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action208::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant26(__nt), __end);
+        (0, __symbol, 75)
+    }
+    pub(crate) fn __reduce158<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Float = "Float" => ActionFn(132);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action132::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 76)
+    }
+    pub(crate) fn __reduce159<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Function = "def", "Id", Generics, Parameters, "->", Type, Block => ActionFn(299);
+        let __sym6 = __pop_Variant20(__symbols);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant36(__symbols);
+        let __sym2 = __pop_Variant28(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action299::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __symbol = (__start, __Symbol::Variant27(__nt), __end);
+        (7, __symbol, 77)
+    }
+    pub(crate) fn __reduce160<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Function = "def", "Id", Parameters, "->", Type, Block => ActionFn(300);
+        let __sym5 = __pop_Variant20(__symbols);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant36(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action300::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant27(__nt), __end);
+        (6, __symbol, 77)
+    }
+    pub(crate) fn __reduce161<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Function = "def", "Id", Generics, Parameters, Block => ActionFn(301);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant36(__symbols);
+        let __sym2 = __pop_Variant28(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action301::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant27(__nt), __end);
+        (5, __symbol, 77)
+    }
+    pub(crate) fn __reduce162<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Function = "def", "Id", Parameters, Block => ActionFn(302);
+        let __sym3 = __pop_Variant20(__symbols);
+        let __sym2 = __pop_Variant36(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action302::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant27(__nt), __end);
+        (4, __symbol, 77)
+    }
+    pub(crate) fn __reduce163<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Generics = "<", TypeParameter, ">" => ActionFn(250);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action250::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant28(__nt), __end);
+        (3, __symbol, 78)
+    }
+    pub(crate) fn __reduce164<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Generics = "<", TypeParameter, ("," <TypeParameter>)+, ">" => ActionFn(251);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant11(__symbols);
+        let __sym1 = __pop_Variant10(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym3.2.clone();
+        let __nt = super::__action251::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant28(__nt), __end);
+        (4, __symbol, 78)
+    }
+    pub(crate) fn __reduce165<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Generics? = Generics => ActionFn(183);
+        let __sym0 = __pop_Variant28(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action183::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant29(__nt), __end);
+        (1, __symbol, 79)
+    }
+    pub(crate) fn __reduce166<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Generics? =  => ActionFn(184);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action184::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant29(__nt), __end);
+        (0, __symbol, 79)
+    }
+    pub(crate) fn __reduce167<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // GlobalDeclaration = "export", "global", "mut", "Id", ":", Type, "=", Expression, ";" => ActionFn(224);
+        let __sym8 = __pop_Variant0(__symbols);
+        let __sym7 = __pop_Variant4(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant8(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym8.2.clone();
+        let __nt = super::__action224::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8);
+        let __symbol = (__start, __Symbol::Variant30(__nt), __end);
+        (9, __symbol, 80)
+    }
+    pub(crate) fn __reduce168<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // GlobalDeclaration = "export", "global", "Id", ":", Type, "=", Expression, ";" => ActionFn(225);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action225::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __symbol = (__start, __Symbol::Variant30(__nt), __end);
+        (8, __symbol, 80)
+    }
+    pub(crate) fn __reduce169<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // GlobalDeclaration = "global", "mut", "Id", ":", Type, "=", Expression, ";" => ActionFn(226);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action226::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __symbol = (__start, __Symbol::Variant30(__nt), __end);
+        (8, __symbol, 80)
+    }
+    pub(crate) fn __reduce170<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // GlobalDeclaration = "global", "Id", ":", Type, "=", Expression, ";" => ActionFn(227);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action227::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __symbol = (__start, __Symbol::Variant30(__nt), __end);
+        (7, __symbol, 80)
+    }
+    pub(crate) fn __reduce171<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // If = "if", Expression, Block => ActionFn(66);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action66::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 81)
+    }
+    pub(crate) fn __reduce172<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // If = "if", Expression, Block, "else", Block => ActionFn(67);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action67::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 81)
+    }
+    pub(crate) fn __reduce173<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // If = "if", Expression, Block, "else", If => ActionFn(283);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action283::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 81)
+    }
+    pub(crate) fn __reduce174<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Integer = "Int" => ActionFn(131);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action131::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 82)
+    }
+    pub(crate) fn __reduce175<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item = ModifiedFunction => ActionFn(2);
+        let __sym0 = __pop_Variant27(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action2::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant31(__nt), __end);
+        (1, __symbol, 83)
+    }
+    pub(crate) fn __reduce176<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item = TypeDeclaration => ActionFn(3);
+        let __sym0 = __pop_Variant38(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action3::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant31(__nt), __end);
+        (1, __symbol, 83)
+    }
+    pub(crate) fn __reduce177<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item = MemoryDeclaration => ActionFn(4);
+        let __sym0 = __pop_Variant32(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action4::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant31(__nt), __end);
+        (1, __symbol, 83)
+    }
+    pub(crate) fn __reduce178<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item = GlobalDeclaration => ActionFn(5);
+        let __sym0 = __pop_Variant30(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action5::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant31(__nt), __end);
+        (1, __symbol, 83)
+    }
+    pub(crate) fn __reduce179<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item = ConstDeclaration => ActionFn(6);
+        let __sym0 = __pop_Variant23(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action6::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant31(__nt), __end);
+        (1, __symbol, 83)
+    }
+    pub(crate) fn __reduce180<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item = StructDeclaration => ActionFn(7);
+        let __sym0 = __pop_Variant37(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action7::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant31(__nt), __end);
+        (1, __symbol, 83)
+    }
+    pub(crate) fn __reduce181<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<Module,__lalrpop_util::ParseError<usize, Spanned<Tok>, crate::CompileError>>>
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        let (__pop_states, __symbol, __nonterminal) = match -__action {
-            1 => {
-                __reduce1(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            2 => {
-                __reduce2(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            3 => {
-                __reduce3(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            4 => {
-                __reduce4(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            5 => {
-                __reduce5(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            6 => {
-                __reduce6(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            7 => {
-                __reduce7(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            8 => {
-                __reduce8(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            9 => {
-                __reduce9(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            10 => {
-                __reduce10(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            11 => {
-                __reduce11(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            12 => {
-                __reduce12(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            13 => {
-                __reduce13(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            14 => {
-                __reduce14(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            15 => {
-                __reduce15(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            16 => {
-                __reduce16(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            17 => {
-                __reduce17(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            18 => {
-                __reduce18(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            19 => {
-                __reduce19(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            20 => {
-                __reduce20(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            21 => {
-                __reduce21(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            22 => {
-                __reduce22(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            23 => {
-                __reduce23(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            24 => {
-                __reduce24(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            25 => {
-                __reduce25(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            26 => {
-                __reduce26(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            27 => {
-                __reduce27(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            28 => {
-                __reduce28(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            29 => {
-                __reduce29(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            30 => {
-                __reduce30(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            31 => {
-                __reduce31(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            32 => {
-                __reduce32(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            33 => {
-                __reduce33(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            34 => {
-                __reduce34(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            35 => {
-                __reduce35(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            36 => {
-                __reduce36(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            37 => {
-                __reduce37(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            38 => {
-                __reduce38(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            39 => {
-                __reduce39(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            40 => {
-                __reduce40(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            41 => {
-                __reduce41(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            42 => {
-                __reduce42(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            43 => {
-                __reduce43(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            44 => {
-                __reduce44(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            45 => {
-                __reduce45(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            46 => {
-                __reduce46(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            47 => {
-                __reduce47(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            48 => {
-                __reduce48(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            49 => {
-                __reduce49(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            50 => {
-                __reduce50(__action, __lookahead_start, __states, __symbols, ::std::marker::PhantomData::<()>)
-            }
-            51 => {
-                // __Module = Module => ActionFn(0);
-                let __sym0 = __pop_Variant8(__symbols);
-                let __start = __sym0.0.clone();
-                let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(__sym0);
-                return Some(Ok(__nt));
-            }
-            _ => panic!("invalid action code {}", __action)
-        };
-        let __states_len = __states.len();
-        __states.truncate(__states_len - __pop_states);
-        __symbols.push(__symbol);
-        let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 27 + __nonterminal] - 1;
-        __states.push(__next_state);
-        None
+        // Item* =  => ActionFn(194);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action194::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (0, __symbol, 84)
     }
-    fn __pop_Variant6<
+    pub(crate) fn __reduce182<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Block, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant6(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item* = Item+ => ActionFn(195);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action195::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 84)
     }
-    fn __pop_Variant3<
+    pub(crate) fn __reduce183<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Expression, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant3(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item+ = Item => ActionFn(196);
+        let __sym0 = __pop_Variant31(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action196::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (1, __symbol, 85)
     }
-    fn __pop_Variant7<
+    pub(crate) fn __reduce184<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Function, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant7(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Item+ = Item+, Item => ActionFn(197);
+        let __sym1 = __pop_Variant31(__symbols);
+        let __sym0 = __pop_Variant17(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action197::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant17(__nt), __end);
+        (2, __symbol, 85)
+    }
+    pub(crate) fn __reduce185<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Let = "let", "mut", "Id", ":", Type, "=", Expression, ";" => ActionFn(261);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant8(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action261::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (8, __symbol, 86)
+    }
+    pub(crate) fn __reduce186<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Let = "let", "mut", "Id", "=", Expression, ";" => ActionFn(262);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym5.2.clone();
+        let __nt = super::__action262::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (6, __symbol, 86)
+    }
+    pub(crate) fn __reduce187<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Let = "let", "Id", ":", Type, "=", Expression, ";" => ActionFn(263);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym6.2.clone();
+        let __nt = super::__action263::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (7, __symbol, 86)
     }
-    fn __pop_Variant8<
+    pub(crate) fn __reduce188<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Module, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant8(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Let = "let", "Id", "=", Expression, ";" => ActionFn(264);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant4(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action264::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 86)
     }
-    fn __pop_Variant1<
+    pub(crate) fn __reduce189<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Parameter, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant1(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Let = "let", "(", Binding, ("," <Binding>)+, ")", "=", Expression, ";" => ActionFn(47);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant4(__symbols);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant3(__symbols);
+        let __sym2 = __pop_Variant2(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action47::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (8, __symbol, 86)
     }
-    fn __pop_Variant9<
+    pub(crate) fn __reduce190<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Parameters, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant9(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Logical<Expression10, "||", Expression9> = Expression10, "||", Expression9 => ActionFn(135);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action135::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 87)
     }
-    fn __pop_Variant0<
+    pub(crate) fn __reduce191<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Spanned<Tok>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant0(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Logical<Expression9, "&&", Expression8> = Expression9, "&&", Expression8 => ActionFn(136);
+        let __sym2 = __pop_Variant4(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action136::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 88)
     }
-    fn __pop_Variant10<
+    pub(crate) fn __reduce192<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, Spanned<Type>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant10(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Loop = "while", Expression, Block => ActionFn(69);
+        let __sym2 = __pop_Variant20(__symbols);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action69::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (3, __symbol, 89)
     }
-    fn __pop_Variant4<
+    pub(crate) fn __reduce193<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ::std::vec::Vec<Expression>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant4(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Loop = "loop", Block => ActionFn(70);
+        let __sym1 = __pop_Variant20(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action70::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 89)
     }
-    fn __pop_Variant5<
+    pub(crate) fn __reduce194<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ::std::vec::Vec<Function>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant5(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Loop = "for", Binding, "in", Expression, Block => ActionFn(71);
+        let __sym4 = __pop_Variant20(__symbols);
+        let __sym3 = __pop_Variant4(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action71::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 89)
     }
-    fn __pop_Variant2<
+    pub(crate) fn __reduce195<
     >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>
-    ) -> (usize, ::std::vec::Vec<Parameter>, usize)
-     {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Variant2(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // MemoryAccess = "load", "<", Type, ">", "(", Expression, MemoryImmediates, ")" => ActionFn(89);
+        let __sym7 = __pop_Variant0(__symbols);
+        let __sym6 = __pop_Variant33(__symbols);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym7.2.clone();
+        let __nt = super::__action89::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (8, __symbol, 90)
     }
-    pub(crate) fn __reduce1<
+    pub(crate) fn __reduce196<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ("," <Parameter>) = ",", Parameter => ActionFn(37);
-        let __sym1 = __pop_Variant1(__symbols);
+        // MemoryAccess = "store", "<", Type, ">", "(", Expression, ",", Expression, MemoryImmediates, ")" => ActionFn(90);
+        let __sym9 = __pop_Variant0(__symbols);
+        let __sym8 = __pop_Variant33(__symbols);
+        let __sym7 = __pop_Variant4(__symbols);
+        let __sym6 = __pop_Variant0(__symbols);
+        let __sym5 = __pop_Variant4(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action37::<>(__sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (2, __symbol, 0)
+        let __end = __sym9.2.clone();
+        let __nt = super::__action90::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5, __sym6, __sym7, __sym8, __sym9);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (10, __symbol, 90)
     }
-    pub(crate) fn __reduce2<
+    pub(crate) fn __reduce197<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ("," <Parameter>)* =  => ActionFn(35);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action35::<>(&__start, &__end);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (0, __symbol, 1)
+        // MemoryAccess = "memory", ".", "Id", "(", ")" => ActionFn(91);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action91::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (5, __symbol, 90)
     }
-    pub(crate) fn __reduce3<
+    pub(crate) fn __reduce198<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ("," <Parameter>)* = ("," <Parameter>)+ => ActionFn(36);
-        let __sym0 = __pop_Variant2(__symbols);
+        // MemoryAccess = "memory", ".", "Id", "(", Expression, ")" => ActionFn(92);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant4(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action36::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (1, __symbol, 1)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action92::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (6, __symbol, 90)
     }
-    pub(crate) fn __reduce4<
+    pub(crate) fn __reduce199<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ("," <Parameter>)+ = ",", Parameter => ActionFn(47);
-        let __sym1 = __pop_Variant1(__symbols);
+        // MemoryDeclaration = "export", "memory", "Int", "..", "Int", ";" => ActionFn(253);
+        let __sym5 = __pop_Variant0(__symbols);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action47::<>(__sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (2, __symbol, 2)
+        let __end = __sym5.2.clone();
+        let __nt = super::__action253::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
+        let __symbol = (__start, __Symbol::Variant32(__nt), __end);
+        (6, __symbol, 91)
     }
-    pub(crate) fn __reduce5<
+    pub(crate) fn __reduce200<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ("," <Parameter>)+ = ("," <Parameter>)+, ",", Parameter => ActionFn(48);
-        let __sym2 = __pop_Variant1(__symbols);
+        // MemoryDeclaration = "export", "memory", "Int", ";" => ActionFn(254);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant2(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action48::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant2(__nt), __end);
-        (3, __symbol, 2)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action254::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant32(__nt), __end);
+        (4, __symbol, 91)
     }
-    pub(crate) fn __reduce6<
+    pub(crate) fn __reduce201<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // (<Expression>) = Expression => ActionFn(34);
-        let __sym0 = __pop_Variant3(__symbols);
+        // MemoryDeclaration = "memory", "Int", "..", "Int", ";" => ActionFn(255);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action34::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 3)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action255::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant32(__nt), __end);
+        (5, __symbol, 91)
     }
-    pub(crate) fn __reduce7<
+    pub(crate) fn __reduce202<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // MemoryDeclaration = "memory", "Int", ";" => ActionFn(256);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym2.2.clone();
+        let __nt = super::__action256::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant32(__nt), __end);
+        (3, __symbol, 91)
+    }
+    pub(crate) fn __reduce203<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // (<Expression>)* =  => ActionFn(32);
+        // MemoryImmediates =  => ActionFn(93);
         let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
         let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action32::<>(&__start, &__end);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (0, __symbol, 4)
+        let __nt = super::__action93::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant33(__nt), __end);
+        (0, __symbol, 92)
     }
-    pub(crate) fn __reduce8<
+    pub(crate) fn __reduce204<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // (<Expression>)* = (<Expression>)+ => ActionFn(33);
-        let __sym0 = __pop_Variant4(__symbols);
+        // MemoryImmediates = ",", "Int" => ActionFn(94);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action33::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 4)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action94::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant33(__nt), __end);
+        (2, __symbol, 92)
     }
-    pub(crate) fn __reduce9<
+    pub(crate) fn __reduce205<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // (<Expression>)+ = Expression => ActionFn(51);
-        let __sym0 = __pop_Variant3(__symbols);
+        // MemoryImmediates = ",", "Int", ",", "Int" => ActionFn(95);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action51::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (1, __symbol, 5)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action95::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant33(__nt), __end);
+        (4, __symbol, 92)
     }
-    pub(crate) fn __reduce10<
+    pub(crate) fn __reduce206<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // (<Expression>)+ = (<Expression>)+, Expression => ActionFn(52);
-        let __sym1 = __pop_Variant3(__symbols);
-        let __sym0 = __pop_Variant4(__symbols);
+        // ModifiedFunction = "export", Function => ActionFn(15);
+        let __sym1 = __pop_Variant27(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action52::<>(__sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
-        (2, __symbol, 5)
+        let __nt = super::__action15::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant27(__nt), __end);
+        (2, __symbol, 93)
     }
-    pub(crate) fn __reduce11<
+    pub(crate) fn __reduce207<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // ModifiedFunction = Function => ActionFn(16);
+        let __sym0 = __pop_Variant27(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action16::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant27(__nt), __end);
+        (1, __symbol, 93)
+    }
+    pub(crate) fn __reduce208<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // (<ModifiedFunction*>) =  => ActionFn(55);
+        // Module =  => ActionFn(275);
         let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
         let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action55::<>(&__start, &__end);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (0, __symbol, 6)
+        let __nt = super::__action275::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant34(__nt), __end);
+        (0, __symbol, 94)
     }
-    pub(crate) fn __reduce12<
+    pub(crate) fn __reduce209<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // (<ModifiedFunction*>) = ModifiedFunction+ => ActionFn(56);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Module = Item+ => ActionFn(276);
+        let __sym0 = __pop_Variant17(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action56::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (1, __symbol, 6)
+        let __nt = super::__action276::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant34(__nt), __end);
+        (1, __symbol, 94)
     }
-    pub(crate) fn __reduce13<
+    pub(crate) fn __reduce210<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Binary<Expression2, "*", Expression1> = Expression2, "*", Expression1 => ActionFn(31);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NamedType = "Id" => ActionFn(32);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action31::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (3, __symbol, 7)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action32::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 95)
     }
-    pub(crate) fn __reduce14<
+    pub(crate) fn __reduce211<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Binary<Expression2, "/", Expression1> = Expression2, "/", Expression1 => ActionFn(30);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NonEmptyStatements = Expression => ActionFn(42);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action30::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (3, __symbol, 8)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action42::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant35(__nt), __end);
+        (1, __symbol, 96)
     }
-    pub(crate) fn __reduce15<
+    pub(crate) fn __reduce212<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Binary<Expression3, "+", Expression2> = Expression3, "+", Expression2 => ActionFn(29);
-        let __sym2 = __pop_Variant3(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NonEmptyStatements = Let, Statements => ActionFn(43);
+        let __sym1 = __pop_Variant35(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action29::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (3, __symbol, 9)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action43::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant35(__nt), __end);
+        (2, __symbol, 96)
     }
-    pub(crate) fn __reduce16<
+    pub(crate) fn __reduce213<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Binary<Expression3, "-", Expression2> = Expression3, "-", Expression2 => ActionFn(28);
-        let __sym2 = __pop_Variant3(__symbols);
+        // NonEmptyStatements = Expression, ";", Statements => ActionFn(44);
+        let __sym2 = __pop_Variant35(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant3(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action28::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (3, __symbol, 10)
+        let __nt = super::__action44::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant35(__nt), __end);
+        (3, __symbol, 96)
     }
-    pub(crate) fn __reduce17<
+    pub(crate) fn __reduce214<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Block = "{", "}" => ActionFn(53);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
+        // NonEmptyStatements = BlockLike, NonEmptyStatements => ActionFn(45);
+        let __sym1 = __pop_Variant35(__symbols);
+        let __sym0 = __pop_Variant4(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action53::<>(__sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
-        (2, __symbol, 11)
+        let __nt = super::__action45::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant35(__nt), __end);
+        (2, __symbol, 96)
     }
-    pub(crate) fn __reduce18<
+    pub(crate) fn __reduce215<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Block = "{", (<Expression>)+, "}" => ActionFn(54);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant4(__symbols);
+        // NumericType = "i32" => ActionFn(33);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action54::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
-        (3, __symbol, 11)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action33::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 97)
     }
-    pub(crate) fn __reduce19<
+    pub(crate) fn __reduce216<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression = Expression3 => ActionFn(16);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NumericType = "i64" => ActionFn(34);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action16::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 12)
+        let __nt = super::__action34::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 97)
     }
-    pub(crate) fn __reduce20<
+    pub(crate) fn __reduce217<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression1 = "Id" => ActionFn(17);
+        // NumericType = "u32" => ActionFn(35);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action17::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 13)
+        let __nt = super::__action35::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 97)
     }
-    pub(crate) fn __reduce21<
+    pub(crate) fn __reduce218<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression1 = I32 => ActionFn(18);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NumericType = "u64" => ActionFn(36);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action18::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 13)
+        let __nt = super::__action36::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 97)
     }
-    pub(crate) fn __reduce22<
+    pub(crate) fn __reduce219<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression1 = F64 => ActionFn(19);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NumericType = "f32" => ActionFn(37);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action19::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 13)
+        let __nt = super::__action37::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 97)
     }
-    pub(crate) fn __reduce23<
+    pub(crate) fn __reduce220<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression2 = Expression1 => ActionFn(20);
-        let __sym0 = __pop_Variant3(__symbols);
+        // NumericType = "f64" => ActionFn(38);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action20::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 14)
+        let __nt = super::__action38::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 97)
     }
-    pub(crate) fn __reduce24<
+    pub(crate) fn __reduce221<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression2 = Binary<Expression2, "*", Expression1> => ActionFn(21);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Parameter = "mut", "Id", ":", Type => ActionFn(230);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action21::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 14)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action230::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (4, __symbol, 98)
     }
-    pub(crate) fn __reduce25<
+    pub(crate) fn __reduce222<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression2 = Binary<Expression2, "/", Expression1> => ActionFn(22);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Parameter = "Id", ":", Type => ActionFn(231);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action22::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 14)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action231::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant6(__nt), __end);
+        (3, __symbol, 98)
     }
-    pub(crate) fn __reduce26<
+    pub(crate) fn __reduce223<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression3 = Expression2 => ActionFn(23);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Parameters = "(", Parameter, ")" => ActionFn(244);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action23::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 15)
+        let __end = __sym2.2.clone();
+        let __nt = super::__action244::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant36(__nt), __end);
+        (3, __symbol, 99)
     }
-    pub(crate) fn __reduce27<
+    pub(crate) fn __reduce224<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression3 = Binary<Expression3, "+", Expression2> => ActionFn(24);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Parameters = "(", Parameter, ("," <Parameter>)+, ")" => ActionFn(245);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant7(__symbols);
+        let __sym1 = __pop_Variant6(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action24::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 15)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action245::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant36(__nt), __end);
+        (4, __symbol, 99)
     }
-    pub(crate) fn __reduce28<
+    pub(crate) fn __reduce225<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Expression3 = Binary<Expression3, "-", Expression2> => ActionFn(25);
-        let __sym0 = __pop_Variant3(__symbols);
+        // Parameters = "(", ")" => ActionFn(22);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action25::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 15)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action22::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant36(__nt), __end);
+        (2, __symbol, 99)
     }
-    pub(crate) fn __reduce29<
+    pub(crate) fn __reduce226<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // F64 = "Float" => ActionFn(27);
+        // Return = "return", Expression => ActionFn(289);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action27::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 16)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action289::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 100)
     }
-    pub(crate) fn __reduce30<
+    pub(crate) fn __reduce227<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Function = "def", "Id", Parameters, "->", Type, Block => ActionFn(4);
-        let __sym5 = __pop_Variant6(__symbols);
-        let __sym4 = __pop_Variant10(__symbols);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant9(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Return = "return" => ActionFn(290);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym5.2.clone();
-        let __nt = super::__action4::<>(__sym0, __sym1, __sym2, __sym3, __sym4, __sym5);
-        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
-        (6, __symbol, 17)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action290::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 100)
     }
-    pub(crate) fn __reduce31<
+    pub(crate) fn __reduce228<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Function = "def", "Id", Parameters, Block => ActionFn(5);
-        let __sym3 = __pop_Variant6(__symbols);
-        let __sym2 = __pop_Variant9(__symbols);
-        let __sym1 = __pop_Variant0(__symbols);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action5::<>(__sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
-        (4, __symbol, 17)
+        // Statements =  => ActionFn(40);
+        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+        let __nt = super::__action40::<>(&__start, &__end);
+        let __symbol = (__start, __Symbol::Variant35(__nt), __end);
+        (0, __symbol, 101)
     }
-    pub(crate) fn __reduce32<
+    pub(crate) fn __reduce229<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // I32 = "Int" => ActionFn(26);
-        let __sym0 = __pop_Variant0(__symbols);
+        // Statements = NonEmptyStatements => ActionFn(41);
+        let __sym0 = __pop_Variant35(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action26::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant3(__nt), __end);
-        (1, __symbol, 18)
+        let __nt = super::__action41::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant35(__nt), __end);
+        (1, __symbol, 101)
     }
-    pub(crate) fn __reduce33<
+    pub(crate) fn __reduce230<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ModifiedFunction = "export", Function => ActionFn(2);
-        let __sym1 = __pop_Variant7(__symbols);
+        // StructDeclaration = "struct", "Id", "{", Comma<FieldDeclaration>, "}" => ActionFn(8);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant21(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action2::<>(__sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
-        (2, __symbol, 19)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action8::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant37(__nt), __end);
+        (5, __symbol, 102)
     }
-    pub(crate) fn __reduce34<
+    pub(crate) fn __reduce231<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ModifiedFunction = Function => ActionFn(3);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Type = NumericType => ActionFn(24);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action3::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant7(__nt), __end);
-        (1, __symbol, 19)
+        let __nt = super::__action24::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 103)
     }
-    pub(crate) fn __reduce35<
+    pub(crate) fn __reduce232<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ModifiedFunction* =  => ActionFn(39);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action39::<>(&__start, &__end);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (0, __symbol, 20)
+        // Type = "bool" => ActionFn(25);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action25::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 103)
     }
-    pub(crate) fn __reduce36<
+    pub(crate) fn __reduce233<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ModifiedFunction* = ModifiedFunction+ => ActionFn(40);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Type = NamedType => ActionFn(26);
+        let __sym0 = __pop_Variant8(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym0.2.clone();
-        let __nt = super::__action40::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (1, __symbol, 20)
+        let __nt = super::__action26::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (1, __symbol, 103)
     }
-    pub(crate) fn __reduce37<
+    pub(crate) fn __reduce234<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ModifiedFunction+ = ModifiedFunction => ActionFn(41);
-        let __sym0 = __pop_Variant7(__symbols);
+        // Type = "(", Type, ("," <Type>)+, ")" => ActionFn(284);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant9(__symbols);
+        let __sym1 = __pop_Variant8(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action41::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (1, __symbol, 21)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action284::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (4, __symbol, 103)
     }
-    pub(crate) fn __reduce38<
+    pub(crate) fn __reduce236<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // ModifiedFunction+ = ModifiedFunction+, ModifiedFunction => ActionFn(42);
-        let __sym1 = __pop_Variant7(__symbols);
-        let __sym0 = __pop_Variant5(__symbols);
+        // Type = "&", "[", Type, "]" => ActionFn(286);
+        let __sym3 = __pop_Variant0(__symbols);
+        let __sym2 = __pop_Variant8(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym1.2.clone();
-        let __nt = super::__action42::<>(__sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant5(__nt), __end);
-        (2, __symbol, 21)
+        let __end = __sym3.2.clone();
+        let __nt = super::__action286::<>(__sym0, __sym1, __sym2, __sym3);
+        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
+        (4, __symbol, 103)
     }
-    pub(crate) fn __reduce39<
+    pub(crate) fn __reduce237<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Module =  => ActionFn(57);
-        let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
-        let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
-        let __nt = super::__action57::<>(&__start, &__end);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (0, __symbol, 22)
+        // TypeDeclaration = "type", "Id", "=", Type, ";" => ActionFn(13);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym4.2.clone();
+        let __nt = super::__action13::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant38(__nt), __end);
+        (5, __symbol, 104)
     }
-    pub(crate) fn __reduce40<
+    pub(crate) fn __reduce238<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Module = ModifiedFunction+ => ActionFn(58);
-        let __sym0 = __pop_Variant5(__symbols);
+        // TypeDeclaration = "newtype", "Id", "=", Type, ";" => ActionFn(14);
+        let __sym4 = __pop_Variant0(__symbols);
+        let __sym3 = __pop_Variant8(__symbols);
+        let __sym2 = __pop_Variant0(__symbols);
+        let __sym1 = __pop_Variant0(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action58::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant8(__nt), __end);
-        (1, __symbol, 22)
+        let __end = __sym4.2.clone();
+        let __nt = super::__action14::<>(__sym0, __sym1, __sym2, __sym3, __sym4);
+        let __symbol = (__start, __Symbol::Variant38(__nt), __end);
+        (5, __symbol, 104)
     }
-    pub(crate) fn __reduce41<
+    pub(crate) fn __reduce239<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Parameter = "Id", ":", Type => ActionFn(8);
-        let __sym2 = __pop_Variant10(__symbols);
+        // TypeParameter = "Id", ":", "Id" => ActionFn(258);
+        let __sym2 = __pop_Variant0(__symbols);
         let __sym1 = __pop_Variant0(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym2.2.clone();
-        let __nt = super::__action8::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant1(__nt), __end);
-        (3, __symbol, 23)
+        let __nt = super::__action258::<>(__sym0, __sym1, __sym2);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (3, __symbol, 105)
     }
-    pub(crate) fn __reduce42<
+    pub(crate) fn __reduce240<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Parameters = "(", Parameter, ")" => ActionFn(49);
-        let __sym2 = __pop_Variant0(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // TypeParameter = "Id" => ActionFn(259);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym2.2.clone();
-        let __nt = super::__action49::<>(__sym0, __sym1, __sym2);
-        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
-        (3, __symbol, 24)
+        let __end = __sym0.2.clone();
+        let __nt = super::__action259::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
+        (1, __symbol, 105)
     }
-    pub(crate) fn __reduce43<
+    pub(crate) fn __reduce241<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Parameters = "(", Parameter, ("," <Parameter>)+, ")" => ActionFn(50);
-        let __sym3 = __pop_Variant0(__symbols);
-        let __sym2 = __pop_Variant2(__symbols);
-        let __sym1 = __pop_Variant1(__symbols);
+        // Unary = Expression1 => ActionFn(96);
+        let __sym0 = __pop_Variant4(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym0.2.clone();
+        let __nt = super::__action96::<>(__sym0);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (1, __symbol, 106)
+    }
+    pub(crate) fn __reduce242<
+    >(
+        __action: i16,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i16>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> (usize, (usize,__Symbol<>,usize), usize)
+    {
+        // Unary = "!", Unary => ActionFn(97);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym3.2.clone();
-        let __nt = super::__action50::<>(__sym0, __sym1, __sym2, __sym3);
-        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
-        (4, __symbol, 24)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action97::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 106)
     }
-    pub(crate) fn __reduce44<
+    pub(crate) fn __reduce243<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Parameters = "(", ")" => ActionFn(7);
-        let __sym1 = __pop_Variant0(__symbols);
+        // Unary = "-", Unary => ActionFn(98);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
         let __end = __sym1.2.clone();
-        let __nt = super::__action7::<>(__sym0, __sym1);
-        let __symbol = (__start, __Symbol::Variant9(__nt), __end);
-        (2, __symbol, 24)
+        let __nt = super::__action98::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 106)
     }
-    pub(crate) fn __reduce45<
+    pub(crate) fn __reduce244<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Type = "i32" => ActionFn(9);
+        // Unary = "~", Unary => ActionFn(99);
+        let __sym1 = __pop_Variant4(__symbols);
         let __sym0 = __pop_Variant0(__symbols);
         let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action9::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
-        (1, __symbol, 25)
+        let __end = __sym1.2.clone();
+        let __nt = super::__action99::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 106)
     }
-    pub(crate) fn __reduce46<
+    pub(crate) fn __reduce245<
     >(
-        __action: i8,
+        __action: i16,
         __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
+        __states: &mut ::std::vec::Vec<i16>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
         _: ::std::marker::PhantomData<()>,
     ) -> (usize, (usize,__Symbol<>,usize), usize)
     {
-        // Type = "i64" => ActionFn(10);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action10::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
-        (1, __symbol, 25)
+        // Unary = "&", Unary => ActionFn(100);
+        let __sym1 = __pop_Variant4(__symbols);
+        let __sym0 = __pop_Variant0(__symbols);
+        let __start = __sym0.0.clone();
+        let __end = __sym1.2.clone();
+        let __nt = super::__action100::<>(__sym0, __sym1);
+        let __symbol = (__start, __Symbol::Variant4(__nt), __end);
+        (2, __symbol, 106)
+    }
+}
+pub use self::__parse__Module::ModuleParser;
+
+fn __action0<
+>(
+    (_, __0, _): (usize, Module, usize),
+) -> Module
+{
+    (__0)
+}
+
+fn __action1<
+>(
+    (_, __0, _): (usize, ::std::vec::Vec<Item>, usize),
+) -> Module
+{
+    Module::from_parser(__0)
+}
+
+fn __action2<
+>(
+    (_, __0, _): (usize, Function, usize),
+) -> Item
+{
+    Item::Function(__0)
+}
+
+fn __action3<
+>(
+    (_, __0, _): (usize, TypeDeclaration, usize),
+) -> Item
+{
+    Item::Type(__0)
+}
+
+fn __action4<
+>(
+    (_, __0, _): (usize, MemoryDeclaration, usize),
+) -> Item
+{
+    Item::Memory(__0)
+}
+
+fn __action5<
+>(
+    (_, __0, _): (usize, GlobalDeclaration, usize),
+) -> Item
+{
+    Item::Global(__0)
+}
+
+fn __action6<
+>(
+    (_, __0, _): (usize, ConstDeclaration, usize),
+) -> Item
+{
+    Item::Const(__0)
+}
+
+fn __action7<
+>(
+    (_, __0, _): (usize, StructDeclaration, usize),
+) -> Item
+{
+    Item::Struct(__0)
+}
+
+fn __action8<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, fields, _): (usize, Vec<FieldDeclaration>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> StructDeclaration
+{
+    StructDeclaration::new(name, fields)
+}
+
+fn __action9<
+>(
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+) -> FieldDeclaration
+{
+    FieldDeclaration::new(name, ty)
+}
+
+fn __action10<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> ConstDeclaration
+{
+    ConstDeclaration::new(name, ty, value)
+}
+
+fn __action11<
+>(
+    (_, export, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, mutable, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> GlobalDeclaration
+{
+    GlobalDeclaration::new(name, ty, mutable.is_some(), value, export.is_some())
+}
+
+fn __action12<
+>(
+    (_, export, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, min, _): (usize, Spanned<Tok>, usize),
+    (_, max, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> MemoryDeclaration
+{
+    MemoryDeclaration::new(tok, min, max, export.is_some())
+}
+
+fn __action13<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> TypeDeclaration
+{
+    TypeDeclaration::new(name, ty, false)
+}
+
+fn __action14<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> TypeDeclaration
+{
+    TypeDeclaration::new(name, ty, true)
+}
+
+fn __action15<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Function, usize),
+) -> Function
+{
+    __0.exported()
+}
+
+fn __action16<
+>(
+    (_, __0, _): (usize, Function, usize),
+) -> Function
+{
+    (__0)
+}
+
+fn __action17<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, generics, _): (usize, ::std::option::Option<Vec<TypeParameter>>, usize),
+    (_, args, _): (usize, Parameters, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+    (_, body, _): (usize, Block, usize),
+) -> Function
+{
+    Function::new(name, args, ty, body).with_generics(generics.unwrap_or_default())
+}
+
+fn __action18<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, generics, _): (usize, ::std::option::Option<Vec<TypeParameter>>, usize),
+    (_, args, _): (usize, Parameters, usize),
+    (_, body, _): (usize, Block, usize),
+) -> Function
+{
+    Function::new(name, args, Type::void(), body).with_generics(generics.unwrap_or_default())
+}
+
+fn __action19<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, first, _): (usize, TypeParameter, usize),
+    (_, rest, _): (usize, ::std::vec::Vec<TypeParameter>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Vec<TypeParameter>
+{
+    {
+        let mut generics = vec![first];
+        generics.extend(rest);
+        generics
     }
-    pub(crate) fn __reduce47<
-    >(
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: ::std::marker::PhantomData<()>,
-    ) -> (usize, (usize,__Symbol<>,usize), usize)
+}
+
+fn __action20<
+>(
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, bound, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+) -> TypeParameter
+{
+    TypeParameter::new(name, bound)
+}
+
+fn __action21<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Parameter, usize),
+    (_, __1, _): (usize, ::std::vec::Vec<Parameter>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Parameters
+{
+    Parameters::from_parser(__0, __1)
+}
+
+fn __action22<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+    (_, __1, _): (usize, Spanned<Tok>, usize),
+) -> Parameters
+{
+    Parameters::empty()
+}
+
+fn __action23<
+>(
+    (_, mutable, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+) -> Parameter
+{
+    Parameter::new(name, ty, mutable.is_some())
+}
+
+fn __action24<
+>(
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> Spanned<Type>
+{
+    (__0)
+}
+
+fn __action25<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::bool().copy_span(&__0)
+}
+
+fn __action26<
+>(
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> Spanned<Type>
+{
+    (__0)
+}
+
+fn __action27<
+>(
+    (_, start, _): (usize, usize, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, first, _): (usize, Spanned<Type>, usize),
+    (_, rest, _): (usize, ::std::vec::Vec<Spanned<Type>>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Spanned<Type>
+{
+    Type::tuple(first, rest).spanned(start, end)
+}
+
+fn __action28<
+>(
+    (_, start, _): (usize, usize, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, element, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, len, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Result<Spanned<Type>,__lalrpop_util::ParseError<usize,Spanned<Tok>,crate::CompileError>>
+{
     {
-        // Type = "u32" => ActionFn(11);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action11::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
-        (1, __symbol, 25)
+        let ty = Type::array(element, len).map_err(|error| ParseError::User { error })?;
+        Ok(ty.spanned(start, end))
     }
-    pub(crate) fn __reduce48<
-    >(
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: ::std::marker::PhantomData<()>,
-    ) -> (usize, (usize,__Symbol<>,usize), usize)
+}
+
+fn __action29<
+>(
+    (_, start, _): (usize, usize, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, element, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Spanned<Type>
+{
+    Type::Slice(Box::new(element.node)).spanned(start, end)
+}
+
+fn __action30<
+>(
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> Spanned<Type>
+{
+    (__0)
+}
+
+fn __action31<
+>(
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> Spanned<Type>
+{
+    (__0)
+}
+
+fn __action32<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::Named(__0.to_ident()).copy_span(&__0)
+}
+
+fn __action33<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::i32().copy_span(&__0)
+}
+
+fn __action34<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::i64().copy_span(&__0)
+}
+
+fn __action35<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::u32().copy_span(&__0)
+}
+
+fn __action36<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::u64().copy_span(&__0)
+}
+
+fn __action37<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::f32().copy_span(&__0)
+}
+
+fn __action38<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
+{
+    Type::f64().copy_span(&__0)
+}
+
+fn __action39<
+>(
+    (_, start, _): (usize, usize, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, statements, _): (usize, (Vec<Expression>, bool), usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Block
+{
+    Block::from_parser(statements, start, end)
+}
+
+fn __action40<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> (Vec<Expression>, bool)
+{
+    (vec![], false)
+}
+
+fn __action41<
+>(
+    (_, __0, _): (usize, (Vec<Expression>, bool), usize),
+) -> (Vec<Expression>, bool)
+{
+    (__0)
+}
+
+fn __action42<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> (Vec<Expression>, bool)
+{
+    (vec![__0], true)
+}
+
+fn __action43<
+>(
+    (_, item, _): (usize, Expression, usize),
+    (_, rest, _): (usize, (Vec<Expression>, bool), usize),
+) -> (Vec<Expression>, bool)
+{
+    { let (mut rest, tail) = rest; rest.push(item); (rest, tail) }
+}
+
+fn __action44<
+>(
+    (_, item, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, rest, _): (usize, (Vec<Expression>, bool), usize),
+) -> (Vec<Expression>, bool)
+{
+    { let (mut rest, tail) = rest; rest.push(item); (rest, tail) }
+}
+
+fn __action45<
+>(
+    (_, item, _): (usize, Expression, usize),
+    (_, rest, _): (usize, (Vec<Expression>, bool), usize),
+) -> (Vec<Expression>, bool)
+{
+    { let (mut rest, tail) = rest; rest.push(item); (rest, tail) }
+}
+
+fn __action46<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, mutable, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, ::std::option::Option<Spanned<Type>>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Let(Box::new(LetExpression::new(name, ty, mutable.is_some(), value)))
+}
+
+fn __action47<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, first, _): (usize, Binding, usize),
+    (_, rest, _): (usize, ::std::vec::Vec<Binding>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Destructure(tok, Box::new(DestructureExpression::from_parser(first, rest, value)))
+}
+
+fn __action48<
+>(
+    (_, mutable, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+) -> Binding
+{
+    Binding::new(name, mutable.is_some())
+}
+
+fn __action49<
+>(
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::assign(name, op, value)
+}
+
+fn __action50<
+>(
+    (_, base, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, field, _): (usize, Spanned<Tok>, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::assign_field(base, field, op, value)
+}
+
+fn __action51<
+>(
+    (_, base, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, index, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::assign_index(base, index, op, value)
+}
+
+fn __action52<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Tok>
+{
+    (__0)
+}
+
+fn __action53<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Tok>
+{
+    (__0)
+}
+
+fn __action54<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Tok>
+{
+    (__0)
+}
+
+fn __action55<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Tok>
+{
+    (__0)
+}
+
+fn __action56<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Tok>
+{
+    (__0)
+}
+
+fn __action57<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action58<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action59<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action60<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action61<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action62<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action63<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action64<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, ::std::option::Option<Expression>, usize),
+) -> Expression
+{
+    Expression::Break(tok, value.map(Box::new))
+}
+
+fn __action65<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, ::std::option::Option<Expression>, usize),
+) -> Expression
+{
+    Expression::Return(tok, value.map(Box::new))
+}
+
+fn __action66<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, condition, _): (usize, Expression, usize),
+    (_, then_block, _): (usize, Block, usize),
+) -> Expression
+{
+    Expression::If(tok, Box::new(IfExpression::new(condition, then_block, None)))
+}
+
+fn __action67<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, condition, _): (usize, Expression, usize),
+    (_, then_block, _): (usize, Block, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, else_block, _): (usize, Block, usize),
+) -> Expression
+{
+    Expression::If(tok, Box::new(IfExpression::new(condition, then_block, Some(else_block))))
+}
+
+fn __action68<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, condition, _): (usize, Expression, usize),
+    (_, then_block, _): (usize, Block, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, start, _): (usize, usize, usize),
+    (_, else_if, _): (usize, Expression, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Expression
+{
+    Expression::If(tok, Box::new(IfExpression::new(condition, then_block, Some(Block::from_parser((vec![else_if], true), start, end)))))
+}
+
+fn __action69<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, condition, _): (usize, Expression, usize),
+    (_, body, _): (usize, Block, usize),
+) -> Expression
+{
+    Expression::While(tok, Box::new(WhileExpression::new(condition, body)))
+}
+
+fn __action70<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, body, _): (usize, Block, usize),
+) -> Expression
+{
+    Expression::Loop(tok, Box::new(body))
+}
+
+fn __action71<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, binding, _): (usize, Binding, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, sequence, _): (usize, Expression, usize),
+    (_, body, _): (usize, Block, usize),
+) -> Expression
+{
+    Expression::For(tok, Box::new(ForExpression::new(binding, sequence, body)))
+}
+
+fn __action72<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action73<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, first, _): (usize, Expression, usize),
+    (_, rest, _): (usize, ::std::vec::Vec<Expression>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::tuple(tok, first, rest)
+}
+
+fn __action74<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Continue(tok)
+}
+
+fn __action75<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action76<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, fields, _): (usize, Vec<FieldInit>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Construct(tok, Box::new(ConstructExpression::new(name, fields)))
+}
+
+fn __action77<
+>(
+    (_, base, _): (usize, Expression, usize),
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, field, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Field(tok, Box::new(base), field)
+}
+
+fn __action78<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, first, _): (usize, Expression, usize),
+    (_, rest, _): (usize, ::std::vec::Vec<Expression>, usize),
+    (_, _, _): (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::array(tok, first, rest)
+}
+
+fn __action79<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, len, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Result<Expression,__lalrpop_util::ParseError<usize,Spanned<Tok>,crate::CompileError>>
+{
     {
-        // Type = "u64" => ActionFn(12);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action12::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
-        (1, __symbol, 25)
+        let len = array_length(len).map_err(|error| ParseError::User { error })?;
+        Ok(Expression::RepeatArray(tok, Box::new(value), len))
     }
-    pub(crate) fn __reduce49<
-    >(
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: ::std::marker::PhantomData<()>,
-    ) -> (usize, (usize,__Symbol<>,usize), usize)
+}
+
+fn __action80<
+>(
+    (_, base, _): (usize, Expression, usize),
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, index, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Index(tok, Box::new(base), Box::new(index))
+}
+
+fn __action81<
+>(
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, args, _): (usize, Vec<Expression>, usize),
+) -> Expression
+{
+    Expression::Call(name, args)
+}
+
+fn __action82<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::VariableAccess(__0)
+}
+
+fn __action83<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action84<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action85<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action86<
+>(
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+) -> FieldInit
+{
+    FieldInit::new(name, value)
+}
+
+fn __action87<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, arg, _): (usize, Expression, usize),
+    (_, rest, _): (usize, ::std::vec::Vec<Expression>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Vec<Expression>
+{
+    { let mut args = vec![arg]; args.extend(rest); args }
+}
+
+fn __action88<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+    (_, __1, _): (usize, Spanned<Tok>, usize),
+) -> Vec<Expression>
+{
+    vec![]
+}
+
+fn __action89<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, address, _): (usize, Expression, usize),
+    (_, immediates, _): (usize, MemoryImmediates, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Load(tok, Box::new(MemoryAccess::new(ty, address, None, immediates)))
+}
+
+fn __action90<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, address, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, value, _): (usize, Expression, usize),
+    (_, immediates, _): (usize, MemoryImmediates, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Store(tok, Box::new(MemoryAccess::new(ty, address, Some(value), immediates)))
+}
+
+fn __action91<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Memory(tok, name, None)
+}
+
+fn __action92<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, name, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, arg, _): (usize, Expression, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Memory(tok, name, Some(Box::new(arg)))
+}
+
+fn __action93<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> MemoryImmediates
+{
+    MemoryImmediates::new(None, None)
+}
+
+fn __action94<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, offset, _): (usize, Spanned<Tok>, usize),
+) -> MemoryImmediates
+{
+    MemoryImmediates::new(Some(offset), None)
+}
+
+fn __action95<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, offset, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, align, _): (usize, Spanned<Tok>, usize),
+) -> MemoryImmediates
+{
+    MemoryImmediates::new(Some(offset), Some(align))
+}
+
+fn __action96<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action97<
+>(
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, expr, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::unary(op, Box::new(expr))
+}
+
+fn __action98<
+>(
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, expr, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::unary(op, Box::new(expr))
+}
+
+fn __action99<
+>(
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, expr, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::unary(op, Box::new(expr))
+}
+
+fn __action100<
+>(
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, array, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::SliceOf(tok, Box::new(array))
+}
+
+fn __action101<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action102<
+>(
+    (_, expr, _): (usize, Expression, usize),
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+) -> Expression
+{
+    Expression::Convert(tok, Box::new(expr), ty, Conversion::Trapping)
+}
+
+fn __action103<
+>(
+    (_, expr, _): (usize, Expression, usize),
+    (_, tok, _): (usize, Spanned<Tok>, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, ty, _): (usize, Spanned<Type>, usize),
+) -> Expression
+{
+    Expression::Convert(tok, Box::new(expr), ty, Conversion::Saturating)
+}
+
+fn __action104<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action105<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action106<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action107<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action108<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action109<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action110<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action111<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action112<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action113<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action114<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action115<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action116<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action117<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action118<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action119<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action120<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action121<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action122<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action123<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action124<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action125<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action126<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action127<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action128<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action129<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action130<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action131<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Const(ast::ConstExpression::integer(__0))
+}
+
+fn __action132<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Const(ast::ConstExpression::float(__0))
+}
+
+fn __action133<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Const(ast::ConstExpression::Bool(true.copy_span(&__0)))
+}
+
+fn __action134<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    Expression::Const(ast::ConstExpression::Bool(false.copy_span(&__0)))
+}
+
+fn __action135<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::logical(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action136<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::logical(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action137<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::compare(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action138<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::compare(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action139<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::compare(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action140<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::compare(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action141<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::compare(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action142<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::compare(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action143<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action144<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action145<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action146<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action147<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action148<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action149<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action150<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action151<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action152<
+>(
+    (_, lhs, _): (usize, Expression, usize),
+    (_, op, _): (usize, Spanned<Tok>, usize),
+    (_, rhs, _): (usize, Expression, usize),
+) -> Expression
+{
+    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+}
+
+fn __action153<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    Some(__0)
+}
+
+fn __action154<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    None
+}
+
+fn __action155<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Expression>
+{
+    vec![]
+}
+
+fn __action156<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Expression>, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    v
+}
+
+fn __action157<
+>(
+    (_, items, _): (usize, ::std::vec::Vec<FieldInit>, usize),
+    (_, last, _): (usize, ::std::option::Option<FieldInit>, usize),
+) -> Vec<FieldInit>
+{
     {
-        // Type = "f32" => ActionFn(13);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action13::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
-        (1, __symbol, 25)
+        let mut items = items;
+        items.extend(last);
+        items
     }
-    pub(crate) fn __reduce50<
-    >(
-        __action: i8,
-        __lookahead_start: Option<&usize>,
-        __states: &mut ::std::vec::Vec<i8>,
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<>,usize)>,
-        _: ::std::marker::PhantomData<()>,
-    ) -> (usize, (usize,__Symbol<>,usize), usize)
+}
+
+fn __action158<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    vec![__0]
+}
+
+fn __action159<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Expression>, usize),
+    (_, e, _): (usize, Expression, usize),
+) -> ::std::vec::Vec<Expression>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action160<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Expression, usize),
+) -> Expression
+{
+    (__0)
+}
+
+fn __action161<
+>(
+    (_, __0, _): (usize, Expression, usize),
+) -> ::std::option::Option<Expression>
+{
+    Some(__0)
+}
+
+fn __action162<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Expression>
+{
+    None
+}
+
+fn __action163<
+>(
+    (_, __0, _): (usize, Binding, usize),
+) -> ::std::vec::Vec<Binding>
+{
+    vec![__0]
+}
+
+fn __action164<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Binding>, usize),
+    (_, e, _): (usize, Binding, usize),
+) -> ::std::vec::Vec<Binding>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action165<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Binding, usize),
+) -> Binding
+{
+    (__0)
+}
+
+fn __action166<
+>(
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> ::std::option::Option<Spanned<Type>>
+{
+    Some(__0)
+}
+
+fn __action167<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Spanned<Type>>
+{
+    None
+}
+
+fn __action168<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> Spanned<Type>
+{
+    (__0)
+}
+
+fn __action169<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+fn __action170<
+>(
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> ::std::vec::Vec<Spanned<Type>>
+{
+    vec![__0]
+}
+
+fn __action171<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Spanned<Type>>, usize),
+    (_, e, _): (usize, Spanned<Type>, usize),
+) -> ::std::vec::Vec<Spanned<Type>>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action172<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Spanned<Type>, usize),
+) -> Spanned<Type>
+{
+    (__0)
+}
+
+fn __action173<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+fn __action174<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Parameter>
+{
+    vec![]
+}
+
+fn __action175<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Parameter>, usize),
+) -> ::std::vec::Vec<Parameter>
+{
+    v
+}
+
+fn __action176<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Parameter, usize),
+) -> Parameter
+{
+    (__0)
+}
+
+fn __action177<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    Some(__0)
+}
+
+fn __action178<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    None
+}
+
+fn __action179<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Tok>
+{
+    (__0)
+}
+
+fn __action180<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<TypeParameter>
+{
+    vec![]
+}
+
+fn __action181<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<TypeParameter>, usize),
+) -> ::std::vec::Vec<TypeParameter>
+{
+    v
+}
+
+fn __action182<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, TypeParameter, usize),
+) -> TypeParameter
+{
+    (__0)
+}
+
+fn __action183<
+>(
+    (_, __0, _): (usize, Vec<TypeParameter>, usize),
+) -> ::std::option::Option<Vec<TypeParameter>>
+{
+    Some(__0)
+}
+
+fn __action184<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Vec<TypeParameter>>
+{
+    None
+}
+
+fn __action185<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    Some(__0)
+}
+
+fn __action186<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    None
+}
+
+fn __action187<
+>(
+    (_, _, _): (usize, Spanned<Tok>, usize),
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> Spanned<Tok>
+{
+    (__0)
+}
+
+fn __action188<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    Some(__0)
+}
+
+fn __action189<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    None
+}
+
+fn __action190<
+>(
+    (_, __0, _): (usize, Spanned<Tok>, usize),
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    Some(__0)
+}
+
+fn __action191<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<Spanned<Tok>>
+{
+    None
+}
+
+fn __action192<
+>(
+    (_, items, _): (usize, ::std::vec::Vec<FieldDeclaration>, usize),
+    (_, last, _): (usize, ::std::option::Option<FieldDeclaration>, usize),
+) -> Vec<FieldDeclaration>
+{
     {
-        // Type = "f64" => ActionFn(14);
-        let __sym0 = __pop_Variant0(__symbols);
-        let __start = __sym0.0.clone();
-        let __end = __sym0.2.clone();
-        let __nt = super::__action14::<>(__sym0);
-        let __symbol = (__start, __Symbol::Variant10(__nt), __end);
-        (1, __symbol, 25)
+        let mut items = items;
+        items.extend(last);
+        items
     }
 }
-pub use self::__parse__Module::ModuleParser;
 
-fn __action0<
+fn __action193<
+>(
+    (_, __0, _): (usize, ::std::vec::Vec<Item>, usize),
+) -> ::std::vec::Vec<Item>
+{
+    (__0)
+}
+
+fn __action194<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<Item>
+{
+    vec![]
+}
+
+fn __action195<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Item>, usize),
+) -> ::std::vec::Vec<Item>
+{
+    v
+}
+
+fn __action196<
+>(
+    (_, __0, _): (usize, Item, usize),
+) -> ::std::vec::Vec<Item>
+{
+    vec![__0]
+}
+
+fn __action197<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Item>, usize),
+    (_, e, _): (usize, Item, usize),
+) -> ::std::vec::Vec<Item>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action198<
+>(
+    (_, __0, _): (usize, FieldDeclaration, usize),
+) -> ::std::option::Option<FieldDeclaration>
+{
+    Some(__0)
+}
+
+fn __action199<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<FieldDeclaration>
+{
+    None
+}
+
+fn __action200<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<FieldDeclaration>
+{
+    vec![]
+}
+
+fn __action201<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<FieldDeclaration>, usize),
+) -> ::std::vec::Vec<FieldDeclaration>
+{
+    v
+}
+
+fn __action202<
+>(
+    (_, __0, _): (usize, FieldDeclaration, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> FieldDeclaration
+{
+    (__0)
+}
+
+fn __action203<
+>(
+    (_, __0, _): (usize, TypeParameter, usize),
+) -> ::std::vec::Vec<TypeParameter>
+{
+    vec![__0]
+}
+
+fn __action204<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<TypeParameter>, usize),
+    (_, e, _): (usize, TypeParameter, usize),
+) -> ::std::vec::Vec<TypeParameter>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action205<
+>(
+    (_, __0, _): (usize, Parameter, usize),
+) -> ::std::vec::Vec<Parameter>
+{
+    vec![__0]
+}
+
+fn __action206<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<Parameter>, usize),
+    (_, e, _): (usize, Parameter, usize),
+) -> ::std::vec::Vec<Parameter>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action207<
+>(
+    (_, __0, _): (usize, FieldInit, usize),
+) -> ::std::option::Option<FieldInit>
+{
+    Some(__0)
+}
+
+fn __action208<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::option::Option<FieldInit>
+{
+    None
+}
+
+fn __action209<
+>(
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> ::std::vec::Vec<FieldInit>
+{
+    vec![]
+}
+
+fn __action210<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<FieldInit>, usize),
+) -> ::std::vec::Vec<FieldInit>
+{
+    v
+}
+
+fn __action211<
+>(
+    (_, __0, _): (usize, FieldInit, usize),
+    (_, _, _): (usize, Spanned<Tok>, usize),
+) -> FieldInit
+{
+    (__0)
+}
+
+fn __action212<
+>(
+    (_, __0, _): (usize, FieldInit, usize),
+) -> ::std::vec::Vec<FieldInit>
+{
+    vec![__0]
+}
+
+fn __action213<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<FieldInit>, usize),
+    (_, e, _): (usize, FieldInit, usize),
+) -> ::std::vec::Vec<FieldInit>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action214<
+>(
+    (_, __0, _): (usize, FieldDeclaration, usize),
+) -> ::std::vec::Vec<FieldDeclaration>
+{
+    vec![__0]
+}
+
+fn __action215<
+>(
+    (_, v, _): (usize, ::std::vec::Vec<FieldDeclaration>, usize),
+    (_, e, _): (usize, FieldDeclaration, usize),
+) -> ::std::vec::Vec<FieldDeclaration>
+{
+    { let mut v = v; v.push(e); v }
+}
+
+fn __action216<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, ::std::vec::Vec<Expression>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    let __start0 = __3.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action153(
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action78(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __4,
+    )
+}
+
+fn __action217<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, ::std::vec::Vec<Expression>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action154(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action78(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
+}
+
+fn __action218<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, Spanned<Type>, usize),
+    __6: (usize, Spanned<Tok>, usize),
+    __7: (usize, Expression, usize),
+    __8: (usize, Spanned<Tok>, usize),
+) -> GlobalDeclaration
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action190(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+fn __action219<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Type>, usize),
+    __5: (usize, Spanned<Tok>, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, Spanned<Tok>, usize),
+) -> GlobalDeclaration
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action191(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action11(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+fn __action220<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+) -> MemoryDeclaration
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action190(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
+}
+
+fn __action221<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, ::std::option::Option<Spanned<Tok>>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> MemoryDeclaration
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action191(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action12(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+fn __action222<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+) -> Binding
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action188(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        __temp0,
+        __1,
+    )
+}
+
+fn __action223<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+) -> Binding
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action189(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action48(
+        __temp0,
+        __0,
+    )
+}
+
+fn __action224<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, Spanned<Type>, usize),
+    __6: (usize, Spanned<Tok>, usize),
+    __7: (usize, Expression, usize),
+    __8: (usize, Spanned<Tok>, usize),
+) -> GlobalDeclaration
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action188(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action218(
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+        __8,
+    )
+}
+
+fn __action225<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Type>, usize),
+    __5: (usize, Spanned<Tok>, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, Spanned<Tok>, usize),
+) -> GlobalDeclaration
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action189(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action218(
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+fn __action226<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Type>, usize),
+    __5: (usize, Spanned<Tok>, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, Spanned<Tok>, usize),
+) -> GlobalDeclaration
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action188(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action219(
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+        __7,
+    )
+}
+
+fn __action227<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Type>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, Expression, usize),
+    __6: (usize, Spanned<Tok>, usize),
+) -> GlobalDeclaration
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action189(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action219(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+fn __action228<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, ::std::option::Option<Spanned<Type>>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, Expression, usize),
+    __6: (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action188(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        __0,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+fn __action229<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, ::std::option::Option<Spanned<Type>>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Expression, usize),
+    __5: (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __1.0.clone();
+    let __temp0 = __action189(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action46(
+        __0,
+        __temp0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+fn __action230<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Type>, usize),
+) -> Parameter
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action188(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        __temp0,
+        __1,
+        __2,
+        __3,
+    )
+}
+
+fn __action231<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Type>, usize),
+) -> Parameter
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action189(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action23(
+        __temp0,
+        __0,
+        __1,
+        __2,
+    )
+}
+
+fn __action232<
 >(
-    (_, __0, _): (usize, Module, usize),
-) -> Module
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Binding, usize),
+) -> ::std::vec::Vec<Binding>
 {
-    (__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action165(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action163(
+        __temp0,
+    )
 }
 
-fn __action1<
+fn __action233<
 >(
-    (_, __0, _): (usize, ::std::vec::Vec<Function>, usize),
-) -> Module
+    __0: (usize, ::std::vec::Vec<Binding>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Binding, usize),
+) -> ::std::vec::Vec<Binding>
 {
-    Module::new(__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action165(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action164(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action2<
+fn __action234<
 >(
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, __0, _): (usize, Function, usize),
-) -> Function
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+) -> ::std::vec::Vec<Expression>
 {
-    __0.exported()
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action160(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action158(
+        __temp0,
+    )
 }
 
-fn __action3<
+fn __action235<
 >(
-    (_, __0, _): (usize, Function, usize),
-) -> Function
+    __0: (usize, ::std::vec::Vec<Expression>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Expression, usize),
+) -> ::std::vec::Vec<Expression>
 {
-    (__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action160(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action159(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action4<
+fn __action236<
 >(
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, name, _): (usize, Spanned<Tok>, usize),
-    (_, args, _): (usize, Parameters, usize),
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, ty, _): (usize, Spanned<Type>, usize),
-    (_, body, _): (usize, Block, usize),
-) -> Function
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> Vec<Expression>
 {
-    Function::new(name, args, ty, body)
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action155(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
-fn __action5<
+fn __action237<
 >(
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, name, _): (usize, Spanned<Tok>, usize),
-    (_, args, _): (usize, Parameters, usize),
-    (_, body, _): (usize, Block, usize),
-) -> Function
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, ::std::vec::Vec<Expression>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Vec<Expression>
 {
-    Function::new(name, args, Type::void(), body)
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action156(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action87(
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
-fn __action6<
+fn __action238<
 >(
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, __0, _): (usize, Parameter, usize),
-    (_, __1, _): (usize, ::std::vec::Vec<Parameter>, usize),
-    (_, _, _): (usize, Spanned<Tok>, usize),
-) -> Parameters
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Expression
 {
-    Parameters::from_parser(__0, __1)
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action155(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action216(
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+    )
 }
 
-fn __action7<
+fn __action239<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-    (_, __1, _): (usize, Spanned<Tok>, usize),
-) -> Parameters
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, ::std::vec::Vec<Expression>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+) -> Expression
 {
-    Parameters::empty()
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action156(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action216(
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
 }
 
-fn __action8<
+fn __action240<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, __1, _): (usize, Spanned<Type>, usize),
-) -> Parameter
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> Expression
 {
-    Parameter::new(__0, __1)
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action155(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action217(
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
-fn __action9<
+fn __action241<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Spanned<Type>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, ::std::vec::Vec<Expression>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Expression
 {
-    Type::i32().copy_span(&__0)
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action156(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action217(
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
-fn __action10<
+fn __action242<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Spanned<Type>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Parameter, usize),
+) -> ::std::vec::Vec<Parameter>
 {
-    Type::i64().copy_span(&__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action176(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action205(
+        __temp0,
+    )
 }
 
-fn __action11<
+fn __action243<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Spanned<Type>
+    __0: (usize, ::std::vec::Vec<Parameter>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Parameter, usize),
+) -> ::std::vec::Vec<Parameter>
 {
-    Type::u32().copy_span(&__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action176(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action206(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action12<
+fn __action244<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Spanned<Type>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Parameter, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> Parameters
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action174(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
+}
+
+fn __action245<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Parameter, usize),
+    __2: (usize, ::std::vec::Vec<Parameter>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Parameters
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action175(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action21(
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
+}
+
+fn __action246<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Type>, usize),
+) -> ::std::vec::Vec<Spanned<Type>>
 {
-    Type::u64().copy_span(&__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action172(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action170(
+        __temp0,
+    )
 }
 
-fn __action13<
+fn __action247<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Spanned<Type>
+    __0: (usize, ::std::vec::Vec<Spanned<Type>>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Type>, usize),
+) -> ::std::vec::Vec<Spanned<Type>>
 {
-    Type::f32().copy_span(&__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action172(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action171(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action14<
+fn __action248<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Spanned<Type>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, TypeParameter, usize),
+) -> ::std::vec::Vec<TypeParameter>
 {
-    Type::f64().copy_span(&__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action182(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action203(
+        __temp0,
+    )
 }
 
-fn __action15<
+fn __action249<
 >(
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, __0, _): (usize, ::std::vec::Vec<Expression>, usize),
-    (_, _, _): (usize, Spanned<Tok>, usize),
-) -> Block
+    __0: (usize, ::std::vec::Vec<TypeParameter>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, TypeParameter, usize),
+) -> ::std::vec::Vec<TypeParameter>
 {
-    Block::new(__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action182(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action204(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action16<
+fn __action250<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, TypeParameter, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> Vec<TypeParameter>
 {
-    (__0)
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action180(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
-fn __action17<
+fn __action251<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, TypeParameter, usize),
+    __2: (usize, ::std::vec::Vec<TypeParameter>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Vec<TypeParameter>
 {
-    Expression::VariableAccess(__0)
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action181(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action19(
+        __0,
+        __1,
+        __temp0,
+        __3,
+    )
 }
 
-fn __action18<
+fn __action252<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+) -> ::std::option::Option<Spanned<Tok>>
 {
-    (__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action187(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action185(
+        __temp0,
+    )
 }
 
-fn __action19<
+fn __action253<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, Spanned<Tok>, usize),
+) -> MemoryDeclaration
 {
-    (__0)
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action252(
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action220(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+    )
 }
 
-fn __action20<
+fn __action254<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> MemoryDeclaration
 {
-    (__0)
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action186(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action220(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+    )
 }
 
-fn __action21<
+fn __action255<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+) -> MemoryDeclaration
 {
-    (__0)
+    let __start0 = __2.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action252(
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action221(
+        __0,
+        __1,
+        __temp0,
+        __4,
+    )
 }
 
-fn __action22<
+fn __action256<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> MemoryDeclaration
 {
-    (__0)
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action186(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action221(
+        __0,
+        __1,
+        __temp0,
+        __2,
+    )
 }
 
-fn __action23<
+fn __action257<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+) -> ::std::option::Option<Spanned<Tok>>
 {
-    (__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action179(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action177(
+        __temp0,
+    )
 }
 
-fn __action24<
+fn __action258<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> TypeParameter
 {
-    (__0)
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action257(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action25<
+fn __action259<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+) -> TypeParameter
 {
-    (__0)
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action178(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action20(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action26<
+fn __action260<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
-) -> Expression
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Type>, usize),
+) -> ::std::option::Option<Spanned<Type>>
 {
-    Expression::Const(ast::ConstExpression::Integer(__0.to_spanned_i32()))
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action168(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action166(
+        __temp0,
+    )
 }
 
-fn __action27<
+fn __action261<
 >(
-    (_, __0, _): (usize, Spanned<Tok>, usize),
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Type>, usize),
+    __5: (usize, Spanned<Tok>, usize),
+    __6: (usize, Expression, usize),
+    __7: (usize, Spanned<Tok>, usize),
 ) -> Expression
 {
-    Expression::Const(ast::ConstExpression::Float(__0.to_spanned_f64()))
+    let __start0 = __3.0.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action260(
+        __3,
+        __4,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action228(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __5,
+        __6,
+        __7,
+    )
 }
 
-fn __action28<
+fn __action262<
 >(
-    (_, lhs, _): (usize, Expression, usize),
-    (_, op, _): (usize, Spanned<Tok>, usize),
-    (_, rhs, _): (usize, Expression, usize),
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Expression, usize),
+    __5: (usize, Spanned<Tok>, usize),
 ) -> Expression
 {
-    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+    let __start0 = __2.2.clone();
+    let __end0 = __3.0.clone();
+    let __temp0 = __action167(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action228(
+        __0,
+        __1,
+        __2,
+        __temp0,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-fn __action29<
+fn __action263<
 >(
-    (_, lhs, _): (usize, Expression, usize),
-    (_, op, _): (usize, Spanned<Tok>, usize),
-    (_, rhs, _): (usize, Expression, usize),
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Type>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, Expression, usize),
+    __6: (usize, Spanned<Tok>, usize),
 ) -> Expression
 {
-    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+    let __start0 = __2.0.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action260(
+        __2,
+        __3,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action229(
+        __0,
+        __1,
+        __temp0,
+        __4,
+        __5,
+        __6,
+    )
 }
 
-fn __action30<
+fn __action264<
 >(
-    (_, lhs, _): (usize, Expression, usize),
-    (_, op, _): (usize, Spanned<Tok>, usize),
-    (_, rhs, _): (usize, Expression, usize),
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Expression, usize),
+    __4: (usize, Spanned<Tok>, usize),
 ) -> Expression
 {
-    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action167(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action229(
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-fn __action31<
+fn __action265<
 >(
-    (_, lhs, _): (usize, Expression, usize),
-    (_, op, _): (usize, Spanned<Tok>, usize),
-    (_, rhs, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, FieldDeclaration, usize),
+    __1: (usize, Spanned<Tok>, usize),
+) -> ::std::vec::Vec<FieldDeclaration>
 {
-    Expression::binary(op, Box::new(BinaryExpression::new(lhs, rhs)))
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action202(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action214(
+        __temp0,
+    )
 }
 
-fn __action32<
+fn __action266<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<Expression>
+    __0: (usize, ::std::vec::Vec<FieldDeclaration>, usize),
+    __1: (usize, FieldDeclaration, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> ::std::vec::Vec<FieldDeclaration>
 {
-    vec![]
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action202(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action215(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action33<
+fn __action267<
 >(
-    (_, v, _): (usize, ::std::vec::Vec<Expression>, usize),
-) -> ::std::vec::Vec<Expression>
+    __0: (usize, ::std::option::Option<FieldDeclaration>, usize),
+) -> Vec<FieldDeclaration>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action200(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action192(
+        __temp0,
+        __0,
+    )
 }
 
-fn __action34<
+fn __action268<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> Expression
+    __0: (usize, ::std::vec::Vec<FieldDeclaration>, usize),
+    __1: (usize, ::std::option::Option<FieldDeclaration>, usize),
+) -> Vec<FieldDeclaration>
 {
-    (__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action201(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action192(
+        __temp0,
+        __1,
+    )
 }
 
-fn __action35<
+fn __action269<
 >(
-    __lookbehind: &usize,
-    __lookahead: &usize,
-) -> ::std::vec::Vec<Parameter>
+    __0: (usize, FieldInit, usize),
+    __1: (usize, Spanned<Tok>, usize),
+) -> ::std::vec::Vec<FieldInit>
 {
-    vec![]
+    let __start0 = __0.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action211(
+        __0,
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action212(
+        __temp0,
+    )
 }
 
-fn __action36<
+fn __action270<
 >(
-    (_, v, _): (usize, ::std::vec::Vec<Parameter>, usize),
-) -> ::std::vec::Vec<Parameter>
+    __0: (usize, ::std::vec::Vec<FieldInit>, usize),
+    __1: (usize, FieldInit, usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> ::std::vec::Vec<FieldInit>
 {
-    v
+    let __start0 = __1.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action211(
+        __1,
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action213(
+        __0,
+        __temp0,
+    )
 }
 
-fn __action37<
+fn __action271<
 >(
-    (_, _, _): (usize, Spanned<Tok>, usize),
-    (_, __0, _): (usize, Parameter, usize),
-) -> Parameter
+    __0: (usize, ::std::option::Option<FieldInit>, usize),
+) -> Vec<FieldInit>
 {
-    (__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action209(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(
+        __temp0,
+        __0,
+    )
 }
 
-fn __action38<
+fn __action272<
 >(
-    (_, __0, _): (usize, ::std::vec::Vec<Function>, usize),
-) -> ::std::vec::Vec<Function>
+    __0: (usize, ::std::vec::Vec<FieldInit>, usize),
+    __1: (usize, ::std::option::Option<FieldInit>, usize),
+) -> Vec<FieldInit>
 {
-    (__0)
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action210(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action157(
+        __temp0,
+        __1,
+    )
 }
 
-fn __action39<
+fn __action273<
 >(
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> ::std::vec::Vec<Function>
+) -> ::std::vec::Vec<Item>
 {
-    vec![]
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action194(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action193(
+        __temp0,
+    )
 }
 
-fn __action40<
+fn __action274<
 >(
-    (_, v, _): (usize, ::std::vec::Vec<Function>, usize),
-) -> ::std::vec::Vec<Function>
+    __0: (usize, ::std::vec::Vec<Item>, usize),
+) -> ::std::vec::Vec<Item>
 {
-    v
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action195(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action193(
+        __temp0,
+    )
 }
 
-fn __action41<
+fn __action275<
 >(
-    (_, __0, _): (usize, Function, usize),
-) -> ::std::vec::Vec<Function>
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> Module
 {
-    vec![__0]
+    let __start0 = __lookbehind.clone();
+    let __end0 = __lookahead.clone();
+    let __temp0 = __action273(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
 }
 
-fn __action42<
+fn __action276<
 >(
-    (_, v, _): (usize, ::std::vec::Vec<Function>, usize),
-    (_, e, _): (usize, Function, usize),
-) -> ::std::vec::Vec<Function>
+    __0: (usize, ::std::vec::Vec<Item>, usize),
+) -> Module
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action274(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action1(
+        __temp0,
+    )
 }
 
-fn __action43<
+fn __action277<
 >(
-    (_, __0, _): (usize, Parameter, usize),
-) -> ::std::vec::Vec<Parameter>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, (Vec<Expression>, bool), usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, usize, usize),
+) -> Block
 {
-    vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action173(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action39(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+    )
 }
 
-fn __action44<
+fn __action278<
 >(
-    (_, v, _): (usize, ::std::vec::Vec<Parameter>, usize),
-    (_, e, _): (usize, Parameter, usize),
-) -> ::std::vec::Vec<Parameter>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, Block, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Expression, usize),
+    __5: (usize, usize, usize),
+) -> Expression
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __3.2.clone();
+    let __end0 = __4.0.clone();
+    let __temp0 = __action173(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action68(
+        __0,
+        __1,
+        __2,
+        __3,
+        __temp0,
+        __4,
+        __5,
+    )
 }
 
-fn __action45<
+fn __action279<
 >(
-    (_, __0, _): (usize, Expression, usize),
-) -> ::std::vec::Vec<Expression>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Type>, usize),
+    __2: (usize, ::std::vec::Vec<Spanned<Type>>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, usize, usize),
+) -> Spanned<Type>
 {
-    vec![__0]
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action173(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action27(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+    )
 }
 
-fn __action46<
+fn __action280<
 >(
-    (_, v, _): (usize, ::std::vec::Vec<Expression>, usize),
-    (_, e, _): (usize, Expression, usize),
-) -> ::std::vec::Vec<Expression>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Type>, usize),
+    __2: (usize, Spanned<Tok>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, usize, usize),
+) -> Result<Spanned<Type>,__lalrpop_util::ParseError<usize,Spanned<Tok>,crate::CompileError>>
 {
-    { let mut v = v; v.push(e); v }
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action173(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action28(
+        __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
 }
 
-fn __action47<
+fn __action281<
 >(
     __0: (usize, Spanned<Tok>, usize),
-    __1: (usize, Parameter, usize),
-) -> ::std::vec::Vec<Parameter>
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Type>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, usize, usize),
+) -> Spanned<Type>
 {
     let __start0 = __0.0.clone();
-    let __end0 = __1.2.clone();
-    let __temp0 = __action37(
-        __0,
-        __1,
+    let __end0 = __0.0.clone();
+    let __temp0 = __action173(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action43(
+    __action29(
         __temp0,
+        __0,
+        __1,
+        __2,
+        __3,
+        __4,
     )
 }
 
-fn __action48<
+fn __action282<
 >(
-    __0: (usize, ::std::vec::Vec<Parameter>, usize),
-    __1: (usize, Spanned<Tok>, usize),
-    __2: (usize, Parameter, usize),
-) -> ::std::vec::Vec<Parameter>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, (Vec<Expression>, bool), usize),
+    __2: (usize, Spanned<Tok>, usize),
+) -> Block
 {
-    let __start0 = __1.0.clone();
+    let __start0 = __2.2.clone();
     let __end0 = __2.2.clone();
-    let __temp0 = __action37(
+    let __temp0 = __action169(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action277(
+        __0,
         __1,
         __2,
+        __temp0,
+    )
+}
+
+fn __action283<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Expression, usize),
+    __2: (usize, Block, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Expression, usize),
+) -> Expression
+{
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action169(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action44(
+    __action278(
         __0,
+        __1,
+        __2,
+        __3,
+        __4,
         __temp0,
     )
 }
 
-fn __action49<
+fn __action284<
 >(
     __0: (usize, Spanned<Tok>, usize),
-    __1: (usize, Parameter, usize),
-    __2: (usize, Spanned<Tok>, usize),
-) -> Parameters
+    __1: (usize, Spanned<Type>, usize),
+    __2: (usize, ::std::vec::Vec<Spanned<Type>>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
 {
-    let __start0 = __1.2.clone();
-    let __end0 = __2.0.clone();
-    let __temp0 = __action35(
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action169(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action279(
         __0,
         __1,
-        __temp0,
         __2,
+        __3,
+        __temp0,
     )
 }
 
-fn __action50<
+fn __action285<
 >(
     __0: (usize, Spanned<Tok>, usize),
-    __1: (usize, Parameter, usize),
-    __2: (usize, ::std::vec::Vec<Parameter>, usize),
+    __1: (usize, Spanned<Type>, usize),
+    __2: (usize, Spanned<Tok>, usize),
     __3: (usize, Spanned<Tok>, usize),
-) -> Parameters
+    __4: (usize, Spanned<Tok>, usize),
+) -> Result<Spanned<Type>,__lalrpop_util::ParseError<usize,Spanned<Tok>,crate::CompileError>>
 {
-    let __start0 = __2.0.clone();
-    let __end0 = __2.2.clone();
-    let __temp0 = __action36(
-        __2,
+    let __start0 = __4.2.clone();
+    let __end0 = __4.2.clone();
+    let __temp0 = __action169(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action6(
+    __action280(
         __0,
         __1,
-        __temp0,
+        __2,
         __3,
+        __4,
+        __temp0,
     )
 }
 
-fn __action51<
+fn __action286<
 >(
-    __0: (usize, Expression, usize),
-) -> ::std::vec::Vec<Expression>
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Spanned<Type>, usize),
+    __3: (usize, Spanned<Tok>, usize),
+) -> Spanned<Type>
 {
-    let __start0 = __0.0.clone();
-    let __end0 = __0.2.clone();
-    let __temp0 = __action34(
-        __0,
+    let __start0 = __3.2.clone();
+    let __end0 = __3.2.clone();
+    let __temp0 = __action169(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action45(
+    __action281(
+        __0,
+        __1,
+        __2,
+        __3,
         __temp0,
     )
 }
 
-fn __action52<
+fn __action287<
 >(
-    __0: (usize, ::std::vec::Vec<Expression>, usize),
+    __0: (usize, Spanned<Tok>, usize),
     __1: (usize, Expression, usize),
-) -> ::std::vec::Vec<Expression>
+) -> Expression
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action34(
+    let __temp0 = __action161(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action46(
+    __action64(
         __0,
         __temp0,
     )
 }
 
-fn __action53<
+fn __action288<
 >(
     __0: (usize, Spanned<Tok>, usize),
-    __1: (usize, Spanned<Tok>, usize),
-) -> Block
+) -> Expression
 {
     let __start0 = __0.2.clone();
-    let __end0 = __1.0.clone();
-    let __temp0 = __action32(
+    let __end0 = __0.2.clone();
+    let __temp0 = __action162(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
+    __action64(
         __0,
         __temp0,
-        __1,
     )
 }
 
-fn __action54<
+fn __action289<
 >(
     __0: (usize, Spanned<Tok>, usize),
-    __1: (usize, ::std::vec::Vec<Expression>, usize),
-    __2: (usize, Spanned<Tok>, usize),
-) -> Block
+    __1: (usize, Expression, usize),
+) -> Expression
 {
     let __start0 = __1.0.clone();
     let __end0 = __1.2.clone();
-    let __temp0 = __action33(
+    let __temp0 = __action161(
         __1,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action15(
+    __action65(
         __0,
         __temp0,
-        __2,
     )
 }
 
-fn __action55<
+fn __action290<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+) -> Expression
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action162(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action65(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action291<
+>(
+    __0: (usize, FieldDeclaration, usize),
+) -> Vec<FieldDeclaration>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action198(
+        __0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action267(
+        __temp0,
+    )
+}
+
+fn __action292<
 >(
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> ::std::vec::Vec<Function>
+) -> Vec<FieldDeclaration>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action39(
+    let __temp0 = __action199(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action38(
+    __action267(
         __temp0,
     )
 }
 
-fn __action56<
+fn __action293<
+>(
+    __0: (usize, ::std::vec::Vec<FieldDeclaration>, usize),
+    __1: (usize, FieldDeclaration, usize),
+) -> Vec<FieldDeclaration>
+{
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action198(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action268(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action294<
+>(
+    __0: (usize, ::std::vec::Vec<FieldDeclaration>, usize),
+) -> Vec<FieldDeclaration>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action199(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action268(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action295<
 >(
-    __0: (usize, ::std::vec::Vec<Function>, usize),
-) -> ::std::vec::Vec<Function>
+    __0: (usize, FieldInit, usize),
+) -> Vec<FieldInit>
 {
     let __start0 = __0.0.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action40(
+    let __temp0 = __action207(
         __0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action38(
+    __action271(
         __temp0,
     )
 }
 
-fn __action57<
+fn __action296<
 >(
     __lookbehind: &usize,
     __lookahead: &usize,
-) -> Module
+) -> Vec<FieldInit>
 {
     let __start0 = __lookbehind.clone();
     let __end0 = __lookahead.clone();
-    let __temp0 = __action55(
+    let __temp0 = __action208(
         &__start0,
         &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    __action271(
         __temp0,
     )
 }
 
-fn __action58<
+fn __action297<
 >(
-    __0: (usize, ::std::vec::Vec<Function>, usize),
-) -> Module
+    __0: (usize, ::std::vec::Vec<FieldInit>, usize),
+    __1: (usize, FieldInit, usize),
+) -> Vec<FieldInit>
 {
-    let __start0 = __0.0.clone();
+    let __start0 = __1.0.clone();
+    let __end0 = __1.2.clone();
+    let __temp0 = __action207(
+        __1,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action272(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action298<
+>(
+    __0: (usize, ::std::vec::Vec<FieldInit>, usize),
+) -> Vec<FieldInit>
+{
+    let __start0 = __0.2.clone();
     let __end0 = __0.2.clone();
-    let __temp0 = __action56(
+    let __temp0 = __action208(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action272(
+        __0,
+        __temp0,
+    )
+}
+
+fn __action299<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Vec<TypeParameter>, usize),
+    __3: (usize, Parameters, usize),
+    __4: (usize, Spanned<Tok>, usize),
+    __5: (usize, Spanned<Type>, usize),
+    __6: (usize, Block, usize),
+) -> Function
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action183(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action17(
         __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+        __5,
+        __6,
+    )
+}
+
+fn __action300<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Parameters, usize),
+    __3: (usize, Spanned<Tok>, usize),
+    __4: (usize, Spanned<Type>, usize),
+    __5: (usize, Block, usize),
+) -> Function
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action184(
+        &__start0,
+        &__end0,
     );
     let __temp0 = (__start0, __temp0, __end0);
-    __action1(
+    __action17(
+        __0,
+        __1,
+        __temp0,
+        __2,
+        __3,
+        __4,
+        __5,
+    )
+}
+
+fn __action301<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Vec<TypeParameter>, usize),
+    __3: (usize, Parameters, usize),
+    __4: (usize, Block, usize),
+) -> Function
+{
+    let __start0 = __2.0.clone();
+    let __end0 = __2.2.clone();
+    let __temp0 = __action183(
+        __2,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        __0,
+        __1,
+        __temp0,
+        __3,
+        __4,
+    )
+}
+
+fn __action302<
+>(
+    __0: (usize, Spanned<Tok>, usize),
+    __1: (usize, Spanned<Tok>, usize),
+    __2: (usize, Parameters, usize),
+    __3: (usize, Block, usize),
+) -> Function
+{
+    let __start0 = __1.2.clone();
+    let __end0 = __2.0.clone();
+    let __temp0 = __action184(
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action18(
+        __0,
+        __1,
         __temp0,
+        __2,
+        __3,
     )
 }
 
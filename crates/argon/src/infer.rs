@@ -1,9 +1,13 @@
 crate mod constraint;
 crate mod constraint_set;
+crate mod evaluate;
+crate mod literals;
 crate mod substitution;
 crate mod unify;
 
 crate use self::constraint::Constraint;
 crate use self::constraint_set::Constraints;
+crate use self::evaluate::fold_constants;
+crate use self::literals::{check_function, check_literals};
 crate use self::substitution::Substitution;
 crate use self::unify::UnifyTable;
@@ -1,18 +1,93 @@
 crate use super::constraint_set::Constraints;
-use crate::ir::InferType;
+use crate::ir::{InferType, Span};
+use crate::lexer::IdentifierId;
 
 #[cfg(test)]
 mod tests;
 
 #[derive(Debug, Hash, Eq, PartialEq, Ord, PartialOrd)]
 crate struct Constraint {
+    // Constraints with an origin are checked after the ones without, so
+    // declared types are checked once everything else about the function
+    // is known
+    crate origin: Option<Origin>,
+    crate relation: Relation,
     crate left: InferType,
     crate right: InferType,
 }
 
+// How `right` relates to `left`. Everything but `Equal` needs `left` to be
+// known before it can be checked, so those wait until inference has found
+// out enough about it.
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+crate enum Relation {
+    Equal,
+    // `right` is the type of `left`'s field `name`
+    Field { name: IdentifierId, span: Span },
+    // `right` is the type of the elements of the array or slice `left`
+    Element { span: Span },
+    // `right` is a slice of the array `left`
+    SliceOf { span: Span },
+}
+
+// Where a constraint came from, for reporting it when it fails
+#[derive(Debug, Copy, Clone, Hash, Eq, PartialEq, Ord, PartialOrd)]
+crate enum Origin {
+    // a function body or `return` has to produce the declared return type
+    Return { expression: Span },
+}
+
 impl Constraint {
     crate fn new(left: InferType, right: InferType) -> Constraint {
-        Constraint { left, right }
+        Constraint {
+            origin: None,
+            relation: Relation::Equal,
+            left,
+            right,
+        }
+    }
+
+    // `value`, produced by `expression`, has to match the declared `ret`
+    crate fn returns(ret: InferType, value: InferType, expression: Span) -> Constraint {
+        Constraint {
+            origin: Some(Origin::Return { expression }),
+            relation: Relation::Equal,
+            left: ret,
+            right: value,
+        }
+    }
+
+    // `field` is the type of the field `name` of the struct `base`
+    crate fn field(
+        base: InferType,
+        name: IdentifierId,
+        span: Span,
+        field: InferType,
+    ) -> Constraint {
+        Constraint::projection(Relation::Field { name, span }, base, field)
+    }
+
+    // `element` is the type of `sequence`'s elements
+    crate fn element(sequence: InferType, span: Span, element: InferType) -> Constraint {
+        Constraint::projection(Relation::Element { span }, sequence, element)
+    }
+
+    // `slice` is a slice of `array`
+    crate fn slice_of(array: InferType, span: Span, slice: InferType) -> Constraint {
+        Constraint::projection(Relation::SliceOf { span }, array, slice)
+    }
+
+    fn projection(relation: Relation, left: InferType, right: InferType) -> Constraint {
+        Constraint {
+            origin: None,
+            relation,
+            left,
+            right,
+        }
+    }
+
+    crate fn is_projection(&self) -> bool {
+        self.relation != Relation::Equal
     }
 }
 
@@ -26,5 +101,5 @@ impl std::ops::Add for Constraint {
 
 #[allow(non_snake_case)]
 crate fn Constraint(left: InferType, right: InferType) -> Constraint {
-    Constraint { left, right }
+    Constraint::new(left, right)
 }
@@ -0,0 +1,906 @@
+use crate::annotated::{self, Annotated, Expression};
+use crate::infer::literals::integer_range;
+use crate::ir::ast::{ConstExpression, ConstValue};
+use crate::ir::{Span, Spanned};
+use crate::{
+    CompareOperator, CompileError, ConstError, Conversion, InferType, LogicalOperator,
+    MathOperator, MathType, Type, UnaryOperator,
+};
+use nan_preserving_float::F64;
+use std::ops::{Add, Div, Mul, Sub};
+
+// Evaluates consts, inlines them where they're used, and folds operators
+// whose operands are all constants. Runs once literals have been checked,
+// so every literal fits in its type.
+//
+// Overflow and division by zero are errors in the value of a const or a
+// global. Anywhere else, an operation that would fail is left for the
+// program to run, so folding never changes what a function does.
+crate fn fold_constants(module: annotated::Module) -> Result<annotated::Module, CompileError> {
+    let annotated::Module {
+        funcs,
+        memory,
+        globals,
+        consts,
+        structs,
+    } = module;
+
+    let (funcs, globals, values) = {
+        let mut folder = Folder::new(&consts);
+
+        // every const is evaluated, even if nothing uses it
+        for index in 0..consts.len() {
+            folder.const_value(index as u32)?;
+        }
+
+        let globals: Result<Vec<_>, CompileError> = globals
+            .into_iter()
+            .map(|global| {
+                Ok(annotated::Global {
+                    value: folder.fold_strict(global.value)?,
+                    ..global
+                })
+            })
+            .collect();
+        let globals = globals?;
+
+        let funcs: Result<Vec<_>, CompileError> = funcs
+            .into_iter()
+            .map(|function| {
+                Ok(annotated::Function {
+                    body: folder.fold_block(function.body)?,
+                    ..function
+                })
+            })
+            .collect();
+
+        (funcs?, globals, folder.values)
+    };
+
+    let consts = consts
+        .into_iter()
+        .zip(values)
+        .map(|(constant, value)| annotated::Const {
+            value: Expression::Const(value.expect("every const was evaluated"))
+                .annotate(constant.value.ty.clone()),
+            ..constant
+        })
+        .collect();
+
+    Ok(annotated::Module {
+        funcs,
+        memory,
+        globals,
+        consts,
+        structs,
+    })
+}
+
+struct Folder<'a> {
+    consts: &'a [annotated::Const],
+    // each const's value, once something has needed it
+    values: Vec<Option<ConstExpression>>,
+    // whether failing to evaluate an operation is an error, rather than a
+    // reason to leave it alone
+    strict: bool,
+}
+
+impl Folder<'a> {
+    fn new(consts: &'a [annotated::Const]) -> Folder<'a> {
+        Folder {
+            consts,
+            values: vec![None; consts.len()],
+            strict: false,
+        }
+    }
+
+    // Name resolution rejects consts that refer to themselves, so this
+    // always terminates
+    fn const_value(&mut self, index: u32) -> Result<ConstExpression, CompileError> {
+        if let Some(value) = self.values[index as usize] {
+            return Ok(value);
+        }
+
+        let consts = self.consts;
+        let folded = self.fold_strict(consts[index as usize].value.clone())?;
+
+        let value = match folded.item {
+            Expression::Const(value) => value,
+            other => panic!("{:?} isn't a constant expression", other),
+        };
+
+        self.values[index as usize] = Some(value);
+        Ok(value)
+    }
+
+    fn fold_strict(
+        &mut self,
+        expr: Annotated<Expression>,
+    ) -> Result<Annotated<Expression>, CompileError> {
+        let strict = self.strict;
+
+        self.strict = true;
+        let folded = self.fold(expr);
+        self.strict = strict;
+
+        folded
+    }
+
+    fn fold_block(
+        &mut self,
+        Annotated { item, ty }: Annotated<annotated::Block>,
+    ) -> Result<Annotated<annotated::Block>, CompileError> {
+        let mut expressions = vec![];
+
+        for expr in item.expressions {
+            expressions.push(self.fold(expr)?);
+        }
+
+        Ok(Annotated {
+            item: annotated::Block {
+                expressions,
+                tail: item.tail,
+                span: item.span,
+            },
+            ty,
+        })
+    }
+
+    fn fold_boxed(
+        &mut self,
+        expr: Box<Annotated<Expression>>,
+    ) -> Result<Box<Annotated<Expression>>, CompileError> {
+        let box expr = expr;
+        Ok(box self.fold(expr)?)
+    }
+
+    fn fold_optional(
+        &mut self,
+        expr: Option<Box<Annotated<Expression>>>,
+    ) -> Result<Option<Box<Annotated<Expression>>>, CompileError> {
+        match expr {
+            Some(expr) => Ok(Some(self.fold_boxed(expr)?)),
+            None => Ok(None),
+        }
+    }
+
+    // The result of an operation, or `None` if it fails outside of a
+    // const or a global
+    fn evaluated(
+        &self,
+        result: Result<ConstExpression, ConstError>,
+    ) -> Result<Option<ConstExpression>, CompileError> {
+        match result {
+            Ok(value) => Ok(Some(value)),
+            Err(error) => {
+                if self.strict {
+                    Err(error.into())
+                } else {
+                    Ok(None)
+                }
+            }
+        }
+    }
+
+    fn fold(
+        &mut self,
+        Annotated { item, ty }: Annotated<Expression>,
+    ) -> Result<Annotated<Expression>, CompileError> {
+        let item = match item {
+            Expression::ConstAccess { index, span } => {
+                Expression::Const(respan(self.const_value(index)?, span))
+            }
+
+            Expression::Binary {
+                operator,
+                box lhs,
+                box rhs,
+            } => {
+                let lhs = self.fold(lhs)?;
+                let rhs = self.fold(rhs)?;
+
+                let folded = match (constant(&lhs), constant(&rhs), math_type(&ty)) {
+                    (Some(l), Some(r), Some(math)) => {
+                        self.evaluated(binary(operator.node, l, r, math))?
+                    }
+                    _ => None,
+                };
+
+                match folded {
+                    Some(value) => Expression::Const(value),
+                    None => Expression::Binary {
+                        operator,
+                        lhs: box lhs,
+                        rhs: box rhs,
+                    },
+                }
+            }
+
+            Expression::Compare {
+                operator,
+                box lhs,
+                box rhs,
+            } => {
+                let lhs = self.fold(lhs)?;
+                let rhs = self.fold(rhs)?;
+
+                match (constant(&lhs), constant(&rhs)) {
+                    (Some(l), Some(r)) if is_concrete(&lhs.ty) => {
+                        Expression::Const(compare(operator.node, l, r))
+                    }
+                    _ => Expression::Compare {
+                        operator,
+                        lhs: box lhs,
+                        rhs: box rhs,
+                    },
+                }
+            }
+
+            Expression::Logical {
+                operator,
+                box lhs,
+                box rhs,
+            } => {
+                let lhs = self.fold(lhs)?;
+                let rhs = self.fold(rhs)?;
+
+                match (constant(&lhs), constant(&rhs)) {
+                    (Some(l), Some(r)) => Expression::Const(logical(operator.node, l, r)),
+                    _ => Expression::Logical {
+                        operator,
+                        lhs: box lhs,
+                        rhs: box rhs,
+                    },
+                }
+            }
+
+            Expression::Unary { operator, box expr } => {
+                let expr = self.fold(expr)?;
+
+                // `-` and `!` on a generic type depend on the instance
+                let math = math_type(&ty);
+                let foldable = math.is_some() || operator.node == UnaryOperator::Not;
+
+                let folded = match constant(&expr) {
+                    Some(value) if foldable => self.evaluated(unary(operator, value, math))?,
+                    _ => None,
+                };
+
+                match folded {
+                    Some(value) => Expression::Const(value),
+                    None => Expression::Unary {
+                        operator,
+                        expr: box expr,
+                    },
+                }
+            }
+
+            Expression::Convert {
+                box expr,
+                conversion,
+            } => {
+                let expr = self.fold(expr)?;
+
+                let folded = match (constant(&expr), math_type(&expr.ty), math_type(&ty)) {
+                    (Some(value), Some(from), Some(to)) => {
+                        let span = value.span().to(type_span(&ty));
+                        self.evaluated(convert(value, from, to, conversion, span))?
+                    }
+                    _ => None,
+                };
+
+                match folded {
+                    Some(value) => Expression::Const(value),
+                    None => Expression::Convert {
+                        expr: box expr,
+                        conversion,
+                    },
+                }
+            }
+
+            Expression::Apply(box function, args) => {
+                let function = self.fold(function)?;
+                let mut folded = vec![];
+
+                for arg in args {
+                    folded.push(self.fold(arg)?);
+                }
+
+                Expression::Apply(box function, folded)
+            }
+
+            Expression::Tuple(fields) => {
+                let mut folded = vec![];
+
+                for field in fields {
+                    folded.push(self.fold(field)?);
+                }
+
+                Expression::Tuple(folded)
+            }
+
+            Expression::Let {
+                local,
+                binding,
+                value,
+            } => Expression::Let {
+                local,
+                binding,
+                value: self.fold_boxed(value)?,
+            },
+
+            Expression::Destructure {
+                locals,
+                bindings,
+                value,
+            } => Expression::Destructure {
+                locals,
+                bindings,
+                value: self.fold_boxed(value)?,
+            },
+
+            Expression::Assign {
+                local,
+                binding,
+                value,
+            } => Expression::Assign {
+                local,
+                binding,
+                value: self.fold_boxed(value)?,
+            },
+
+            Expression::GlobalAssign {
+                global,
+                binding,
+                value,
+            } => Expression::GlobalAssign {
+                global,
+                binding,
+                value: self.fold_boxed(value)?,
+            },
+
+            Expression::If {
+                condition,
+                then_block,
+                else_block,
+            } => Expression::If {
+                condition: self.fold_boxed(condition)?,
+                then_block: self.fold_block(then_block)?,
+                else_block: match else_block {
+                    Some(block) => Some(self.fold_block(block)?),
+                    None => None,
+                },
+            },
+
+            Expression::While { condition, body } => Expression::While {
+                condition: self.fold_boxed(condition)?,
+                body: self.fold_block(body)?,
+            },
+
+            Expression::Loop { body } => Expression::Loop {
+                body: self.fold_block(body)?,
+            },
+
+            Expression::Break { target, value } => Expression::Break {
+                target,
+                value: self.fold_optional(value)?,
+            },
+
+            Expression::Return { ret, value, span } => Expression::Return {
+                ret,
+                value: self.fold_optional(value)?,
+                span,
+            },
+
+            Expression::Load {
+                address,
+                offset,
+                align,
+            } => Expression::Load {
+                address: self.fold_boxed(address)?,
+                offset,
+                align,
+            },
+
+            Expression::Store {
+                ty: stored,
+                address,
+                value,
+                offset,
+                align,
+            } => Expression::Store {
+                ty: stored,
+                address: self.fold_boxed(address)?,
+                value: self.fold_boxed(value)?,
+                offset,
+                align,
+            },
+
+            Expression::MemoryGrow { pages } => Expression::MemoryGrow {
+                pages: self.fold_boxed(pages)?,
+            },
+
+            Expression::Construct { ty: constructed, fields } => {
+                let mut folded = vec![];
+
+                for (field, value) in fields {
+                    folded.push((field, self.fold(value)?));
+                }
+
+                Expression::Construct {
+                    ty: constructed,
+                    fields: folded,
+                }
+            }
+
+            Expression::Field { base, name } => Expression::Field {
+                base: self.fold_boxed(base)?,
+                name,
+            },
+
+            Expression::AssignField {
+                base,
+                name,
+                operator,
+                value,
+            } => Expression::AssignField {
+                base: self.fold_boxed(base)?,
+                name,
+                operator,
+                value: self.fold_boxed(value)?,
+            },
+
+            Expression::StructAt { address } => Expression::StructAt {
+                address: self.fold_boxed(address)?,
+            },
+
+            Expression::Array { elements, span } => {
+                let mut folded = vec![];
+
+                for element in elements {
+                    folded.push(self.fold(element)?);
+                }
+
+                Expression::Array {
+                    elements: folded,
+                    span,
+                }
+            }
+
+            Expression::RepeatArray { value, len, span } => Expression::RepeatArray {
+                value: self.fold_boxed(value)?,
+                len,
+                span,
+            },
+
+            Expression::Index { base, index, span } => Expression::Index {
+                base: self.fold_boxed(base)?,
+                index: self.fold_boxed(index)?,
+                span,
+            },
+
+            Expression::AssignIndex {
+                base,
+                index,
+                span,
+                operator,
+                value,
+            } => Expression::AssignIndex {
+                base: self.fold_boxed(base)?,
+                index: self.fold_boxed(index)?,
+                span,
+                operator,
+                value: self.fold_boxed(value)?,
+            },
+
+            Expression::SliceOf { array, span } => Expression::SliceOf {
+                array: self.fold_boxed(array)?,
+                span,
+            },
+
+            Expression::Len {
+                sequence,
+                element,
+                span,
+            } => Expression::Len {
+                sequence: self.fold_boxed(sequence)?,
+                element,
+                span,
+            },
+
+            Expression::For {
+                local,
+                sequence,
+                element,
+                body,
+                span,
+            } => Expression::For {
+                local,
+                sequence: self.fold_boxed(sequence)?,
+                element,
+                body: self.fold_block(body)?,
+                span,
+            },
+
+            other @ Expression::Const(..)
+            | other @ Expression::VariableAccess(..)
+            | other @ Expression::GlobalAccess(..)
+            | other @ Expression::FunctionAccess { .. }
+            | other @ Expression::Continue
+            | other @ Expression::MemorySize => other,
+        };
+
+        Ok(item.annotate(ty))
+    }
+}
+
+fn constant(expr: &Annotated<Expression>) -> Option<ConstExpression> {
+    match expr.item {
+        Expression::Const(value) => Some(value),
+        _ => None,
+    }
+}
+
+// `None` for anything but a math type, including a generic function's type
+// parameters, whose operations depend on the instance
+fn math_type(ty: &InferType) -> Option<MathType> {
+    match ty {
+        InferType::Resolved(ty) => match ty.node.representation() {
+            Type::Math(math) => Some(*math),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+// Whether a comparison's operands have a type that doesn't depend on a
+// generic function's instance
+fn is_concrete(ty: &InferType) -> bool {
+    match ty {
+        InferType::Resolved(ty) => match ty.node.representation() {
+            Type::Math(..) | Type::Bool => true,
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+fn type_span(ty: &InferType) -> Span {
+    match ty {
+        InferType::Resolved(ty) => ty.span,
+        other => panic!("Unexpected {:?}; conversions are to a declared type", other),
+    }
+}
+
+// A const's value, as it appears where the const is used
+fn respan(value: ConstExpression, span: Span) -> ConstExpression {
+    match value {
+        ConstExpression::Integer(int, suffix) => ConstExpression::Integer(
+            Spanned {
+                node: int.node,
+                span,
+            },
+            suffix,
+        ),
+        ConstExpression::Float(float, suffix) => ConstExpression::Float(
+            Spanned {
+                node: float.node,
+                span,
+            },
+            suffix,
+        ),
+        ConstExpression::Bool(boolean) => ConstExpression::Bool(Spanned {
+            node: boolean.node,
+            span,
+        }),
+    }
+}
+
+fn integer(value: i128, span: Span) -> ConstExpression {
+    ConstExpression::Integer(Spanned { node: value, span }, None)
+}
+
+fn float(value: f64, span: Span) -> ConstExpression {
+    ConstExpression::Float(
+        Spanned {
+            node: F64::from_float(value),
+            span,
+        },
+        None,
+    )
+}
+
+fn boolean(value: bool, span: Span) -> ConstExpression {
+    ConstExpression::Bool(Spanned { node: value, span })
+}
+
+fn fits(value: i128, ty: MathType) -> bool {
+    let (min, max) = integer_range(ty);
+    min <= value && value <= max
+}
+
+fn is_signed(ty: MathType) -> bool {
+    let (min, _) = integer_range(ty);
+    min < 0
+}
+
+// The low bits of `value` that fit in `ty`, like a wasm wrap or extend
+fn wrap(value: i128, ty: MathType) -> i128 {
+    match ty {
+        MathType::I32 => i128::from(value as i32),
+        MathType::U32 => i128::from(value as u32),
+        MathType::I64 => i128::from(value as i64),
+        MathType::U64 => i128::from(value as u64),
+
+        MathType::F32 | MathType::F64 => panic!("{:?} isn't an integer type", ty),
+    }
+}
+
+fn binary(
+    operator: MathOperator,
+    lhs: ConstExpression,
+    rhs: ConstExpression,
+    ty: MathType,
+) -> Result<ConstExpression, ConstError> {
+    let span = lhs.span().to(rhs.span());
+
+    match ty {
+        MathType::F32 => {
+            let value = arithmetic(operator, lhs.to_f32(), rhs.to_f32());
+            Ok(float(f64::from(value), span))
+        }
+        MathType::F64 => Ok(float(arithmetic(operator, lhs.to_f64(), rhs.to_f64()), span)),
+        _ => integer_binary(operator, lhs.to_integer(), rhs.to_integer(), ty, span),
+    }
+}
+
+fn arithmetic<T>(operator: MathOperator, lhs: T, rhs: T) -> T
+where
+    T: Add<Output = T> + Sub<Output = T> + Mul<Output = T> + Div<Output = T>,
+{
+    match operator {
+        MathOperator::Add => lhs + rhs,
+        MathOperator::Sub => lhs - rhs,
+        MathOperator::Mul => lhs * rhs,
+        MathOperator::Div => lhs / rhs,
+
+        other => panic!("inference bug: {:?} on floats", other),
+    }
+}
+
+fn integer_binary(
+    operator: MathOperator,
+    lhs: i128,
+    rhs: i128,
+    ty: MathType,
+    span: Span,
+) -> Result<ConstExpression, ConstError> {
+    let overflow = || ConstError::Overflow {
+        expression: span,
+        ty,
+    };
+
+    let value = match operator {
+        MathOperator::Add => lhs.checked_add(rhs),
+        MathOperator::Sub => lhs.checked_sub(rhs),
+        MathOperator::Mul => lhs.checked_mul(rhs),
+
+        MathOperator::Div | MathOperator::Rem if rhs == 0 => {
+            return Err(ConstError::DivisionByZero { expression: span })
+        }
+        MathOperator::Div => lhs.checked_div(rhs),
+        MathOperator::Rem => lhs.checked_rem(rhs),
+
+        MathOperator::And => Some(lhs & rhs),
+        MathOperator::Or => Some(lhs | rhs),
+        MathOperator::Xor => Some(lhs ^ rhs),
+
+        // wasm only uses the low bits of the amount, which is never what
+        // a constant shift means
+        MathOperator::ShiftLeft | MathOperator::ShiftRight
+            if rhs < 0 || rhs >= i128::from(ty.size() * 8) =>
+        {
+            return Err(overflow())
+        }
+        // the bits shifted out are lost, as they are at runtime
+        MathOperator::ShiftLeft => Some(wrap(lhs << rhs as u32, ty)),
+        MathOperator::ShiftRight => Some(lhs >> rhs as u32),
+    };
+
+    match value {
+        Some(value) if fits(value, ty) => Ok(integer(value, span)),
+        _ => Err(overflow()),
+    }
+}
+
+fn unary(
+    operator: Spanned<UnaryOperator>,
+    value: ConstExpression,
+    ty: Option<MathType>,
+) -> Result<ConstExpression, ConstError> {
+    let span = operator.span.to(value.span());
+
+    match (operator.node, ty) {
+        (UnaryOperator::Not, _) => Ok(boolean(!value.to_bool(), span)),
+
+        (UnaryOperator::Negate, Some(MathType::F32))
+        | (UnaryOperator::Negate, Some(MathType::F64)) => Ok(float(-value.to_f64(), span)),
+        (UnaryOperator::Negate, Some(ty)) => {
+            let negated = -value.to_integer();
+
+            if fits(negated, ty) {
+                Ok(integer(negated, span))
+            } else {
+                Err(ConstError::Overflow {
+                    expression: span,
+                    ty,
+                })
+            }
+        }
+
+        (UnaryOperator::BitNot, Some(ty)) => {
+            let int = value.to_integer();
+
+            if is_signed(ty) {
+                Ok(integer(!int, span))
+            } else {
+                let (_, max) = integer_range(ty);
+                Ok(integer(max - int, span))
+            }
+        }
+
+        (operator, None) => panic!("{:?} on a constant that isn't a number", operator),
+    }
+}
+
+fn convert(
+    value: ConstExpression,
+    from: MathType,
+    to: MathType,
+    conversion: Conversion,
+    span: Span,
+) -> Result<ConstExpression, ConstError> {
+    let from_float = from == MathType::F32 || from == MathType::F64;
+
+    match (from_float, to) {
+        (_, MathType::F32) => Ok(float(f64::from(value.to_f32()), span)),
+        (_, MathType::F64) => Ok(float(value.to_f64(), span)),
+
+        (false, to) => Ok(integer(wrap(value.to_integer(), to), span)),
+
+        // wasm traps when the float isn't a number, or its integer part
+        // doesn't fit, unless the conversion saturates
+        (true, to) => {
+            let truncated = value.to_f64().trunc();
+            let (min, max) = integer_range(to);
+
+            let saturated = if truncated.is_nan() {
+                0
+            } else if truncated < min as f64 {
+                min
+            } else if truncated >= (max + 1) as f64 {
+                max
+            } else {
+                return Ok(integer(truncated as i128, span));
+            };
+
+            match conversion {
+                Conversion::Trapping => Err(ConstError::Overflow {
+                    expression: span,
+                    ty: to,
+                }),
+                Conversion::Saturating => Ok(integer(saturated, span)),
+            }
+        }
+    }
+}
+
+fn compare(
+    operator: CompareOperator,
+    lhs: ConstExpression,
+    rhs: ConstExpression,
+) -> ConstExpression {
+    let span = lhs.span().to(rhs.span());
+
+    let result = match (lhs.value(), rhs.value()) {
+        (ConstValue::Integer(l), ConstValue::Integer(r)) => holds(operator, l, r),
+        (ConstValue::Bool(l), ConstValue::Bool(r)) => holds(operator, l, r),
+        _ => holds(operator, lhs.to_f64(), rhs.to_f64()),
+    };
+
+    boolean(result, span)
+}
+
+fn holds<T: PartialOrd>(operator: CompareOperator, lhs: T, rhs: T) -> bool {
+    match operator {
+        CompareOperator::Equal => lhs == rhs,
+        CompareOperator::NotEqual => lhs != rhs,
+        CompareOperator::Less => lhs < rhs,
+        CompareOperator::LessEqual => lhs <= rhs,
+        CompareOperator::Greater => lhs > rhs,
+        CompareOperator::GreaterEqual => lhs >= rhs,
+    }
+}
+
+fn logical(
+    operator: LogicalOperator,
+    lhs: ConstExpression,
+    rhs: ConstExpression,
+) -> ConstExpression {
+    let span = lhs.span().to(rhs.span());
+
+    let result = match operator {
+        LogicalOperator::And => lhs.to_bool() && rhs.to_bool(),
+        LogicalOperator::Or => lhs.to_bool() || rhs.to_bool(),
+    };
+
+    boolean(result, span)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{binary, convert};
+    use crate::ir::ast::ConstExpression;
+    use crate::ir::{Span, SpannedItem};
+    use crate::{ConstError, Conversion, MathOperator, MathType};
+
+    fn int(value: i128) -> ConstExpression {
+        ConstExpression::Integer(value.synthetic("test"), None)
+    }
+
+    #[test]
+    fn folds_in_range() {
+        let value = binary(MathOperator::ShiftLeft, int(1), int(20), MathType::U32).unwrap();
+        assert_eq!(value.to_integer(), 1 << 20);
+
+        let value = binary(MathOperator::Sub, int(0), int(1), MathType::I64).unwrap();
+        assert_eq!(value.to_integer(), -1);
+    }
+
+    #[test]
+    fn overflow() {
+        let max = i128::from(u32::max_value());
+
+        for (operator, lhs, rhs) in vec![
+            (MathOperator::Add, max, 1),
+            (MathOperator::Sub, 0, 1),
+            (MathOperator::Mul, 1 << 16, 1 << 16),
+            (MathOperator::ShiftLeft, 1, 32),
+        ] {
+            match binary(operator, int(lhs), int(rhs), MathType::U32) {
+                Err(ConstError::Overflow { ty, .. }) => assert_eq!(ty, MathType::U32),
+                other => panic!("{:?} {:?} {:?} gave {:?}", lhs, operator, rhs, other),
+            }
+        }
+
+        let min = int(i128::from(i32::min_value()));
+
+        match binary(MathOperator::Div, min, int(-1), MathType::I32) {
+            Err(ConstError::Overflow { .. }) => {}
+            other => panic!("i32::MIN / -1 gave {:?}", other),
+        }
+    }
+
+    #[test]
+    fn division_by_zero() {
+        for operator in vec![MathOperator::Div, MathOperator::Rem] {
+            match binary(operator, int(1), int(0), MathType::I32) {
+                Err(ConstError::DivisionByZero { .. }) => {}
+                other => panic!("1 {:?} 0 gave {:?}", operator, other),
+            }
+        }
+    }
+
+    #[test]
+    fn conversions_wrap_like_wasm() {
+        let span = Span::Synthetic("test");
+
+        let trapping = Conversion::Trapping;
+
+        let value = convert(int(-1), MathType::I32, MathType::U64, trapping, span).unwrap();
+        assert_eq!(value.to_integer(), i128::from(u64::max_value()));
+
+        let value = convert(int(1 << 32), MathType::I64, MathType::I32, trapping, span).unwrap();
+        assert_eq!(value.to_integer(), 0);
+    }
+}
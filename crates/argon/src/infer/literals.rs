@@ -0,0 +1,299 @@
+use crate::annotated::{self, Annotated};
+use crate::ir::ast::{ConstExpression, ConstValue};
+use crate::ir::Span;
+use crate::{CompileError, InferType, MathType, Type, TypeError};
+
+// Literals are only checked once inference has decided their type, so
+// `300` is fine as a `u32` and `-1` is an error as one.
+crate fn check_literals(module: &annotated::Module) -> Result<(), CompileError> {
+    for global in &module.globals {
+        check_expression(&global.value)?;
+    }
+
+    for constant in &module.consts {
+        check_expression(&constant.value)?;
+    }
+
+    for function in &module.funcs {
+        check_function(function)?;
+    }
+
+    Ok(())
+}
+
+// A generic function's literals are checked again for each instance, once
+// their type is known
+crate fn check_function(function: &annotated::Function) -> Result<(), CompileError> {
+    check_block(&function.body)
+}
+
+fn check_block(block: &Annotated<annotated::Block>) -> Result<(), CompileError> {
+    for expression in &block.item.expressions {
+        check_expression(expression)?;
+    }
+
+    Ok(())
+}
+
+fn check_expression(
+    Annotated { item, ty }: &Annotated<annotated::Expression>,
+) -> Result<(), CompileError> {
+    match item {
+        annotated::Expression::Const(constant) => check_const(constant, ty),
+
+        annotated::Expression::VariableAccess(..)
+        | annotated::Expression::GlobalAccess(..)
+        | annotated::Expression::ConstAccess { .. }
+        | annotated::Expression::FunctionAccess { .. }
+        | annotated::Expression::Continue
+        | annotated::Expression::MemorySize => Ok(()),
+
+        annotated::Expression::Apply(box function, args) => {
+            check_expression(function)?;
+
+            for arg in args {
+                check_expression(arg)?;
+            }
+
+            Ok(())
+        }
+
+        annotated::Expression::Tuple(fields) => {
+            for field in fields {
+                check_expression(field)?;
+            }
+
+            Ok(())
+        }
+
+        annotated::Expression::Array { elements, span } => {
+            check_elements(ty, *span)?;
+
+            for element in elements {
+                check_expression(element)?;
+            }
+
+            Ok(())
+        }
+
+        annotated::Expression::RepeatArray {
+            box value, span, ..
+        } => {
+            check_elements(ty, *span)?;
+            check_expression(value)
+        }
+
+        annotated::Expression::Construct { fields, .. } => {
+            for (_, value) in fields {
+                check_expression(value)?;
+            }
+
+            Ok(())
+        }
+
+        annotated::Expression::Binary {
+            box lhs, box rhs, ..
+        }
+        | annotated::Expression::Compare {
+            box lhs, box rhs, ..
+        }
+        | annotated::Expression::Logical {
+            box lhs, box rhs, ..
+        }
+        | annotated::Expression::Store {
+            address: box lhs,
+            value: box rhs,
+            ..
+        }
+        | annotated::Expression::AssignField {
+            base: box lhs,
+            value: box rhs,
+            ..
+        }
+        | annotated::Expression::Index {
+            base: box lhs,
+            index: box rhs,
+            ..
+        } => {
+            check_expression(lhs)?;
+            check_expression(rhs)
+        }
+
+        annotated::Expression::AssignIndex {
+            box base,
+            box index,
+            box value,
+            ..
+        } => {
+            check_expression(base)?;
+            check_expression(index)?;
+            check_expression(value)
+        }
+
+        annotated::Expression::Unary { box expr, .. }
+        | annotated::Expression::Convert { box expr, .. }
+        | annotated::Expression::Load {
+            address: box expr, ..
+        }
+        | annotated::Expression::MemoryGrow { pages: box expr }
+        | annotated::Expression::Field { base: box expr, .. }
+        | annotated::Expression::StructAt { address: box expr }
+        | annotated::Expression::SliceOf {
+            array: box expr, ..
+        }
+        | annotated::Expression::Len {
+            sequence: box expr, ..
+        } => check_expression(expr),
+
+        annotated::Expression::Let { box value, .. }
+        | annotated::Expression::Destructure { box value, .. }
+        | annotated::Expression::Assign { box value, .. }
+        | annotated::Expression::GlobalAssign { box value, .. } => check_expression(value),
+
+        annotated::Expression::If {
+            box condition,
+            then_block,
+            else_block,
+        } => {
+            check_expression(condition)?;
+            check_block(then_block)?;
+
+            match else_block {
+                Some(else_block) => check_block(else_block),
+                None => Ok(()),
+            }
+        }
+
+        annotated::Expression::While {
+            box condition,
+            body,
+        } => {
+            check_expression(condition)?;
+            check_block(body)
+        }
+
+        annotated::Expression::Loop { body } => check_block(body),
+
+        annotated::Expression::For {
+            box sequence, body, ..
+        } => {
+            check_expression(sequence)?;
+            check_block(body)
+        }
+
+        annotated::Expression::Break { value, .. }
+        | annotated::Expression::Return { value, .. } => match value {
+            Some(box value) => check_expression(value),
+            None => Ok(()),
+        },
+    }
+}
+
+// An array literal's elements are stored like a struct's fields, so they're
+// numbers, booleans or structs. Declared arrays are checked when names are
+// resolved, but a literal's element type is only known now.
+fn check_elements(ty: &InferType, span: Span) -> Result<(), CompileError> {
+    let element = match ty {
+        InferType::Resolved(ty) => ty.node.element().map(|element| element.representation()),
+        _ => None,
+    };
+
+    match element {
+        Some(Type::Math(..)) | Some(Type::Bool) | Some(Type::Struct(..)) => Ok(()),
+        _ => Err(TypeError::InvalidElementType {
+            array: span,
+            ty: ty.clone(),
+        }.into()),
+    }
+}
+
+fn check_const(constant: &ConstExpression, ty: &InferType) -> Result<(), CompileError> {
+    let math = match ty {
+        InferType::Resolved(ty) => match ty.node.representation() {
+            Type::Math(math) => *math,
+            _ => return Ok(()),
+        },
+        _ => return Ok(()),
+    };
+
+    if fits(constant.value(), math) {
+        Ok(())
+    } else {
+        Err(TypeError::LiteralOutOfRange {
+            literal: constant.span(),
+            ty: math,
+        }.into())
+    }
+}
+
+fn fits(value: ConstValue, ty: MathType) -> bool {
+    match value {
+        ConstValue::Integer(int) => {
+            let (min, max) = integer_range(ty);
+            min <= int && int <= max
+        }
+
+        ConstValue::Float(float) => match ty {
+            MathType::F32 => (float.to_float() as f32).is_finite(),
+            _ => true,
+        },
+
+        ConstValue::Bool(..) => true,
+    }
+}
+
+crate fn integer_range(ty: MathType) -> (i128, i128) {
+    match ty {
+        MathType::I32 => (i32::min_value().into(), i32::max_value().into()),
+        MathType::I64 => (i64::min_value().into(), i64::max_value().into()),
+        MathType::U32 => (0, u32::max_value().into()),
+        MathType::U64 => (0, u64::max_value().into()),
+
+        MathType::F32 | MathType::F64 => {
+            panic!("inference bug: an integer literal was given the type {:?}", ty)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::check_const;
+    use crate::ir::ast::ConstExpression;
+    use crate::ir::SpannedItem;
+    use crate::{InferType, Type};
+    use nan_preserving_float::F64;
+
+    fn integer(value: i128) -> ConstExpression {
+        ConstExpression::Integer(value.synthetic("test"), None)
+    }
+
+    fn fits(constant: ConstExpression, ty: Type) -> bool {
+        check_const(&constant, &InferType::Resolved(ty.synthetic("test"))).is_ok()
+    }
+
+    #[test]
+    fn checks_signed_ranges() {
+        assert!(fits(integer(2_147_483_647), Type::i32()));
+        assert!(fits(integer(-2_147_483_648), Type::i32()));
+        assert!(!fits(integer(2_147_483_648), Type::i32()));
+        assert!(fits(integer(2_147_483_648), Type::i64()));
+        assert!(!fits(integer(9_223_372_036_854_775_808), Type::i64()));
+    }
+
+    #[test]
+    fn checks_unsigned_ranges() {
+        assert!(fits(integer(4_294_967_295), Type::u32()));
+        assert!(!fits(integer(4_294_967_296), Type::u32()));
+        assert!(!fits(integer(-1), Type::u32()));
+        assert!(fits(integer(18_446_744_073_709_551_615), Type::u64()));
+        assert!(!fits(integer(-1), Type::u64()));
+    }
+
+    #[test]
+    fn checks_float_ranges() {
+        let big = ConstExpression::Float(F64::from_float(1e39).synthetic("test"), None);
+
+        assert!(fits(big, Type::f64()));
+        assert!(!fits(big, Type::f32()));
+    }
+}
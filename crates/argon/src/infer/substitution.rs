@@ -1,5 +1,6 @@
 use crate::annotated::{self, Annotated, TypeVar};
-use crate::ir::InferType;
+use crate::ir::{InferType, Spanned, SpannedItem};
+use crate::Type;
 use std::collections::BTreeMap;
 use std::fmt;
 
@@ -9,12 +10,23 @@ mod test_helpers;
 #[derive(Eq, PartialEq)]
 crate struct Substitution {
     solutions: BTreeMap<TypeVar, InferType>,
+    // the types a generic function's type parameters are replaced with
+    params: Vec<Type>,
 }
 
 impl Substitution {
     crate fn empty() -> Substitution {
         Substitution {
             solutions: BTreeMap::new(),
+            params: vec![],
+        }
+    }
+
+    // Turns an already inferred generic function into one of its instances
+    crate fn instantiate(params: Vec<Type>) -> Substitution {
+        Substitution {
+            solutions: BTreeMap::new(),
+            params,
         }
     }
 
@@ -29,15 +41,41 @@ impl Substitution {
             .map(|f| self.apply_function(f))
             .collect();
 
-        annotated::Module { funcs }
+        let globals = module
+            .globals
+            .into_iter()
+            .map(|global| annotated::Global {
+                value: self.apply_expr(global.value),
+                ..global
+            })
+            .collect();
+
+        let consts = module
+            .consts
+            .into_iter()
+            .map(|constant| annotated::Const {
+                value: self.apply_expr(constant.value),
+                ..constant
+            })
+            .collect();
+
+        annotated::Module {
+            funcs,
+            memory: module.memory,
+            globals,
+            consts,
+            structs: module.structs,
+        }
     }
 
     crate fn apply_function(
         &self,
         annotated::Function {
             name,
+            generics,
             params,
             symbols,
+            locals,
             ret,
             body,
             modifiers,
@@ -45,9 +83,11 @@ impl Substitution {
     ) -> annotated::Function {
         annotated::Function {
             name,
-            params,
+            generics,
+            params: params.into_iter().map(|ty| self.apply_param(ty)).collect(),
             symbols,
-            ret,
+            locals: locals.into_iter().map(|ty| self.apply_ty(ty)).collect(),
+            ret: self.apply_param(ret),
             body: self.apply_block(body),
             modifiers,
         }
@@ -61,7 +101,11 @@ impl Substitution {
         }
 
         Annotated {
-            item: annotated::Block { expressions: exprs },
+            item: annotated::Block {
+                expressions: exprs,
+                tail: block.item.tail,
+                span: block.item.span,
+            },
             ty: self.apply_ty(block.ty),
         }
     }
@@ -75,7 +119,19 @@ impl Substitution {
         match item {
             c @ annotated::Expression::Const(..) => c.annotate(ty),
             v @ annotated::Expression::VariableAccess(..) => v.annotate(ty),
-            annotated::Expression::Apply(box _expr, _params) => unimplemented!(),
+            g @ annotated::Expression::GlobalAccess(..) => g.annotate(ty),
+            c @ annotated::Expression::ConstAccess { .. } => c.annotate(ty),
+            annotated::Expression::FunctionAccess {
+                function,
+                type_args,
+            } => annotated::Expression::FunctionAccess {
+                function,
+                type_args: type_args.into_iter().map(|ty| self.apply_ty(ty)).collect(),
+            }.annotate(ty),
+            annotated::Expression::Apply(box function, args) => annotated::Expression::Apply(
+                box self.apply_expr(function),
+                args.into_iter().map(|arg| self.apply_expr(arg)).collect(),
+            ).annotate(ty),
             annotated::Expression::Binary {
                 operator,
                 box lhs,
@@ -85,13 +141,263 @@ impl Substitution {
                 lhs: box self.apply_expr(lhs),
                 rhs: box self.apply_expr(rhs),
             }.annotate(ty),
+            annotated::Expression::Compare {
+                operator,
+                box lhs,
+                box rhs,
+            } => annotated::Expression::Compare {
+                operator,
+                lhs: box self.apply_expr(lhs),
+                rhs: box self.apply_expr(rhs),
+            }.annotate(ty),
+            annotated::Expression::Logical {
+                operator,
+                box lhs,
+                box rhs,
+            } => annotated::Expression::Logical {
+                operator,
+                lhs: box self.apply_expr(lhs),
+                rhs: box self.apply_expr(rhs),
+            }.annotate(ty),
+            annotated::Expression::Unary { operator, box expr } => annotated::Expression::Unary {
+                operator,
+                expr: box self.apply_expr(expr),
+            }.annotate(ty),
+            annotated::Expression::Convert {
+                box expr,
+                conversion,
+            } => annotated::Expression::Convert {
+                expr: box self.apply_expr(expr),
+                conversion,
+            }.annotate(ty),
+            annotated::Expression::Tuple(fields) => annotated::Expression::Tuple(
+                fields.into_iter().map(|field| self.apply_expr(field)).collect(),
+            ).annotate(ty),
+            annotated::Expression::Destructure {
+                locals,
+                bindings,
+                box value,
+            } => annotated::Expression::Destructure {
+                locals,
+                bindings: bindings.into_iter().map(|ty| self.apply_ty(ty)).collect(),
+                value: box self.apply_expr(value),
+            }.annotate(ty),
+            annotated::Expression::Let {
+                local,
+                binding,
+                box value,
+            } => annotated::Expression::Let {
+                local,
+                binding: self.apply_ty(binding),
+                value: box self.apply_expr(value),
+            }.annotate(ty),
+            annotated::Expression::Assign {
+                local,
+                binding,
+                box value,
+            } => annotated::Expression::Assign {
+                local,
+                binding: self.apply_ty(binding),
+                value: box self.apply_expr(value),
+            }.annotate(ty),
+            annotated::Expression::GlobalAssign {
+                global,
+                binding,
+                box value,
+            } => annotated::Expression::GlobalAssign {
+                global,
+                binding: self.apply_ty(binding),
+                value: box self.apply_expr(value),
+            }.annotate(ty),
+            annotated::Expression::If {
+                box condition,
+                then_block,
+                else_block,
+            } => annotated::Expression::If {
+                condition: box self.apply_expr(condition),
+                then_block: self.apply_block(then_block),
+                else_block: else_block.map(|block| self.apply_block(block)),
+            }.annotate(ty),
+            annotated::Expression::While {
+                box condition,
+                body,
+            } => annotated::Expression::While {
+                condition: box self.apply_expr(condition),
+                body: self.apply_block(body),
+            }.annotate(ty),
+            annotated::Expression::Loop { body } => annotated::Expression::Loop {
+                body: self.apply_block(body),
+            }.annotate(ty),
+            annotated::Expression::Break { target, value } => annotated::Expression::Break {
+                target: self.apply_ty(target),
+                value: value.map(|box value| box self.apply_expr(value)),
+            }.annotate(ty),
+            c @ annotated::Expression::Continue => c.annotate(ty),
+            annotated::Expression::Return { ret, value, span } => annotated::Expression::Return {
+                ret: self.apply_ty(ret),
+                value: value.map(|box value| box self.apply_expr(value)),
+                span,
+            }.annotate(ty),
+            annotated::Expression::Load {
+                box address,
+                offset,
+                align,
+            } => annotated::Expression::Load {
+                address: box self.apply_expr(address),
+                offset,
+                align,
+            }.annotate(ty),
+            annotated::Expression::Store {
+                ty: stored,
+                box address,
+                box value,
+                offset,
+                align,
+            } => annotated::Expression::Store {
+                ty: stored,
+                address: box self.apply_expr(address),
+                value: box self.apply_expr(value),
+                offset,
+                align,
+            }.annotate(ty),
+            m @ annotated::Expression::MemorySize => m.annotate(ty),
+            annotated::Expression::MemoryGrow { box pages } => annotated::Expression::MemoryGrow {
+                pages: box self.apply_expr(pages),
+            }.annotate(ty),
+            annotated::Expression::Construct {
+                ty: constructed,
+                fields,
+            } => annotated::Expression::Construct {
+                ty: constructed,
+                fields: fields
+                    .into_iter()
+                    .map(|(field, value)| (field, self.apply_expr(value)))
+                    .collect(),
+            }.annotate(ty),
+            annotated::Expression::Field { box base, name } => annotated::Expression::Field {
+                base: box self.apply_expr(base),
+                name,
+            }.annotate(ty),
+            annotated::Expression::AssignField {
+                box base,
+                name,
+                operator,
+                box value,
+            } => annotated::Expression::AssignField {
+                base: box self.apply_expr(base),
+                name,
+                operator,
+                value: box self.apply_expr(value),
+            }.annotate(ty),
+            annotated::Expression::StructAt { box address } => annotated::Expression::StructAt {
+                address: box self.apply_expr(address),
+            }.annotate(ty),
+            annotated::Expression::Array { elements, span } => annotated::Expression::Array {
+                elements: elements
+                    .into_iter()
+                    .map(|element| self.apply_expr(element))
+                    .collect(),
+                span,
+            }.annotate(ty),
+            annotated::Expression::RepeatArray {
+                box value,
+                len,
+                span,
+            } => annotated::Expression::RepeatArray {
+                value: box self.apply_expr(value),
+                len,
+                span,
+            }.annotate(ty),
+            annotated::Expression::Index {
+                box base,
+                box index,
+                span,
+            } => annotated::Expression::Index {
+                base: box self.apply_expr(base),
+                index: box self.apply_expr(index),
+                span,
+            }.annotate(ty),
+            annotated::Expression::AssignIndex {
+                box base,
+                box index,
+                span,
+                operator,
+                box value,
+            } => annotated::Expression::AssignIndex {
+                base: box self.apply_expr(base),
+                index: box self.apply_expr(index),
+                span,
+                operator,
+                value: box self.apply_expr(value),
+            }.annotate(ty),
+            annotated::Expression::SliceOf { box array, span } => annotated::Expression::SliceOf {
+                array: box self.apply_expr(array),
+                span,
+            }.annotate(ty),
+            annotated::Expression::Len {
+                box sequence,
+                element,
+                span,
+            } => annotated::Expression::Len {
+                sequence: box self.apply_expr(sequence),
+                element: self.apply_ty(element),
+                span,
+            }.annotate(ty),
+            annotated::Expression::For {
+                local,
+                box sequence,
+                element,
+                body,
+                span,
+            } => annotated::Expression::For {
+                local,
+                sequence: box self.apply_expr(sequence),
+                element: self.apply_ty(element),
+                body: self.apply_block(body),
+                span,
+            }.annotate(ty),
         }
     }
 
     crate fn apply_ty(&self, ty: InferType) -> InferType {
-        match ty {
+        let ty = match ty {
             InferType::Variable(var) => self.solutions[&var].clone(),
-            r @ InferType::Resolved(..) => r,
+            other => other,
+        };
+
+        match ty {
+            InferType::Resolved(ty) => InferType::Resolved(self.apply_param(ty)),
+            InferType::Function(params, ret) => InferType::Function(
+                params.into_iter().map(|ty| self.apply_param(ty)).collect(),
+                self.apply_param(ret),
+            ),
+
+            // a call of a generic function, whose signature was instantiated
+            // with variables
+            InferType::VariableFunction(params, box ret) => InferType::Function(
+                params
+                    .into_iter()
+                    .map(|ty| self.apply_ty(ty).into_type())
+                    .collect(),
+                self.apply_ty(ret).into_type(),
+            ),
+
+            InferType::Tuple(fields) => InferType::Resolved(
+                Type::Tuple(
+                    fields
+                        .into_iter()
+                        .map(|field| self.apply_ty(field).into_type().node)
+                        .collect(),
+                ).synthetic("tuple"),
+            ),
+
+            InferType::Array(box element, len) => InferType::Resolved(
+                Type::Array(box self.apply_ty(element).into_type().node, len).synthetic("array"),
+            ),
+
+            InferType::Slice(box element) => InferType::Resolved(
+                Type::Slice(box self.apply_ty(element).into_type().node).synthetic("slice"),
+            ),
 
             other => panic!(
                 "Unexpected {:?}; should have been eliminated in type inference",
@@ -99,6 +405,26 @@ impl Substitution {
             ),
         }
     }
+
+    fn apply_param(&self, ty: Spanned<Type>) -> Spanned<Type> {
+        self.substitute_params(ty.node.clone()).copy_span(&ty)
+    }
+
+    fn substitute_params(&self, ty: Type) -> Type {
+        match ty {
+            Type::Param(param) => match self.params.get(param.index as usize) {
+                Some(ty) => ty.clone(),
+                None => Type::Param(param),
+            },
+            Type::Tuple(fields) => Type::Tuple(
+                fields
+                    .into_iter()
+                    .map(|field| self.substitute_params(field))
+                    .collect(),
+            ),
+            other => other,
+        }
+    }
 }
 
 impl std::ops::Index<usize> for Substitution {
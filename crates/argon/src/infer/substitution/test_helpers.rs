@@ -10,6 +10,9 @@ impl Substitution {
             map.insert(TypeVar::new(*var), ty.clone());
         }
 
-        Substitution { solutions: map }
+        Substitution {
+            solutions: map,
+            params: vec![],
+        }
     }
 }
@@ -1,8 +1,8 @@
-use super::constraint::Constraints;
+use super::constraint::{Constraint, Constraints, Origin, Relation};
 use super::substitution::Substitution;
 use crate::ir::{InferType, TypeVar};
-use crate::pos::Spanned;
-use crate::CompileError;
+use crate::pos::{Spanned, SpannedItem};
+use crate::{CompileError, Type, TypeError};
 use ena::unify::InPlaceUnificationTable;
 use std::collections::BTreeSet;
 
@@ -27,8 +27,25 @@ impl Unify {
         {
             let mut unify = UnifyOne { table: &mut table };
 
-            for constraint in &constraints {
-                unify.constrain(&constraint.left, &constraint.right)?;
+            // fields and elements wait until their struct, array or slice
+            // is known, and declared return types are still checked last
+            let (projections, equalities): (Vec<&Constraint>, Vec<&Constraint>) =
+                constraints.into_iter().partition(|c| c.is_projection());
+
+            for constraint in equalities.iter().filter(|c| c.origin.is_none()) {
+                unify.constrain_one(constraint)?;
+            }
+
+            let projections = unify.project(projections)?;
+
+            for constraint in equalities.iter().filter(|c| c.origin.is_some()) {
+                unify.constrain_one(constraint)?;
+            }
+
+            let projections = unify.project(projections)?;
+
+            if let Some(constraint) = projections.first() {
+                return Err(unify.unresolved(constraint));
             }
         }
 
@@ -36,7 +53,7 @@ impl Unify {
 
         for key in &keys {
             let value = table.probe_value(*key);
-            substitution.set(*key, value)
+            substitution.set(*key, value.defaulted())
         }
 
         Ok(substitution)
@@ -51,127 +68,362 @@ impl UnifyOne<'unify> {
     fn constrain(&mut self, left: &InferType, right: &InferType) -> Result<(), CompileError> {
         trace!(target: "argon::unify::one", "+constraint {:?} {:?}", left, right);
 
+        let left = self.shallow(left);
+        let right = self.shallow(right);
+
         trace!(target: "argon::unify", "Constraining {:#?} + {:#?}", left, right);
 
-        match (left, right) {
-            (
-                InferType::Resolved(Spanned { node: left, .. }),
-                InferType::Resolved(Spanned { node: right, .. }),
-            ) if left == right => {}
+        match (&left, &right) {
+            (InferType::Variable(l), InferType::Variable(r)) => {
+                self.table.unify_var_var(*l, *r)?;
+            }
 
-            (InferType::Resolved(..), InferType::Resolved(..)) => {
-                return Err(CompileError::Unimplemented)
+            (InferType::Variable(var), other) | (other, InferType::Variable(var)) => {
+                self.bind(*var, other)?;
             }
 
-            (InferType::Constrained(c), InferType::Resolved(r)) => {
+            // `never` unifies with anything, so a branch that returns can
+            // stand in for a value of any type
+            (
+                InferType::Resolved(Spanned {
+                    node: Type::Never, ..
+                }),
+                _,
+            )
+            | (
+                _,
+                InferType::Resolved(Spanned {
+                    node: Type::Never, ..
+                }),
+            ) => {}
+
+            (InferType::Resolved(l), InferType::Resolved(r)) => {
+                if l.node != r.node {
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
+            }
+
+            (InferType::Constrained(c), InferType::Resolved(r))
+            | (InferType::Resolved(r), InferType::Constrained(c)) => {
                 if !c.unifies_ty(&r.node) {
-                    println!("Failed to unify {:?} + {:?}", c, r);
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
+            }
 
-                    return Err(CompileError::UnifyError(
-                        InferType::Constrained(c.clone()),
-                        InferType::Resolved(r.clone()),
-                    ));
-                };
+            (InferType::Constrained(l), InferType::Constrained(r)) => {
+                if l.meet(r).is_none() {
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
             }
 
-            (r @ InferType::Resolved(..), c @ InferType::Constrained(..)) => self.constrain(c, r)?,
+            // two known signatures have to be identical
+            (InferType::Function(lparams, lret), InferType::Function(rparams, rret)) => {
+                let same = lparams.len() == rparams.len()
+                    && lparams.iter().zip(rparams).all(|(l, r)| l.node == r.node)
+                    && lret.node == rret.node;
 
-            (InferType::Constrained(..), InferType::Constrained(..)) => {
-                // float literals and int literals unify, but eventually we need a better
-                // intersection system
+                if !same {
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
             }
 
-            (InferType::Variable(var), value @ InferType::Resolved(..)) => {
-                self.table.unify_var_value(*var, value.clone())?;
-                self.recurse_left(*var, value)?;
+            // calling a function with a known signature
+            (InferType::Function(params, ret), InferType::VariableFunction(args, result))
+            | (InferType::VariableFunction(args, result), InferType::Function(params, ret)) => {
+                if params.len() != args.len() {
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
+
+                for (param, arg) in params.iter().zip(args) {
+                    self.constrain(&InferType::Resolved(param.clone()), arg)?;
+                }
+
+                self.constrain(&InferType::Resolved(ret.clone()), result)?;
             }
 
-            (value @ InferType::Resolved(..), var @ InferType::Variable(..)) => {
-                self.constrain(var, value)?;
+            // tuples unify field by field
+            (InferType::Tuple(lfields), InferType::Tuple(rfields)) => {
+                if lfields.len() != rfields.len() {
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
+
+                for (l, r) in lfields.iter().zip(rfields) {
+                    self.constrain(l, r)?;
+                }
             }
 
-            (InferType::Variable(left), InferType::Variable(right)) => {
-                self.table.unify_var_var(*left, *right)?;
-                self.recurse_both(*left, *right)?;
+            (InferType::Tuple(fields), InferType::Resolved(resolved))
+            | (InferType::Resolved(resolved), InferType::Tuple(fields)) => {
+                let types = match &resolved.node {
+                    Type::Tuple(types) if types.len() == fields.len() => types,
+                    _ => return Err(CompileError::UnifyError(left.clone(), right.clone())),
+                };
+
+                for (field, ty) in fields.iter().zip(types) {
+                    self.constrain(field, &InferType::Resolved(ty.clone().copy_span(resolved)))?;
+                }
             }
 
-            (InferType::Variable(left), constrained @ InferType::Constrained(..)) => {
-                self.recurse_left(*left, constrained)?;
+            // arrays unify element by element, and have to be the same
+            // length
+            (InferType::Array(lelement, llen), InferType::Array(relement, rlen)) => {
+                if llen != rlen {
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
+
+                self.constrain(lelement, relement)?;
+            }
+
+            (InferType::Array(element, len), InferType::Resolved(resolved))
+            | (InferType::Resolved(resolved), InferType::Array(element, len)) => {
+                let ty = match &resolved.node {
+                    Type::Array(ty, resolved_len) if resolved_len == len => ty,
+                    _ => return Err(CompileError::UnifyError(left.clone(), right.clone())),
+                };
+
+                let ty = InferType::Resolved((**ty).clone().copy_span(resolved));
+                self.constrain(element, &ty)?;
             }
 
-            (constrained @ InferType::Constrained(..), var @ InferType::Variable(..)) => {
-                self.constrain(var, constrained)?;
+            (InferType::Slice(lelement), InferType::Slice(relement)) => {
+                self.constrain(lelement, relement)?;
+            }
+
+            (InferType::Slice(element), InferType::Resolved(resolved))
+            | (InferType::Resolved(resolved), InferType::Slice(element)) => {
+                let ty = match &resolved.node {
+                    Type::Slice(ty) => ty,
+                    _ => return Err(CompileError::UnifyError(left.clone(), right.clone())),
+                };
+
+                let ty = InferType::Resolved((**ty).clone().copy_span(resolved));
+                self.constrain(element, &ty)?;
             }
 
             (
                 InferType::VariableFunction(lparams, lret),
                 InferType::VariableFunction(rparams, rret),
             ) => {
-                for (left, right) in lparams.iter().zip(rparams) {
-                    self.constrain(left, right)?;
+                if lparams.len() != rparams.len() {
+                    return Err(CompileError::UnifyError(left.clone(), right.clone()));
+                }
+
+                for (l, r) in lparams.iter().zip(rparams) {
+                    self.constrain(l, r)?;
                 }
 
                 self.constrain(lret, rret)?;
             }
 
-            (InferType::Variable(left), f @ InferType::VariableFunction(..)) => {
-                self.recurse_left(*left, f)?;
+            // a function where a value is expected, or a value where a
+            // function is expected
+            _ => return Err(CompileError::UnifyError(left.clone(), right.clone())),
+        };
+
+        trace!(target: "argon::unify", "-constraint table={:#?}", self.table);
+
+        Ok(())
+    }
+
+    fn constrain_one(&mut self, constraint: &Constraint) -> Result<(), CompileError> {
+        match self.constrain(&constraint.left, &constraint.right) {
+            Ok(()) => Ok(()),
+            Err(error) => Err(self.explain(constraint, error)),
+        }
+    }
+
+    // Checks every projection whose struct, array or slice is known by
+    // now, until none of the rest can be checked. Returns the rest.
+    fn project(
+        &mut self,
+        mut pending: Vec<&'c Constraint>,
+    ) -> Result<Vec<&'c Constraint>, CompileError> {
+        loop {
+            let before = pending.len();
+            let mut waiting = vec![];
+
+            for constraint in pending {
+                if !self.project_one(constraint)? {
+                    waiting.push(constraint);
+                }
             }
 
-            (f @ InferType::VariableFunction(..), v @ InferType::Variable(..)) => {
-                self.constrain(v, f)?;
+            if waiting.len() == before {
+                return Ok(waiting);
             }
 
-            (InferType::Variable(left), f @ InferType::Function(..)) => {
-                self.recurse_left(*left, f)?;
+            pending = waiting;
+        }
+    }
+
+    // Whether the projection could be checked yet
+    fn project_one(&mut self, constraint: &Constraint) -> Result<bool, CompileError> {
+        let base = self.shallow(&constraint.left);
+
+        if let InferType::Variable(..) = base {
+            return Ok(false);
+        }
+
+        match constraint.relation {
+            Relation::Equal => unreachable!("equalities aren't projections"),
+
+            Relation::Field { name, span } => {
+                let field = match &base {
+                    InferType::Resolved(ty) => match &ty.node {
+                        Type::Struct(declared) => declared
+                            .fields
+                            .iter()
+                            .find(|field| field.name == name)
+                            .map(|field| InferType::Resolved(field.ty.clone().copy_span(ty))),
+                        _ => None,
+                    },
+                    _ => None,
+                };
+
+                let field = field.ok_or_else(|| TypeError::UnknownField { field: span })?;
+                self.constrain(&field, &constraint.right)?;
             }
 
-            (f @ InferType::Function(..), v @ InferType::Variable(..)) => {
-                self.constrain(v, f)?;
+            Relation::Element { span } => {
+                let element = element(&base)
+                    .ok_or_else(|| TypeError::NotASequence { expression: span })?;
+
+                self.constrain(&element, &constraint.right)?;
             }
 
-            (left, right) => unimplemented!("unifying constraints {:?} and {:?}", left, right),
-        };
+            Relation::SliceOf { span } => {
+                let element = match &base {
+                    InferType::Resolved(Spanned {
+                        node: Type::Array(..),
+                        ..
+                    })
+                    | InferType::Array(..) => element(&base).expect("arrays have elements"),
+                    other if element(other).is_some() => {
+                        return Err(TypeError::NotAnArray { expression: span }.into())
+                    }
+                    _ => return Err(TypeError::NotASequence { expression: span }.into()),
+                };
 
-        trace!(target: "argon::unify", "-constraint table={:#?}", self.table);
+                self.constrain(&InferType::Slice(box element), &constraint.right)?;
+            }
+        }
 
-        Ok(())
+        Ok(true)
     }
 
-    fn recurse_left(&mut self, var: TypeVar, ty: &InferType) -> Result<(), CompileError> {
-        let probed_left = self.table.probe_value(var);
+    // A projection whose struct, array or slice was never found out
+    fn unresolved(&mut self, constraint: &Constraint) -> CompileError {
+        // a number can't have fields or elements, whichever number it is
+        let number = match self.probe(&constraint.left) {
+            InferType::Constrained(..) => true,
+            _ => false,
+        };
 
-        if InferType::Variable(var) == probed_left {
-            return Ok(());
+        match constraint.relation {
+            Relation::Equal => unreachable!("equalities aren't projections"),
+            Relation::Field { span, .. } if number => TypeError::UnknownField { field: span },
+            Relation::Field { span, .. } => TypeError::FieldOfUnknownType { field: span },
+            Relation::Element { span } | Relation::SliceOf { span } if number => {
+                TypeError::NotASequence { expression: span }
+            }
+            Relation::Element { span } | Relation::SliceOf { span } => {
+                TypeError::SequenceOfUnknownType { expression: span }
+            }
+        }.into()
+    }
+
+    // Replaces a variable with what it's bound to, if that's a complete
+    // type or a function. Variables that are unbound, only constrained, or
+    // only seen as `never` so far stay variables, because they can still
+    // learn more about their type.
+    fn shallow(&mut self, ty: &InferType) -> InferType {
+        match ty {
+            InferType::Variable(var) => match self.table.probe_value(*var) {
+                InferType::Variable(..)
+                | InferType::Constrained(..)
+                | InferType::Resolved(Spanned {
+                    node: Type::Never, ..
+                }) => InferType::Variable(*var),
+
+                bound => bound,
+            },
+
+            other => other.clone(),
         }
+    }
 
-        self.constrain(&probed_left, ty)
+    // Binds a variable that can still learn more about its type; its
+    // current value and `ty` are merged by `unify_values`
+    fn bind(&mut self, var: TypeVar, ty: &InferType) -> Result<(), CompileError> {
+        if self.occurs(var, ty) {
+            return Err(TypeError::RecursiveType {
+                var: InferType::Variable(var),
+                ty: ty.clone(),
+            }.into());
+        }
+
+        self.table.unify_var_value(var, ty.clone())
     }
 
-    fn recurse_both(&mut self, left: TypeVar, right: TypeVar) -> Result<(), CompileError> {
-        let probed_left = self.table.probe_value(left);
-        let probed_right = self.table.probe_value(right);
+    // Whether `var` appears in `ty`, which would make binding them together
+    // produce an infinite type
+    fn occurs(&mut self, var: TypeVar, ty: &InferType) -> bool {
+        match ty {
+            InferType::Variable(other) => {
+                if self.table.unioned(var, *other) {
+                    return true;
+                }
+
+                match self.shallow(ty) {
+                    InferType::Variable(..) => false,
+                    bound => self.occurs(var, &bound),
+                }
+            }
+
+            InferType::VariableFunction(params, ret) => {
+                params.iter().any(|param| self.occurs(var, param)) || self.occurs(var, ret)
+            }
+
+            InferType::Tuple(fields) => fields.iter().any(|field| self.occurs(var, field)),
+
+            InferType::Array(element, _) | InferType::Slice(element) => self.occurs(var, element),
 
-        if InferType::Variable(left) == probed_left && InferType::Variable(right) == probed_right {
-            return Ok(());
+            InferType::Resolved(..) | InferType::Constrained(..) | InferType::Function(..) => {
+                false
+            }
         }
+    }
 
-        self.constrain(&probed_left, &probed_right)
+    // Turns a failure into an error about the code the constraint came from
+    fn explain(&mut self, constraint: &Constraint, error: CompileError) -> CompileError {
+        match constraint.origin {
+            None => error,
+
+            Some(Origin::Return { expression }) => TypeError::MismatchedReturn {
+                expected: constraint.left.clone().into_type(),
+                found: self.probe(&constraint.right),
+                expression,
+            }.into(),
+        }
     }
-}
 
-#[allow(unused)]
-fn occurs(type_var: TypeVar, ty: &InferType) -> bool {
-    match ty {
-        InferType::Function(params, ret) => {
-            params
-                .iter()
-                .any(|ty| occurs(type_var, &InferType::Resolved(ty.clone())))
-                || occurs(type_var, &InferType::Resolved(ret.clone()))
+    fn probe(&mut self, ty: &InferType) -> InferType {
+        match ty {
+            InferType::Variable(var) => self.table.probe_value(*var),
+            other => other.clone(),
         }
+    }
+}
 
-        InferType::Variable(type_var2) => type_var == *type_var2,
-        _ => false,
+// The type of the elements of an array or a slice
+fn element(sequence: &InferType) -> Option<InferType> {
+    match sequence {
+        InferType::Resolved(ty) => ty
+            .node
+            .element()
+            .map(|element| InferType::Resolved(element.clone().copy_span(ty))),
+        InferType::Array(element, _) | InferType::Slice(element) => Some((**element).clone()),
+        _ => None,
     }
 }
 
@@ -179,8 +431,12 @@ fn occurs(type_var: TypeVar, ty: &InferType) -> bool {
 mod tests {
     use super::Substitution;
     use super::UnifyTable;
+    use crate::annotated::{ConstrainedType, Signature};
     use crate::infer::constraint::{Constraint, Constraints};
     use crate::ir::InferType;
+    use crate::ir::{Span, SpannedItem};
+    use crate::lexer::IdentifierId;
+    use crate::{CompileError, MathType, Newtype, Type, TypeError, TypeParam};
 
     fn types() -> UnifyTable {
         UnifyTable::new()
@@ -220,6 +476,217 @@ mod tests {
         assert_eq!(substitution, Ok(Substitution::empty()));
     }
 
+    #[test]
+    fn rejects_mismatched_types() {
+        crate::init_logger();
+
+        let substitution =
+            types().unify(Constraints(Constraint(InferType::i32(), InferType::bool())));
+
+        assert_eq!(
+            substitution,
+            Err(CompileError::UnifyError(InferType::i32(), InferType::bool()))
+        );
+    }
+
+    #[test]
+    fn rejects_non_bool_operands() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t1 = types.fresh();
+
+        // def not(x: i32) -> bool { !x }
+
+        let constraints =
+            Constraint(t1.clone(), InferType::i32()) + Constraint(t1.clone(), InferType::bool());
+
+        assert!(types.unify(constraints).is_err());
+    }
+
+    #[test]
+    fn rejects_integer_literals_as_bools() {
+        crate::init_logger();
+
+        let substitution = types().unify(Constraints(Constraint(
+            InferType::integer(),
+            InferType::bool(),
+        )));
+
+        assert!(substitution.is_err());
+    }
+
+    #[test]
+    fn rejects_integer_only_operators_on_floats() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t1 = types.fresh();
+
+        // def rem(x: f64, y: f64) -> f64 { x % y }
+
+        let constraints =
+            Constraint(t1.clone(), InferType::f64()) + Constraint(t1.clone(), InferType::integer());
+
+        assert!(types.unify(constraints).is_err());
+    }
+
+    #[test]
+    fn rejects_integer_literals_as_floats() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+
+        // let x: f64 = 1;
+
+        let constraints =
+            Constraint(t0.clone(), InferType::integer()) + Constraint(t0.clone(), InferType::f64());
+
+        assert!(types.unify(constraints).is_err());
+        assert!(
+            UnifyTable::new()
+                .unify(Constraints(Constraint(InferType::integer(), InferType::float())))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_mixing_integer_and_float_literals() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+
+        // 1 + 1.5
+
+        let constraints = Constraint(t0.clone(), InferType::integer())
+            + Constraint(t0.clone(), InferType::float());
+
+        assert!(types.unify(constraints).is_err());
+    }
+
+    #[test]
+    fn defaults_unresolved_literals() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+        let t2 = types.fresh();
+
+        // 1 == 1; 1.5 == 1.5; -(1 + 1)
+
+        let constraints = Constraint(t0.clone(), InferType::integer())
+            + Constraint(t1.clone(), InferType::float())
+            + Constraint(t2.clone(), InferType::integer())
+            + Constraint(t2.clone(), InferType::signed());
+
+        let expected = Substitution::from(&[
+            (0, InferType::Resolved(Type::i32().synthetic("default"))),
+            (1, InferType::Resolved(Type::f64().synthetic("default"))),
+            (2, InferType::Resolved(Type::i32().synthetic("default"))),
+        ]);
+
+        assert_eq!(types.unify(constraints), Ok(expected));
+    }
+
+    #[test]
+    fn negated_integers_stay_signed() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+
+        // let x: u32 = -(1 + 1);
+
+        let constraints = Constraint(t0.clone(), InferType::integer())
+            + Constraint(t0.clone(), InferType::signed())
+            + Constraint(t0.clone(), InferType::Resolved(Type::u32().synthetic("test")));
+
+        assert!(types.unify(constraints).is_err());
+    }
+
+    #[test]
+    fn rejects_negating_unsigned_integers() {
+        crate::init_logger();
+
+        let substitution = types().unify(Constraints(Constraint(
+            InferType::Resolved(Type::u32().synthetic("test")),
+            InferType::signed(),
+        )));
+
+        assert!(substitution.is_err());
+    }
+
+    #[test]
+    fn unifies_never_with_anything() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t1 = types.fresh();
+
+        // if x < 0 { return 0 } else { x }
+
+        let constraints = Constraint(t1.clone(), InferType::never())
+            + Constraint(t1.clone(), InferType::i32())
+            + Constraint(InferType::never(), InferType::bool());
+
+        let substitution = types.unify(constraints);
+        let expected = Substitution::from(&[(0, InferType::i32())]);
+
+        assert_eq!(substitution, Ok(expected));
+    }
+
+    #[test]
+    fn remembers_literal_constraints() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+
+        // let x = 1.5; let y: i32 = x;
+
+        let constraints = Constraint(t0.clone(), InferType::float())
+            + Constraint(t0.clone(), t1.clone())
+            + Constraint(t1.clone(), InferType::i32());
+
+        assert!(types.unify(constraints).is_err());
+    }
+
+    #[test]
+    fn reports_mismatched_return_types() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+
+        // def f() -> i64 { 1.5 }
+
+        let constraints = Constraint(t0.clone(), InferType::float())
+            + Constraint(t0.clone(), t1.clone())
+            + Constraint::returns(InferType::i64(), t1.clone(), Span::Synthetic("body"));
+
+        let expected = TypeError::MismatchedReturn {
+            expected: Type::i64().synthetic("test"),
+            found: InferType::float(),
+            expression: Span::Synthetic("body"),
+        };
+
+        assert_eq!(types.unify(constraints), Err(CompileError::TypeError(expected)));
+    }
+
     #[test]
     fn unifies_variables_with_non_variables() {
         crate::init_logger();
@@ -274,6 +741,138 @@ mod tests {
         assert_eq!(substitution, Ok(expected));
     }
 
+    #[test]
+    fn unifies_call_with_function_signature() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t1 = types.fresh();
+        let t2 = types.fresh();
+
+        // def square(x: i64) -> i64; square(t1) : t2
+
+        let substitution = types.unify(Constraints(Constraint(
+            InferType::Function(vec![Type::i64().synthetic("test")], Type::i64().synthetic("test")),
+            InferType::variable_function(vec![t1.clone()], t2.clone()),
+        )));
+
+        let expected = Substitution::from(&[(0, InferType::i64()), (1, InferType::i64())]);
+
+        assert_eq!(substitution, Ok(expected));
+    }
+
+    #[test]
+    fn unifies_chains_of_variables() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+        let t2 = types.fresh();
+
+        // let a = b; let b = c; let c: i64 = 1;
+
+        let constraints = Constraint(t0.clone(), t1.clone())
+            + Constraint(t1.clone(), t2.clone())
+            + Constraint(t2.clone(), InferType::i64());
+
+        let expected = Substitution::from(&[
+            (0, InferType::i64()),
+            (1, InferType::i64()),
+            (2, InferType::i64()),
+        ]);
+
+        assert_eq!(types.unify(constraints), Ok(expected));
+    }
+
+    #[test]
+    fn rejects_recursive_types() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+
+        // f(f), where f : (T0) -> i32 and T0 is f's own type
+
+        let function = InferType::variable_function(vec![t0.clone()], InferType::i32());
+        let constraints = Constraints(Constraint(t0.clone(), function.clone()));
+
+        let expected = TypeError::RecursiveType {
+            var: t0.clone(),
+            ty: function,
+        };
+
+        assert_eq!(types.unify(constraints), Err(CompileError::TypeError(expected)));
+    }
+
+    #[test]
+    fn rejects_calling_a_value() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+
+        // let x = 1; x()
+
+        let constraints = Constraint(t0.clone(), InferType::i32())
+            + Constraint(t0.clone(), InferType::variable_function(vec![], InferType::i32()));
+
+        assert!(types.unify(constraints).is_err());
+    }
+
+    #[test]
+    fn rejects_mismatched_signatures() {
+        crate::init_logger();
+
+        let i64_to_i64 =
+            InferType::Function(vec![Type::i64().synthetic("test")], Type::i64().synthetic("test"));
+        let i64_to_bool =
+            InferType::Function(vec![Type::i64().synthetic("test")], Type::bool().synthetic("test"));
+
+        assert!(
+            types()
+                .unify(Constraints(Constraint(i64_to_i64.clone(), i64_to_i64.clone())))
+                .is_ok()
+        );
+        assert!(
+            types()
+                .unify(Constraints(Constraint(i64_to_i64, i64_to_bool)))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn rejects_variable_functions_with_different_arity() {
+        crate::init_logger();
+
+        let substitution = types().unify(Constraints(Constraint(
+            InferType::variable_function(vec![InferType::bool()], InferType::bool()),
+            InferType::variable_function(vec![], InferType::bool()),
+        )));
+
+        assert!(substitution.is_err());
+    }
+
+    #[test]
+    fn rejects_call_with_wrong_arity() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t1 = types.fresh();
+
+        let substitution = types.unify(Constraints(Constraint(
+            InferType::Function(vec![], Type::i32().synthetic("test")),
+            InferType::variable_function(vec![InferType::i32()], t1.clone()),
+        )));
+
+        assert!(substitution.is_err());
+    }
+
     #[test]
     fn unifies_multiple_variables_and_literals() {
         crate::init_logger();
@@ -298,4 +897,309 @@ mod tests {
         assert_eq!(substitution[2], InferType::f64());
         assert_eq!(substitution[3], InferType::f64());
     }
+
+    fn param(bound: Option<ConstrainedType>) -> TypeParam {
+        TypeParam { index: 0, bound }
+    }
+
+    fn param_type(bound: Option<ConstrainedType>) -> InferType {
+        InferType::Resolved(Type::Param(param(bound)).synthetic("test"))
+    }
+
+    #[test]
+    fn type_parameters_satisfy_constraints_their_bound_implies() {
+        crate::init_logger();
+
+        // def add<T: Num>(a: T, b: T) -> T { a + b }
+        let num = param_type(Some(ConstrainedType::Numeric));
+        assert!(
+            types()
+                .unify(Constraints(Constraint(num.clone(), InferType::numeric())))
+                .is_ok()
+        );
+
+        // def rem<T: Num>(a: T, b: T) -> T { a % b }
+        assert!(
+            types()
+                .unify(Constraints(Constraint(num.clone(), InferType::integer())))
+                .is_err()
+        );
+
+        // def rem<T: Int>(a: T, b: T) -> T { a % b }
+        let int = param_type(Some(ConstrainedType::Integer));
+        assert!(
+            types()
+                .unify(Constraints(Constraint(int, InferType::integer())))
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn unbounded_type_parameters_arent_numeric() {
+        crate::init_logger();
+
+        // def add<T>(a: T, b: T) -> T { a + b }
+        let substitution = types().unify(Constraints(Constraint(
+            param_type(None),
+            InferType::numeric(),
+        )));
+
+        assert!(substitution.is_err());
+    }
+
+    #[test]
+    fn type_parameters_are_not_concrete_types() {
+        crate::init_logger();
+
+        // def f<T: Num>(a: T) -> T { 1i32 }
+        let substitution = types().unify(Constraints(Constraint(
+            param_type(Some(ConstrainedType::Numeric)),
+            InferType::i32(),
+        )));
+
+        assert!(substitution.is_err());
+    }
+
+    fn add_signature() -> Signature {
+        let t = Type::Param(param(Some(ConstrainedType::Numeric)));
+
+        Signature {
+            generics: vec![param(Some(ConstrainedType::Numeric))],
+            params: vec![t.clone().synthetic("test"), t.clone().synthetic("test")],
+            ret: t.synthetic("test"),
+        }
+    }
+
+    #[test]
+    fn instantiates_generic_functions_at_each_call() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        // add(1, 2); add(1.5, x) where x: f64
+        let (first, first_args) = add_signature().instantiate(&mut types);
+        let (second, second_args) = add_signature().instantiate(&mut types);
+        let t2 = types.fresh();
+        let t3 = types.fresh();
+
+        let integers = vec![InferType::integer(), InferType::integer()];
+        let floats = vec![InferType::float(), InferType::f64()];
+
+        let constraints = Constraint(first, InferType::variable_function(integers, t2.clone()))
+            + Constraint(second, InferType::variable_function(floats, t3.clone()));
+
+        let substitution = types.unify(constraints).unwrap();
+
+        let math = |ty: &InferType| substitution.apply_ty(ty.clone()).as_math();
+
+        assert_eq!(math(&first_args[0]), MathType::I32);
+        assert_eq!(math(&t2), MathType::I32);
+        assert_eq!(math(&second_args[0]), MathType::F64);
+        assert_eq!(math(&t3), MathType::F64);
+    }
+
+    #[test]
+    fn rejects_instantiations_outside_the_bound() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        // add(true, false)
+        let (add, _) = add_signature().instantiate(&mut types);
+        let t1 = types.fresh();
+
+        let constraints = Constraints(Constraint(
+            add,
+            InferType::variable_function(vec![InferType::bool(), InferType::bool()], t1),
+        ));
+
+        assert!(types.unify(constraints).is_err());
+    }
+
+    fn user_id() -> InferType {
+        let newtype = Newtype {
+            index: 0,
+            repr: Type::u32(),
+        };
+
+        InferType::Resolved(Type::Newtype(box newtype).synthetic("test"))
+    }
+
+    #[test]
+    fn newtypes_are_distinct_from_their_representation() {
+        crate::init_logger();
+
+        // newtype UserId = u32; def f(id: UserId) -> u32 { id }
+        let u32 = InferType::Resolved(Type::u32().synthetic("test"));
+
+        assert!(types().unify(Constraints(Constraint(user_id(), u32))).is_err());
+        assert!(types().unify(Constraints(Constraint(user_id(), user_id()))).is_ok());
+    }
+
+    #[test]
+    fn newtypes_satisfy_the_constraints_of_their_representation() {
+        crate::init_logger();
+
+        // let id: UserId = 1; id % 2
+        let integer = Constraint(user_id(), InferType::integer());
+        assert!(types().unify(Constraints(integer)).is_ok());
+
+        // -id
+        let signed = Constraint(user_id(), InferType::signed());
+        assert!(types().unify(Constraints(signed)).is_err());
+    }
+
+    fn tuple(fields: Vec<Type>) -> InferType {
+        InferType::Resolved(Type::Tuple(fields).synthetic("test"))
+    }
+
+    #[test]
+    fn unifies_tuples_field_by_field() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+
+        // let (a, b) = f(), where f returns (i32, f64)
+        let constraints = Constraints(Constraint(
+            InferType::Tuple(vec![t0.clone(), t1.clone()]),
+            tuple(vec![Type::i32(), Type::f64()]),
+        ));
+
+        let expected = Substitution::from(&[(0, InferType::i32()), (1, InferType::f64())]);
+
+        assert_eq!(types.unify(constraints), Ok(expected));
+    }
+
+    #[test]
+    fn rejects_tuples_of_different_arity() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+        let pair = InferType::Tuple(vec![t0.clone(), t1.clone()]);
+
+        let triple = tuple(vec![Type::i32(), Type::i32(), Type::i32()]);
+        assert!(types.unify(Constraints(Constraint(pair.clone(), triple))).is_err());
+        assert!(types.unify(Constraints(Constraint(pair, InferType::i32()))).is_err());
+    }
+
+    fn array(element: Type, len: u32) -> InferType {
+        InferType::Resolved(Type::Array(box element, len).synthetic("test"))
+    }
+
+    #[test]
+    fn unifies_array_literals_with_declared_arrays() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+
+        // let a: [f64; 4] = [x; 4]
+        let constraints = Constraints(Constraint(
+            InferType::Array(box t0.clone(), 4),
+            array(Type::f64(), 4),
+        ));
+
+        let expected = Substitution::from(&[(0, InferType::f64())]);
+
+        assert_eq!(types.unify(constraints), Ok(expected));
+    }
+
+    #[test]
+    fn rejects_arrays_of_different_lengths() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let literal = InferType::Array(box t0.clone(), 4);
+
+        let longer = array(Type::f64(), 8);
+        assert!(types.unify(Constraints(Constraint(literal.clone(), longer))).is_err());
+
+        let slice = InferType::Resolved(Type::Slice(box Type::f64()).synthetic("test"));
+        assert!(types.unify(Constraints(Constraint(literal, slice))).is_err());
+    }
+
+    #[test]
+    fn finds_elements_once_the_array_is_known() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+
+        // let a = [1.0f64, 2.0]; a[0]
+        let constraints = Constraint::element(t0.clone(), Span::Synthetic("index"), t1.clone())
+            + Constraint(t0.clone(), InferType::Array(box InferType::f64(), 2));
+
+        assert_eq!(types.unify(constraints).unwrap()[1], InferType::f64());
+    }
+
+    #[test]
+    fn slices_share_their_array_elements() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+        let t2 = types.fresh();
+        let span = Span::Synthetic("slice");
+
+        // let a = [1, 2]; let s = &a; s[0] + 1u32
+        let constraints = Constraint(t0.clone(), InferType::integer())
+            + Constraint::slice_of(InferType::Array(box t0.clone(), 2), span, t1.clone())
+            + Constraint::element(t1.clone(), span, t2.clone())
+            + Constraint(t2.clone(), InferType::Resolved(Type::u32().synthetic("test")));
+
+        assert_eq!(
+            types.unify(constraints).unwrap()[0],
+            InferType::Resolved(Type::u32().synthetic("test"))
+        );
+    }
+
+    #[test]
+    fn reports_fields_of_unknown_types() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let t1 = types.fresh();
+        let field = Span::Synthetic("field");
+
+        let constraints = Constraints(Constraint::field(
+            t0.clone(),
+            IdentifierId::from_str("x"),
+            field,
+            t1.clone(),
+        ));
+
+        let expected = TypeError::FieldOfUnknownType { field };
+
+        assert_eq!(types.unify(constraints), Err(CompileError::TypeError(expected)));
+    }
+
+    #[test]
+    fn rejects_indexing_numbers() {
+        crate::init_logger();
+
+        let mut types = types();
+
+        let t0 = types.fresh();
+        let expression = Span::Synthetic("index");
+
+        let constraints = Constraints(Constraint::element(InferType::i32(), expression, t0));
+        let expected = TypeError::NotASequence { expression };
+
+        assert_eq!(types.unify(constraints), Err(CompileError::TypeError(expected)));
+    }
 }
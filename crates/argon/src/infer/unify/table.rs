@@ -1,8 +1,8 @@
 use super::Unify;
-use crate::annotated::{Annotated, TypeVar};
+use crate::annotated::{Annotated, ConstrainedType, TypeVar};
 use crate::infer::{Constraints, Substitution};
 use crate::pos::Spanned;
-use crate::{CompileError, InferType};
+use crate::{CompileError, InferType, Type};
 use ena::unify::{InPlaceUnificationTable, UnifyKey, UnifyValue};
 use std::collections::BTreeSet;
 
@@ -25,20 +25,38 @@ impl UnifyKey for TypeVar {
 impl UnifyValue for InferType {
     type Error = CompileError;
 
+    // Only variables that can still learn more about their type are merged
+    // here: unbound ones, constrained ones and ones only seen as `never`
     fn unify_values(a: &InferType, b: &InferType) -> Result<InferType, CompileError> {
         match (a, b) {
+            (InferType::Variable(..), other) | (other, InferType::Variable(..)) => Ok(other.clone()),
+
+            // a variable that was only seen as `never` so far takes on
+            // the first real type it meets
             (
-                InferType::Resolved(lhs @ Spanned { .. }),
-                InferType::Resolved(rhs @ Spanned { .. }),
-            ) if lhs.node == rhs.node =>
+                InferType::Resolved(Spanned {
+                    node: Type::Never, ..
+                }),
+                other,
+            )
+            | (
+                other,
+                InferType::Resolved(Spanned {
+                    node: Type::Never, ..
+                }),
+            ) => Ok(other.clone()),
+
+            (InferType::Constrained(constrained), InferType::Resolved(ty))
+            | (InferType::Resolved(ty), InferType::Constrained(constrained))
+                if constrained.unifies_ty(&ty.node) =>
             {
-                Ok(InferType::Resolved(lhs.clone()))
+                Ok(InferType::Resolved(ty.clone()))
             }
 
-            (InferType::Variable(..), other @ InferType::Resolved(..)) => Ok(other.clone()),
-            (other @ InferType::Resolved(..), InferType::Variable(..)) => Ok(other.clone()),
-
-            (InferType::Variable(..), other @ InferType::Variable(..)) => Ok(other.clone()),
+            (InferType::Constrained(left), InferType::Constrained(right)) => match left.meet(right) {
+                Some(constrained) => Ok(InferType::Constrained(constrained)),
+                None => Err(CompileError::UnifyError(a.clone(), b.clone())),
+            },
 
             _ => Err(CompileError::UnifyError(a.clone(), b.clone())),
         }
@@ -67,6 +85,16 @@ impl UnifyTable {
         ty
     }
 
+    // A variable that starts out constrained, like a type parameter
+    // with a bound
+    crate fn fresh_constrained(&mut self, constrained: ConstrainedType) -> InferType {
+        let next = self.table.len();
+        trace!(target: "argon::unify", "Generating <T{}>: {:?}", next, constrained);
+        let key = self.table.new_key(InferType::Constrained(constrained));
+        self.keys.insert(key);
+        InferType::Variable(key)
+    }
+
     crate fn annotate_fresh<T>(&mut self, item: T) -> Annotated<T> {
         let ty = self.fresh();
         Annotated { item, ty }
@@ -5,7 +5,9 @@ pub mod resolved;
 crate mod shared;
 
 crate use self::annotated::{InferType, TypeVar};
+crate use self::shared::array_length;
 pub use self::pos::*;
 pub use self::shared::{
-    CompileError, ConstExpression, FunctionModifiers, FunctionType, Type, TypeError,
+    CompileError, ConstError, ConstExpression, Field, FunctionModifiers, FunctionType, Newtype,
+    StructType, Type, TypeError, TypeParam,
 };
@@ -10,8 +10,8 @@ mod test_helpers;
 crate use self::block::Block;
 crate use self::expression::Expression;
 crate use self::function::Function;
-crate use self::module::Module;
-crate use self::types::{InferType, TypeEnv, TypeVar};
+crate use self::module::{Const, Global, Module};
+crate use self::types::{ConstrainedType, Declarations, InferType, Signature, TypeEnv, TypeVar};
 
 impl InferType {
     crate fn annotate<T>(self, item: T) -> Annotated<T> {
@@ -2,29 +2,35 @@ use super::types::InferType;
 use super::{Annotated, Expression, TypeEnv};
 use crate::infer::unify::UnifyTable;
 use crate::infer::{Constraint, Constraints};
-use crate::ir::pos::SpannedItem;
+use crate::ir::pos::{Span, SpannedItem};
 use crate::ir::resolved;
 use crate::Type;
 
 #[derive(Debug, Clone)]
 crate struct Block {
     crate expressions: Vec<Annotated<Expression>>,
+    crate tail: bool,
+    crate span: Span,
 }
 
 impl Block {
     crate fn from(
         block: resolved::Block,
         vars: &mut UnifyTable,
-        env: &TypeEnv,
+        env: &mut TypeEnv<'_>,
     ) -> Annotated<Block> {
         let expressions = block
             .expressions
             .into_iter()
-            .map(|e| e.annotate(vars, &env))
+            .map(|e| e.annotate(vars, env))
             .collect();
 
         Annotated {
-            item: Block { expressions },
+            item: Block {
+                expressions,
+                tail: block.tail,
+                span: block.span,
+            },
             ty: vars.fresh(),
         }
     }
@@ -33,8 +39,12 @@ impl Block {
 impl Block {
     crate fn last_ty(&self) -> InferType {
         match self.expressions.last() {
-            None => InferType::Resolved(Type::Void.synthetic("void")),
-            Some(e) => e.ty.clone(),
+            Some(e) if self.tail => e.ty.clone(),
+
+            // a block ending in `return x;` still never finishes
+            Some(e) if e.ty.is_never() => e.ty.clone(),
+
+            _ => InferType::Resolved(Type::Void.synthetic("void")),
         }
     }
 }
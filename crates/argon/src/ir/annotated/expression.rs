@@ -1,22 +1,175 @@
 use super::types::InferType;
-use super::Annotated;
+use super::{Annotated, Block};
 use crate::infer::{Constraint, Constraints};
 use crate::ir::ast;
-use crate::ir::Spanned;
-use crate::MathOperator;
+use crate::ir::pos::SpannedItem;
+use crate::ir::{Span, Spanned, Type};
+use crate::lexer::Token;
+use crate::{CompareOperator, Conversion, LogicalOperator, MathOperator, UnaryOperator};
 
 #[derive(Debug, Clone)]
 crate enum Expression {
     Const(ast::ConstExpression),
     VariableAccess(u32),
-
-    #[allow(unused)]
+    GlobalAccess(u32),
+    // the span is where the const is used
+    ConstAccess {
+        index: u32,
+        span: Span,
+    },
+    // the types a generic function is instantiated with; empty for
+    // other functions
+    FunctionAccess {
+        function: u32,
+        type_args: Vec<InferType>,
+    },
     Apply(Box<Annotated<Expression>>, Vec<Annotated<Expression>>),
     Binary {
         operator: Spanned<MathOperator>,
         lhs: Box<Annotated<Expression>>,
         rhs: Box<Annotated<Expression>>,
     },
+    Compare {
+        operator: Spanned<CompareOperator>,
+        lhs: Box<Annotated<Expression>>,
+        rhs: Box<Annotated<Expression>>,
+    },
+    Logical {
+        operator: Spanned<LogicalOperator>,
+        lhs: Box<Annotated<Expression>>,
+        rhs: Box<Annotated<Expression>>,
+    },
+    Unary {
+        operator: Spanned<UnaryOperator>,
+        expr: Box<Annotated<Expression>>,
+    },
+    // the type being converted to is the expression's type
+    Convert {
+        expr: Box<Annotated<Expression>>,
+        conversion: Conversion,
+    },
+    Tuple(Vec<Annotated<Expression>>),
+    Let {
+        local: u32,
+        binding: InferType,
+        value: Box<Annotated<Expression>>,
+    },
+    Destructure {
+        locals: Vec<u32>,
+        bindings: Vec<InferType>,
+        value: Box<Annotated<Expression>>,
+    },
+    Assign {
+        local: u32,
+        binding: InferType,
+        value: Box<Annotated<Expression>>,
+    },
+    GlobalAssign {
+        global: u32,
+        binding: InferType,
+        value: Box<Annotated<Expression>>,
+    },
+    If {
+        condition: Box<Annotated<Expression>>,
+        then_block: Annotated<Block>,
+        else_block: Option<Annotated<Block>>,
+    },
+    While {
+        condition: Box<Annotated<Expression>>,
+        body: Annotated<Block>,
+    },
+    Loop {
+        body: Annotated<Block>,
+    },
+    Break {
+        target: InferType,
+        value: Option<Box<Annotated<Expression>>>,
+    },
+    Continue,
+    Return {
+        ret: InferType,
+        value: Option<Box<Annotated<Expression>>>,
+        span: Span,
+    },
+    // the type being loaded is the expression's type
+    Load {
+        address: Box<Annotated<Expression>>,
+        offset: u32,
+        align: u32,
+    },
+    Store {
+        ty: Spanned<Type>,
+        address: Box<Annotated<Expression>>,
+        value: Box<Annotated<Expression>>,
+        offset: u32,
+        align: u32,
+    },
+    MemorySize,
+    MemoryGrow {
+        pages: Box<Annotated<Expression>>,
+    },
+    // `ty` is the struct being constructed
+    Construct {
+        ty: Spanned<Type>,
+        fields: Vec<(u32, Annotated<Expression>)>,
+    },
+    // `base` is a struct, and the field's type is the expression's type
+    Field {
+        base: Box<Annotated<Expression>>,
+        name: Token,
+    },
+    AssignField {
+        base: Box<Annotated<Expression>>,
+        name: Token,
+        operator: Option<Spanned<MathOperator>>,
+        value: Box<Annotated<Expression>>,
+    },
+    // the struct is the expression's type
+    StructAt {
+        address: Box<Annotated<Expression>>,
+    },
+    // the span is the opening bracket
+    Array {
+        elements: Vec<Annotated<Expression>>,
+        span: Span,
+    },
+    RepeatArray {
+        value: Box<Annotated<Expression>>,
+        len: u32,
+        span: Span,
+    },
+    // `base` is an array or a slice, and the element's type is the
+    // expression's type
+    Index {
+        base: Box<Annotated<Expression>>,
+        index: Box<Annotated<Expression>>,
+        span: Span,
+    },
+    AssignIndex {
+        base: Box<Annotated<Expression>>,
+        index: Box<Annotated<Expression>>,
+        span: Span,
+        operator: Option<Spanned<MathOperator>>,
+        value: Box<Annotated<Expression>>,
+    },
+    // the slice is the expression's type
+    SliceOf {
+        array: Box<Annotated<Expression>>,
+        span: Span,
+    },
+    Len {
+        sequence: Box<Annotated<Expression>>,
+        element: InferType,
+        span: Span,
+    },
+    // `element` is the local's type
+    For {
+        local: u32,
+        sequence: Box<Annotated<Expression>>,
+        element: InferType,
+        body: Annotated<Block>,
+        span: Span,
+    },
 }
 
 impl Expression {
@@ -50,25 +203,322 @@ impl Annotated<Expression> {
                     Constraints(Constraint::new(ty.clone(), InferType::bool()))
                 }
 
-                ast::ConstExpression::Integer(..) => {
+                // a suffix fixes the literal's type
+                ast::ConstExpression::Integer(int, Some(suffix)) => Constraints(Constraint::new(
+                    ty.clone(),
+                    InferType::Resolved(Type::Math(*suffix).copy_span(int)),
+                )),
+
+                ast::ConstExpression::Float(float, Some(suffix)) => Constraints(Constraint::new(
+                    ty.clone(),
+                    InferType::Resolved(Type::Math(*suffix).copy_span(float)),
+                )),
+
+                ast::ConstExpression::Integer(_, None) => {
                     Constraints(Constraint::new(ty.clone(), InferType::integer()))
                 }
 
-                ast::ConstExpression::Float(..) => {
+                ast::ConstExpression::Float(_, None) => {
                     Constraints(Constraint::new(ty.clone(), InferType::float()))
                 }
             },
             Expression::VariableAccess(_) => Constraints::empty(),
+            Expression::GlobalAccess(_) => Constraints::empty(),
+            Expression::ConstAccess { .. } => Constraints::empty(),
+            Expression::FunctionAccess { .. } => Constraints::empty(),
             Expression::Binary {
+                operator,
+                lhs: box lhs,
+                rhs: box rhs,
+            } => {
+                let constraints = lhs.constraints()
+                    + rhs.constraints()
+                    + Constraints(Constraint(ty.clone(), lhs.ty.clone()))
+                    + Constraints(Constraint(ty.clone(), rhs.ty.clone()));
+
+                if operator.node.is_integer_only() {
+                    constraints + Constraint(ty.clone(), InferType::integer())
+                } else {
+                    constraints + Constraint(ty.clone(), InferType::numeric())
+                }
+            }
+            Expression::Compare {
+                operator,
+                lhs: box lhs,
+                rhs: box rhs,
+            } => {
+                let constraints = lhs.constraints()
+                    + rhs.constraints()
+                    + Constraints(Constraint(lhs.ty.clone(), rhs.ty.clone()))
+                    + Constraints(Constraint(ty.clone(), InferType::bool()));
+
+                // any two values of the same type can be tested for
+                // equality, but only numbers are ordered
+                if operator.node.is_ordering() {
+                    constraints + Constraint(lhs.ty.clone(), InferType::numeric())
+                } else {
+                    constraints
+                }
+            }
+            Expression::Logical {
                 operator: _,
                 lhs: box lhs,
                 rhs: box rhs,
             } => {
                 lhs.constraints()
                     + rhs.constraints()
-                    + Constraints(Constraint(ty.clone(), lhs.ty.clone()))
-                    + Constraints(Constraint(ty.clone(), rhs.ty.clone()))
+                    + Constraints(Constraint(lhs.ty.clone(), InferType::bool()))
+                    + Constraints(Constraint(rhs.ty.clone(), InferType::bool()))
+                    + Constraints(Constraint(ty.clone(), InferType::bool()))
             }
+            Expression::Unary {
+                operator,
+                expr: box expr,
+            } => match operator.node {
+                UnaryOperator::Not => {
+                    expr.constraints()
+                        + Constraints(Constraint(expr.ty.clone(), InferType::bool()))
+                        + Constraints(Constraint(ty.clone(), InferType::bool()))
+                }
+                UnaryOperator::Negate => {
+                    expr.constraints()
+                        + Constraints(Constraint(ty.clone(), expr.ty.clone()))
+                        + Constraints(Constraint(ty.clone(), InferType::signed()))
+                }
+                UnaryOperator::BitNot => {
+                    expr.constraints()
+                        + Constraints(Constraint(ty.clone(), expr.ty.clone()))
+                        + Constraints(Constraint(ty.clone(), InferType::integer()))
+                }
+            },
+            // `as` can name any type, but only converts between numeric ones
+            Expression::Convert { expr: box expr, .. } => {
+                expr.constraints()
+                    + Constraint(expr.ty.clone(), InferType::numeric())
+                    + Constraint(ty.clone(), InferType::numeric())
+            }
+            // the tuple's type is made of its fields' types
+            Expression::Tuple(fields) => {
+                let mut constraints = Constraints::empty();
+
+                for field in fields {
+                    constraints += field.constraints();
+                }
+
+                constraints
+            }
+            Expression::Destructure {
+                bindings,
+                value: box value,
+                ..
+            } => {
+                value.constraints()
+                    + Constraint(InferType::Tuple(bindings.clone()), value.ty.clone())
+            }
+            Expression::Let {
+                binding,
+                value: box value,
+                ..
+            }
+            | Expression::Assign {
+                binding,
+                value: box value,
+                ..
+            }
+            | Expression::GlobalAssign {
+                binding,
+                value: box value,
+                ..
+            } => value.constraints() + Constraint(binding.clone(), value.ty.clone()),
+            Expression::If {
+                box condition,
+                then_block,
+                else_block,
+            } => {
+                let constraints = condition.constraints()
+                    + then_block.constraints()
+                    + Constraint(condition.ty.clone(), InferType::bool());
+
+                match else_block {
+                    // both branches have to produce the same type
+                    Some(else_block) => {
+                        constraints
+                            + else_block.constraints()
+                            + Constraint(ty.clone(), then_block.ty.clone())
+                            + Constraint(ty.clone(), else_block.ty.clone())
+                    }
+
+                    // an `if` without an `else` doesn't produce a value
+                    None => constraints + Constraint(ty.clone(), InferType::void()),
+                }
+            }
+            Expression::While {
+                box condition,
+                body,
+            } => {
+                condition.constraints()
+                    + body.constraints()
+                    + Constraint(condition.ty.clone(), InferType::bool())
+            }
+            Expression::Loop { body } => body.constraints(),
+            Expression::Break { target, value } => match value {
+                Some(box value) => value.constraints() + Constraint(target.clone(), value.ty.clone()),
+                None => Constraints(Constraint(target.clone(), InferType::void())),
+            },
+            Expression::Continue => Constraints::empty(),
+            Expression::Return { ret, value, span } => match value {
+                Some(box value) => {
+                    value.constraints()
+                        + Constraint::returns(ret.clone(), value.ty.clone(), *span)
+                }
+                None => Constraints(Constraint::returns(ret.clone(), InferType::void(), *span)),
+            },
+            Expression::Load {
+                address: box address,
+                ..
+            } => address.constraints() + Constraint(address.ty.clone(), InferType::address()),
+            Expression::Store {
+                ty,
+                address: box address,
+                value: box value,
+                ..
+            } => {
+                address.constraints()
+                    + value.constraints()
+                    + Constraint(address.ty.clone(), InferType::address())
+                    + Constraint(value.ty.clone(), InferType::Resolved(ty.clone()))
+            }
+            Expression::MemorySize => Constraints::empty(),
+            // memory is grown by a number of pages
+            Expression::MemoryGrow { pages: box pages } => {
+                pages.constraints() + Constraint(pages.ty.clone(), InferType::address())
+            }
+            Expression::Construct { ty, fields } => {
+                let mut constraints = Constraints::empty();
+
+                for (field, value) in fields {
+                    constraints += value.constraints()
+                        + Constraint(value.ty.clone(), field_type(ty, *field));
+                }
+
+                constraints
+            }
+            // the field is looked up once inference knows the struct
+            Expression::Field {
+                base: box base,
+                name,
+            } => {
+                base.constraints()
+                    + Constraint::field(base.ty.clone(), name.to_ident(), name.span, ty.clone())
+            }
+            // `p.x += 1` is `p.x = p.x + 1`, which needs a numeric field
+            Expression::AssignField {
+                base: box base,
+                name,
+                operator,
+                value: box value,
+            } => {
+                let field = value.ty.clone();
+                let constraints = base.constraints()
+                    + value.constraints()
+                    + Constraint::field(base.ty.clone(), name.to_ident(), name.span, field.clone());
+
+                match operator {
+                    None => constraints,
+                    Some(operator) if operator.node.is_integer_only() => {
+                        constraints + Constraint(field, InferType::integer())
+                    }
+                    Some(_) => constraints + Constraint(field, InferType::numeric()),
+                }
+            }
+            // a struct can be laid out at any address
+            Expression::StructAt { address: box address } => {
+                address.constraints() + Constraint(address.ty.clone(), InferType::address())
+            }
+            // the array's type is made of its first element's type, which
+            // the others share
+            Expression::Array { elements, .. } => {
+                let mut constraints = Constraints::empty();
+
+                for element in elements {
+                    constraints += element.constraints();
+                }
+
+                for element in &elements[1..] {
+                    constraints += Constraint(element.ty.clone(), elements[0].ty.clone());
+                }
+
+                constraints
+            }
+            Expression::RepeatArray { value: box value, .. } => value.constraints(),
+            // elements are indexed by a `u32`, like addresses
+            Expression::Index {
+                base: box base,
+                index: box index,
+                span,
+            } => {
+                base.constraints()
+                    + index.constraints()
+                    + Constraint::element(base.ty.clone(), *span, ty.clone())
+                    + Constraint(index.ty.clone(), InferType::address())
+            }
+            // `a[i] += 1` is `a[i] = a[i] + 1`, which needs numeric elements
+            Expression::AssignIndex {
+                base: box base,
+                index: box index,
+                span,
+                operator,
+                value: box value,
+            } => {
+                let element = value.ty.clone();
+                let constraints = base.constraints()
+                    + index.constraints()
+                    + value.constraints()
+                    + Constraint::element(base.ty.clone(), *span, element.clone())
+                    + Constraint(index.ty.clone(), InferType::address());
+
+                match operator {
+                    None => constraints,
+                    Some(operator) if operator.node.is_integer_only() => {
+                        constraints + Constraint(element, InferType::integer())
+                    }
+                    Some(_) => constraints + Constraint(element, InferType::numeric()),
+                }
+            }
+            Expression::SliceOf {
+                array: box array,
+                span,
+            } => array.constraints() + Constraint::slice_of(array.ty.clone(), *span, ty.clone()),
+            Expression::Len {
+                sequence: box sequence,
+                element,
+                span,
+            } => {
+                sequence.constraints()
+                    + Constraint::element(sequence.ty.clone(), *span, element.clone())
+            }
+            // the loop's local has the element type
+            Expression::For {
+                sequence: box sequence,
+                element,
+                body,
+                span,
+                ..
+            } => {
+                sequence.constraints()
+                    + body.constraints()
+                    + Constraint::element(sequence.ty.clone(), *span, element.clone())
+            }
+        }
+    }
+}
+
+// The type of a struct's `field`th field
+fn field_type(ty: &Spanned<Type>, field: u32) -> InferType {
+    match &ty.node {
+        Type::Struct(declared) => {
+            InferType::Resolved(declared.fields[field as usize].ty.clone().copy_span(ty))
         }
+        other => panic!("{:?} isn't a struct, so it has no fields", other),
     }
 }
@@ -1,15 +1,17 @@
-use super::{Annotated, Block, TypeEnv};
+use super::{Annotated, Block, Declarations, InferType, TypeEnv};
 use crate::infer::unify::UnifyTable;
-use crate::infer::Constraints;
+use crate::infer::{Constraint, Constraints};
 use crate::ir::{resolved, Spanned};
 use crate::lexer::Token;
-use crate::{FunctionModifiers, Type};
+use crate::{FunctionModifiers, Type, TypeParam};
 
 #[derive(Debug, Clone)]
 crate struct Function {
     crate name: Token,
+    crate generics: Vec<TypeParam>,
     crate params: Vec<Spanned<Type>>,
     crate symbols: Vec<Token>,
+    crate locals: Vec<InferType>,
     crate ret: Spanned<Type>,
     crate body: Annotated<Block>,
     crate modifiers: FunctionModifiers,
@@ -19,23 +21,36 @@ impl Function {
     crate fn from(
         resolved::Function {
             name,
+            generics,
             params,
             symbols,
+            locals,
             ret,
             body,
             modifiers,
         }: resolved::Function,
         vars: &mut UnifyTable,
+        declarations: &Declarations,
     ) -> Function {
+        let locals: Vec<InferType> = locals
+            .into_iter()
+            .map(|local| match local.ty {
+                Some(ty) => InferType::Resolved(ty),
+                None => vars.fresh(),
+            })
+            .collect();
+
         let body = {
-            let env = TypeEnv::params(&params);
-            Block::from(body, vars, &env)
+            let mut env = TypeEnv::new(&params, &locals, declarations, &ret);
+            Block::from(body, vars, &mut env)
         };
 
         Function {
             name,
+            generics,
             params,
             symbols,
+            locals,
             ret,
             body,
             modifiers,
@@ -43,6 +58,18 @@ impl Function {
     }
 
     crate fn constraints(&self) -> Constraints {
-        self.body.constraints()
+        let constraints = self.body.constraints();
+
+        // a void function discards whatever its body produces
+        if self.ret.node == Type::Void {
+            return constraints;
+        }
+
+        constraints
+            + Constraint::returns(
+                InferType::Resolved(self.ret.clone()),
+                self.body.ty.clone(),
+                self.body.span,
+            )
     }
 }
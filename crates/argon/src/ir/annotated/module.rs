@@ -1,26 +1,103 @@
-use super::Function;
+use super::{Annotated, Declarations, Expression, Function, InferType, TypeEnv};
 use crate::infer::unify::UnifyTable;
-use crate::infer::Constraints;
-use crate::ir::resolved;
+use crate::infer::{Constraint, Constraints};
+use crate::ir::resolved::{self, Memory};
+use crate::ir::Spanned;
+use crate::lexer::Token;
+use crate::{StructType, Type};
 
 #[derive(Debug, Clone)]
 crate struct Module {
     crate funcs: Vec<Function>,
+    crate memory: Option<Memory>,
+    crate globals: Vec<Global>,
+    crate consts: Vec<Const>,
+    crate structs: Vec<StructType>,
+}
+
+// The initial value is a constant expression, which is inferred like any
+// other
+#[derive(Debug, Clone)]
+crate struct Global {
+    crate name: Token,
+    crate ty: Spanned<Type>,
+    crate mutable: bool,
+    crate export: bool,
+    crate value: Annotated<Expression>,
+}
+
+#[derive(Debug, Clone)]
+crate struct Const {
+    crate name: Token,
+    crate ty: Spanned<Type>,
+    crate value: Annotated<Expression>,
 }
 
 impl Module {
-    crate fn from(resolved::Module { funcs }: resolved::Module, vars: &mut UnifyTable) -> Module {
+    crate fn from(
+        resolved::Module {
+            funcs,
+            memory,
+            globals,
+            consts,
+            structs,
+        }: resolved::Module,
+        vars: &mut UnifyTable,
+    ) -> Module {
+        let declarations = Declarations {
+            functions: funcs.iter().map(|func| func.signature()).collect(),
+            globals: globals.iter().map(|global| global.ty.clone()).collect(),
+            consts: consts.iter().map(|constant| constant.ty.clone()).collect(),
+        };
+
         let funcs = funcs
             .into_iter()
-            .map(|func| Function::from(func, vars))
+            .map(|func| Function::from(func, vars, &declarations))
+            .collect();
+
+        // the values of globals and consts are outside of any function
+        let mut env = TypeEnv::new(&[], &[], &declarations, &Type::void());
+
+        let globals = globals
+            .into_iter()
+            .map(|global| Global {
+                name: global.name,
+                ty: global.ty,
+                mutable: global.mutable,
+                export: global.export,
+                value: global.value.annotate(vars, &mut env),
+            })
             .collect();
 
-        Module { funcs }
+        let consts = consts
+            .into_iter()
+            .map(|constant| Const {
+                name: constant.name,
+                ty: constant.ty,
+                value: constant.value.annotate(vars, &mut env),
+            })
+            .collect();
+
+        Module {
+            funcs,
+            memory,
+            globals,
+            consts,
+            structs,
+        }
     }
 
     crate fn constraints(&self) -> Constraints {
         let mut constraints = Constraints::empty();
 
+        for global in &self.globals {
+            constraints += declared(&global.value, &global.ty);
+        }
+
+        for constant in &self.consts {
+            constraints += declared(&constant.value, &constant.ty);
+        }
+
         for function in &self.funcs {
             constraints += function.constraints();
         }
@@ -28,3 +105,8 @@ impl Module {
         constraints
     }
 }
+
+// A global or const's value has its declared type
+fn declared(value: &Annotated<Expression>, ty: &Spanned<Type>) -> Constraints {
+    value.constraints() + Constraint(value.ty.clone(), InferType::Resolved(ty.clone()))
+}
@@ -5,7 +5,10 @@ use crate::ir::pos::SpannedItem;
 
 impl Expression {
     crate fn integer(value: i32) -> Expression {
-        Expression::Const(ast::ConstExpression::Integer(value.synthetic("test")))
+        Expression::Const(ast::ConstExpression::Integer(
+            (value as i128).synthetic("test"),
+            None,
+        ))
     }
 
     crate fn bool(value: bool) -> Expression {
@@ -1,5 +1,6 @@
+use crate::infer::UnifyTable;
 use crate::ir::{Spanned, SpannedItem};
-use crate::{MathType, Type};
+use crate::{MathType, Type, TypeParam};
 use itertools::Itertools;
 use std::fmt;
 
@@ -21,41 +22,223 @@ impl fmt::Debug for TypeVar {
 }
 
 // The name resolution phase resolves names to offsets, which are looked
-// up in the TypeEnv
-crate struct TypeEnv<'input> {
-    // When we get locals, this will need to be changed
-    crate params: &'input [Spanned<Type>],
+// up in the TypeEnv. Parameters come first, followed by `let` locals.
+// Functions, globals and consts are looked up by their index in the module.
+crate struct TypeEnv<'module> {
+    crate locals: Vec<InferType>,
+    declarations: &'module Declarations,
+    ret: InferType,
+    // the loops surrounding the current expression, innermost last
+    loops: Vec<LoopEnv>,
 }
 
-impl TypeEnv<'input> {
-    crate fn params(params: &'input [Spanned<Type>]) -> TypeEnv<'input> {
-        TypeEnv { params }
+struct LoopEnv {
+    ty: InferType,
+    broken: bool,
+}
+
+impl TypeEnv<'module> {
+    crate fn new(
+        params: &[Spanned<Type>],
+        locals: &[InferType],
+        declarations: &'module Declarations,
+        ret: &Spanned<Type>,
+    ) -> TypeEnv<'module> {
+        let locals = params
+            .iter()
+            .map(|ty| InferType::Resolved(ty.clone()))
+            .chain(locals.iter().cloned())
+            .collect();
+
+        TypeEnv {
+            locals,
+            declarations,
+            ret: InferType::Resolved(ret.clone()),
+            loops: vec![],
+        }
+    }
+
+    crate fn get_local(&self, local: usize) -> InferType {
+        self.locals[local].clone()
+    }
+
+    crate fn get_function(&self, function: usize) -> &Signature {
+        &self.declarations.functions[function]
+    }
+
+    crate fn get_global(&self, global: usize) -> InferType {
+        InferType::Resolved(self.declarations.globals[global].clone())
     }
 
-    crate fn get_local(&self, local: usize) -> Spanned<Type> {
-        self.params[local].clone()
+    crate fn get_const(&self, index: usize) -> InferType {
+        InferType::Resolved(self.declarations.consts[index].clone())
+    }
+
+    // The declared return type of the current function, which a `return`
+    // value must have
+    crate fn return_type(&self) -> InferType {
+        self.ret.clone()
+    }
+
+    crate fn enter_loop(&mut self, ty: InferType) {
+        self.loops.push(LoopEnv { ty, broken: false });
+    }
+
+    // Returns whether the loop was ever broken out of
+    crate fn exit_loop(&mut self) -> bool {
+        self.loops.pop().expect("exit_loop without enter_loop").broken
+    }
+
+    // Returns the type of the innermost loop, which a `break` value
+    // must have
+    crate fn break_loop(&mut self) -> InferType {
+        let current = self
+            .loops
+            .last_mut()
+            .expect("name resolution rejects a break outside of a loop");
+
+        current.broken = true;
+        current.ty.clone()
     }
 }
 
+// The declared types of everything in a module that a function can refer to
+crate struct Declarations {
+    crate functions: Vec<Signature>,
+    crate globals: Vec<Spanned<Type>>,
+    crate consts: Vec<Spanned<Type>>,
+}
+
+// A function's declared type. A generic function's parameters and return
+// type can refer to its type parameters.
+#[derive(Debug, Clone)]
+crate struct Signature {
+    crate generics: Vec<TypeParam>,
+    crate params: Vec<Spanned<Type>>,
+    crate ret: Spanned<Type>,
+}
+
+impl Signature {
+    // The function's type at a single call. Each call of a generic function
+    // gets fresh variables for its type parameters, so different calls can
+    // instantiate it with different types. Also returns those variables.
+    crate fn instantiate(&self, vars: &mut UnifyTable) -> (InferType, Vec<InferType>) {
+        if self.generics.is_empty() {
+            return (
+                InferType::Function(self.params.clone(), self.ret.clone()),
+                vec![],
+            );
+        }
+
+        let type_args: Vec<InferType> = self
+            .generics
+            .iter()
+            .map(|param| match &param.bound {
+                Some(bound) => vars.fresh_constrained(bound.clone()),
+                None => vars.fresh(),
+            })
+            .collect();
+
+        let params = self
+            .params
+            .iter()
+            .map(|param| instantiate_ty(param, &type_args))
+            .collect();
+        let ret = instantiate_ty(&self.ret, &type_args);
+
+        (InferType::variable_function(params, ret), type_args)
+    }
+}
+
+fn instantiate_ty(ty: &Spanned<Type>, type_args: &[InferType]) -> InferType {
+    match &ty.node {
+        Type::Param(param) => type_args[param.index as usize].clone(),
+        Type::Tuple(fields) => InferType::Tuple(
+            fields
+                .iter()
+                .map(|field| instantiate_ty(&field.clone().copy_span(ty), type_args))
+                .collect(),
+        ),
+        _ => InferType::Resolved(ty.clone()),
+    }
+}
+
+// A numeric type that isn't known yet, only narrowed down to a set of
+// `MathType`s. Each variant stands for the set of types listed next to it,
+// and the sets are closed under intersection, so `meet` never has to
+// approximate.
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub enum ConstrainedType {
+    // i32, i64, u32, u64: integer literals and the operands of
+    // integer-only operators like `%` and `<<`
     Integer,
+    // f32, f64: float literals
     Float,
+    // i32, i64, f32, f64: the operand of unary `-`
+    Signed,
+    // i32, i64
+    SignedInteger,
+    // every numeric type: the operand of `as`
+    Numeric,
 }
 
 impl ConstrainedType {
+    // The constraint that satisfies both `self` and `other`, if any
+    crate fn meet(&self, other: &ConstrainedType) -> Option<ConstrainedType> {
+        use self::ConstrainedType::*;
+
+        match (self, other) {
+            (left, right) if left == right => Some(left.clone()),
+            (Numeric, other) | (other, Numeric) => Some(other.clone()),
+
+            (Integer, Float) | (Float, Integer) => None,
+            (SignedInteger, Float) | (Float, SignedInteger) => None,
+
+            (Integer, Signed) | (Signed, Integer) => Some(SignedInteger),
+            (Float, Signed) | (Signed, Float) => Some(Float),
+            (SignedInteger, _) | (_, SignedInteger) => Some(SignedInteger),
+
+            (left, right) => unreachable!("meet of {:?} and {:?}", left, right),
+        }
+    }
+
+    // Newtypes satisfy the same constraints as the type they wrap, so
+    // literals and arithmetic work on them
     crate fn unifies_ty(&self, other: &Type) -> bool {
-        let ty = match other {
+        let ty = match other.representation() {
             Type::Math(math) => math,
+            Type::Never => return true,
+
+            // a type parameter can be any type its bound allows, so it only
+            // satisfies constraints that allow all of them
+            Type::Param(TypeParam {
+                bound: Some(bound), ..
+            }) => return self.meet(bound).as_ref() == Some(bound),
+
             _ => return false,
         };
 
+        let is_float = *ty == MathType::F32 || *ty == MathType::F64;
+        let is_signed = *ty != MathType::U32 && *ty != MathType::U64;
+
         match self {
-            ConstrainedType::Integer => true,
-            ConstrainedType::Float => match ty {
-                MathType::F32 | MathType::F64 => true,
-                _ => false,
-            },
+            ConstrainedType::Integer => !is_float,
+            ConstrainedType::Float => is_float,
+            ConstrainedType::Signed => is_signed,
+            ConstrainedType::SignedInteger => is_signed && !is_float,
+            ConstrainedType::Numeric => true,
+        }
+    }
+
+    // The type a value gets if nothing else decides it, like the `1` in
+    // `1 == 1`
+    crate fn default_type(&self) -> MathType {
+        match self {
+            ConstrainedType::Float => MathType::F64,
+            ConstrainedType::Integer
+            | ConstrainedType::Signed
+            | ConstrainedType::SignedInteger
+            | ConstrainedType::Numeric => MathType::I32,
         }
     }
 }
@@ -66,6 +249,12 @@ pub enum InferType {
     Constrained(ConstrainedType),
     Function(Vec<Spanned<Type>>, Spanned<Type>),
     VariableFunction(Vec<InferType>, Box<InferType>),
+    // a tuple whose fields aren't all known yet
+    Tuple(Vec<InferType>),
+    // an array literal whose element type isn't known yet
+    Array(Box<InferType>, u32),
+    // a slice of such an array
+    Slice(Box<InferType>),
     Variable(TypeVar),
 }
 
@@ -86,6 +275,13 @@ impl fmt::Display for InferType {
                 params.iter().map(|p| format!("{:?}", p)).join(", "),
                 ret
             ),
+            InferType::Tuple(fields) => write!(
+                f,
+                "({})",
+                fields.iter().map(|field| format!("{}", field)).join(", ")
+            ),
+            InferType::Array(element, len) => write!(f, "[{}; {}]", element, len),
+            InferType::Slice(element) => write!(f, "&[{}]", element),
             InferType::Variable(var) => write!(f, "{:?}", var),
         }
     }
@@ -102,6 +298,25 @@ impl InferType {
         }
     }
 
+    // A value that's still only constrained takes the constraint's default
+    crate fn defaulted(self) -> InferType {
+        match self {
+            InferType::Constrained(constrained) => InferType::Resolved(
+                Type::Math(constrained.default_type()).synthetic("default"),
+            ),
+            other => other,
+        }
+    }
+
+    crate fn is_never(&self) -> bool {
+        match self {
+            InferType::Resolved(Spanned {
+                node: Type::Never, ..
+            }) => true,
+            _ => false,
+        }
+    }
+
     crate fn into_type(self) -> Spanned<Type> {
         match self {
             InferType::Resolved(ty) => ty,
@@ -111,10 +326,10 @@ impl InferType {
 
     crate fn as_math(&self) -> MathType {
         match self {
-            InferType::Resolved(Spanned {
-                node: Type::Math(ty),
-                ..
-            }) => *ty,
+            InferType::Resolved(ty) => match ty.node.representation() {
+                Type::Math(math) => *math,
+                _ => panic!("Cannot convert a {:?} into a MathType", self),
+            },
             other => panic!("Cannot convert a {:?} into a MathType", other),
         }
     }
@@ -131,9 +346,30 @@ impl InferType {
         InferType::Constrained(ConstrainedType::Float)
     }
 
+    crate fn signed() -> InferType {
+        InferType::Constrained(ConstrainedType::Signed)
+    }
+
+    crate fn numeric() -> InferType {
+        InferType::Constrained(ConstrainedType::Numeric)
+    }
+
     crate fn bool() -> InferType {
         InferType::Resolved(Type::bool().synthetic("test"))
     }
+
+    crate fn void() -> InferType {
+        InferType::Resolved(Type::void())
+    }
+
+    // addresses into linear memory are `u32`s
+    crate fn address() -> InferType {
+        InferType::Resolved(Type::u32().synthetic("address"))
+    }
+
+    crate fn never() -> InferType {
+        InferType::Resolved(Type::never())
+    }
 }
 
 #[cfg(test)]
@@ -1,4 +1,7 @@
-use crate::compile::math::MathOperator;
+use crate::compile::math::{
+    CompareOperator, Conversion, LogicalOperator, MathOperator, MathType, UnaryOperator,
+};
+use crate::ir::pos::{Span, SpannedItem};
 use crate::ir::{FunctionModifiers, Spanned, Type};
 use crate::lexer::{IdentifierId, Tok, Token};
 use nan_preserving_float::F64;
@@ -11,11 +14,16 @@ pub type Identifier = Token;
 pub struct Parameter {
     pub name: Identifier,
     pub ty: Spanned<Type>,
+    pub mutable: bool,
 }
 
 impl fmt::Debug for Parameter {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}: {:?}", self.name, self.ty)
+        if self.mutable {
+            write!(f, "mut {:?}: {:?}", self.name, self.ty)
+        } else {
+            write!(f, "{:?}: {:?}", self.name, self.ty)
+        }
     }
 }
 
@@ -55,6 +63,7 @@ impl Parameters {
 #[derive(PartialEq, Clone)]
 pub struct Function {
     pub name: Identifier,
+    pub generics: Vec<TypeParameter>,
     pub args: Parameters,
     pub ret: Spanned<Type>,
     pub body: Block,
@@ -68,6 +77,7 @@ impl Function {
 
         Function {
             name,
+            generics: vec![],
             args,
             ret,
             body,
@@ -80,6 +90,18 @@ impl Function {
         self.modifiers.export = true;
         self
     }
+
+    pub fn with_generics(mut self, generics: Vec<TypeParameter>) -> Function {
+        self.generics = generics;
+        self
+    }
+}
+
+// `T` or `T: Num` in `def add<T: Num>(a: T, b: T) -> T`
+#[derive(Debug, PartialEq, Clone, new)]
+pub struct TypeParameter {
+    pub name: Identifier,
+    pub bound: Option<Identifier>,
 }
 
 fn function_mappings(args: &Parameters) -> BTreeMap<IdentifierId, u32> {
@@ -96,6 +118,7 @@ impl fmt::Debug for Function {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         f.debug_map()
             .entry(&"name", &self.name)
+            .entry(&"generics", &self.generics)
             .entry(&"args", &self.args)
             .entry(&"return", &self.ret)
             .entry(&"body", &self.body)
@@ -107,11 +130,120 @@ impl fmt::Debug for Function {
 #[derive(PartialEq, Clone, Debug, new)]
 pub struct Module {
     pub funcs: Vec<Function>,
+    pub types: Vec<TypeDeclaration>,
+    // name resolution rejects more than one
+    pub memories: Vec<MemoryDeclaration>,
+    pub globals: Vec<GlobalDeclaration>,
+    pub consts: Vec<ConstDeclaration>,
+    pub structs: Vec<StructDeclaration>,
+}
+
+impl Module {
+    crate fn from_parser(items: Vec<Item>) -> Module {
+        let mut module = Module::new(vec![], vec![], vec![], vec![], vec![], vec![]);
+
+        for item in items {
+            match item {
+                Item::Function(function) => module.funcs.push(function),
+                Item::Type(declaration) => module.types.push(declaration),
+                Item::Memory(declaration) => module.memories.push(declaration),
+                Item::Global(declaration) => module.globals.push(declaration),
+                Item::Const(declaration) => module.consts.push(declaration),
+                Item::Struct(declaration) => module.structs.push(declaration),
+            }
+        }
+
+        module
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub enum Item {
+    Function(Function),
+    Type(TypeDeclaration),
+    Memory(MemoryDeclaration),
+    Global(GlobalDeclaration),
+    Const(ConstDeclaration),
+    Struct(StructDeclaration),
+}
+
+// `type Meters = f64;` gives a type another name, and `newtype UserId =
+// u32;` declares a new type that's represented like a `u32`
+#[derive(PartialEq, Clone, Debug, new)]
+pub struct TypeDeclaration {
+    pub name: Identifier,
+    pub ty: Spanned<Type>,
+    pub nominal: bool,
+}
+
+// `export memory 1..16;` declares the module's linear memory, with its
+// initial and maximum size in 64KiB pages
+#[derive(PartialEq, Clone, Debug, new)]
+pub struct MemoryDeclaration {
+    pub token: Token,
+    pub min: Token,
+    pub max: Option<Token>,
+    pub export: bool,
+}
+
+// `global mut counter: i64 = 0;` declares a variable every function in the
+// module can use
+#[derive(PartialEq, Clone, Debug, new)]
+pub struct GlobalDeclaration {
+    pub name: Identifier,
+    pub ty: Spanned<Type>,
+    pub mutable: bool,
+    pub value: Expression,
+    pub export: bool,
+}
+
+// `const LIMIT: u32 = 1 << 20;` is evaluated while compiling, and its value
+// is used wherever `LIMIT` is
+#[derive(PartialEq, Clone, Debug, new)]
+pub struct ConstDeclaration {
+    pub name: Identifier,
+    pub ty: Spanned<Type>,
+    pub value: Expression,
+}
+
+// `struct Point { x: f64, y: f64 }` declares a type whose values are
+// pointers to their fields in linear memory
+#[derive(PartialEq, Clone, Debug, new)]
+pub struct StructDeclaration {
+    pub name: Identifier,
+    pub fields: Vec<FieldDeclaration>,
+}
+
+#[derive(PartialEq, Clone, Debug, new)]
+pub struct FieldDeclaration {
+    pub name: Identifier,
+    pub ty: Spanned<Type>,
 }
 
 #[derive(PartialEq, Clone, new)]
 pub struct Block {
     pub expressions: Vec<Expression>,
+    // whether the last expression is the value of the block, rather than
+    // a statement ending in `;`
+    pub tail: bool,
+    pub span: Span,
+}
+
+impl Block {
+    // the parser collects the expressions in a block back to front
+    crate fn from_parser(
+        (mut expressions, tail): (Vec<Expression>, bool),
+        start: usize,
+        end: usize,
+    ) -> Block {
+        expressions.reverse();
+
+        Block {
+            expressions,
+            tail,
+            span: Span::from_locations(start, end),
+        }
+    }
 }
 
 impl fmt::Debug for Block {
@@ -120,70 +252,122 @@ impl fmt::Debug for Block {
     }
 }
 
+// Numeric literals remember their suffix (`10u64`, `1.5f32`), which fixes
+// their type. Integers are wide enough to hold any `u64` or `i64`, so range
+// checks happen once the literal's type is known.
 #[derive(PartialEq, Copy, Clone)]
 pub enum ConstExpression {
-    Integer(Spanned<i32>),
-    Float(Spanned<F64>),
+    Integer(Spanned<i128>, Option<MathType>),
+    Float(Spanned<F64>, Option<MathType>),
     Bool(Spanned<bool>),
 }
 
 #[derive(PartialEq, Copy, Clone)]
 crate enum ConstValue {
-    Integer(i32),
+    Integer(i128),
     Float(F64),
     Bool(bool),
 }
 
-fn is_float_int(float: F64) -> bool {
-    float.to_float().floor() == float.to_float()
-}
+impl ConstExpression {
+    crate fn integer(token: Token) -> ConstExpression {
+        match token.node {
+            Tok::Int(int, suffix) => {
+                ConstExpression::Integer((int as i128).copy_span(&token), suffix)
+            }
+            other => panic!("Expected Tok::Int, found {:?}", other),
+        }
+    }
 
-fn is_float_uint(float: F64) -> bool {
-    float.to_float().floor() == float.to_float() && float.to_float() >= 0.0
-}
+    crate fn float(token: Token) -> ConstExpression {
+        match token.node {
+            Tok::Float(float, suffix) => ConstExpression::Float(float.copy_span(&token), suffix),
+            other => panic!("Expected Tok::Float, found {:?}", other),
+        }
+    }
+
+    // The constant negated by the `-` in `op`, spanning both of them
+    fn negate(&self, op: &Token) -> Option<ConstExpression> {
+        let span = op.span.to(self.span());
+
+        match self {
+            ConstExpression::Integer(int, suffix) => Some(ConstExpression::Integer(
+                Spanned {
+                    node: -int.node,
+                    span,
+                },
+                *suffix,
+            )),
+            ConstExpression::Float(float, suffix) => Some(ConstExpression::Float(
+                Spanned {
+                    node: F64::from_float(-float.node.to_float()),
+                    span,
+                },
+                *suffix,
+            )),
+            ConstExpression::Bool(..) => None,
+        }
+    }
+
+    crate fn span(&self) -> Span {
+        match self {
+            ConstExpression::Integer(int, _) => int.span,
+            ConstExpression::Float(float, _) => float.span,
+            ConstExpression::Bool(boolean) => boolean.span,
+        }
+    }
 
-impl ConstExpression {
     crate fn value(&self) -> ConstValue {
         match self {
-            ConstExpression::Integer(Spanned { node: int, .. }) => ConstValue::Integer(*int),
-            ConstExpression::Float(Spanned { node: float, .. }) => ConstValue::Float(*float),
+            ConstExpression::Integer(Spanned { node: int, .. }, _) => ConstValue::Integer(*int),
+            ConstExpression::Float(Spanned { node: float, .. }, _) => ConstValue::Float(*float),
             ConstExpression::Bool(Spanned { node: boolean, .. }) => ConstValue::Bool(*boolean),
         }
     }
 
-    crate fn to_i32(&self) -> i32 {
+    crate fn to_bool(&self) -> bool {
         match self.value() {
-            ConstValue::Integer(int) => int,
-            ConstValue::Float(float) if is_float_int(float) => float.to_float() as i32,
+            ConstValue::Bool(boolean) => boolean,
 
-            _ => panic!("Cannot convert {:?} to an integer"),
+            _ => panic!("Cannot convert {:?} to a boolean", self),
         }
     }
 
-    crate fn to_u32(&self) -> u32 {
+    // The low 32 bits of an integer, which is how wasm represents both
+    // `i32` and `u32`
+    crate fn to_i32_bits(&self) -> i32 {
+        self.to_integer() as i32
+    }
+
+    // The low 64 bits of an integer, which is how wasm represents both
+    // `i64` and `u64`
+    crate fn to_i64_bits(&self) -> i64 {
+        self.to_integer() as i64
+    }
+
+    crate fn to_integer(&self) -> i128 {
         match self.value() {
-            ConstValue::Integer(int) if int >= 0 => int as u32,
-            ConstValue::Float(float) if is_float_uint(float) => float.to_float() as u32,
+            ConstValue::Integer(int) => int,
 
-            _ => panic!("Cannot convert {:?} to an unsigned integer"),
+            _ => panic!("Cannot convert {:?} to an integer", self),
         }
     }
 
     crate fn to_f32(&self) -> f32 {
         match self.value() {
-            ConstValue::Integer(int) if int >= 0 => int as f32,
+            ConstValue::Integer(int) => int as f32,
             ConstValue::Float(float) => float.to_float() as f32,
 
-            _ => panic!("Cannot convert {:?} to a float"),
+            _ => panic!("Cannot convert {:?} to a float", self),
         }
     }
 
     crate fn to_f64(&self) -> f64 {
         match self.value() {
-            ConstValue::Integer(int) if int >= 0 => int as f64,
+            ConstValue::Integer(int) => int as f64,
             ConstValue::Float(float) => float.to_float(),
 
-            _ => panic!("Cannot convert {:?} to a float"),
+            _ => panic!("Cannot convert {:?} to a float", self),
         }
     }
 }
@@ -191,8 +375,10 @@ impl ConstExpression {
 impl fmt::Debug for ConstExpression {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            ConstExpression::Integer(int) => write!(f, "{:?}", *int),
-            ConstExpression::Float(float) => write!(f, "{:?}", *float),
+            ConstExpression::Integer(int, None) => write!(f, "{:?}", *int),
+            ConstExpression::Float(float, None) => write!(f, "{:?}", *float),
+            ConstExpression::Integer(int, Some(suffix)) => write!(f, "{:?}{:?}", *int, suffix),
+            ConstExpression::Float(float, Some(suffix)) => write!(f, "{:?}{:?}", *float, suffix),
             ConstExpression::Bool(boolean) => write!(f, "{:?}", *boolean),
         }
     }
@@ -202,7 +388,49 @@ impl fmt::Debug for ConstExpression {
 pub enum Expression {
     Const(ConstExpression),
     VariableAccess(Identifier),
+    Call(Identifier, Vec<Expression>),
     Binary(MathOperator, Token, Box<BinaryExpression>),
+    Compare(CompareOperator, Token, Box<BinaryExpression>),
+    Logical(LogicalOperator, Token, Box<BinaryExpression>),
+    Unary(UnaryOperator, Token, Box<Expression>),
+    // `expr as ty` or `expr as saturating ty`
+    Convert(Token, Box<Expression>, Spanned<Type>, Conversion),
+    // `(a, b)`; the token is the opening parenthesis
+    Tuple(Token, Vec<Expression>),
+    Let(Box<LetExpression>),
+    // `let (a, b) = value;`
+    Destructure(Token, Box<DestructureExpression>),
+    Assign(Token, Box<AssignExpression>),
+    If(Token, Box<IfExpression>),
+    While(Token, Box<WhileExpression>),
+    Loop(Token, Box<Block>),
+    Break(Token, Option<Box<Expression>>),
+    Continue(Token),
+    Return(Token, Option<Box<Expression>>),
+    // `load<T>(address)`
+    Load(Token, Box<MemoryAccess>),
+    // `store<T>(address, value)`
+    Store(Token, Box<MemoryAccess>),
+    // `memory.size()` and `memory.grow(pages)`; the token is `memory`
+    Memory(Token, Identifier, Option<Box<Expression>>),
+    // `new Point { x: 1.0, y: 2.0 }`; the token is `new`
+    Construct(Token, Box<ConstructExpression>),
+    // `point.x`; the token is the `.`
+    Field(Token, Box<Expression>, Identifier),
+    // `point.x = 1.0`
+    AssignField(Token, Box<AssignFieldExpression>),
+    // `[1.0, 2.0]`; the token is the opening bracket
+    Array(Token, Vec<Expression>),
+    // `[0.0; 64]`, an array of `len` copies of a value
+    RepeatArray(Token, Box<Expression>, u32),
+    // `samples[i]`; the token is the opening bracket
+    Index(Token, Box<Expression>, Box<Expression>),
+    // `samples[i] = 0.0`
+    AssignIndex(Token, Box<AssignIndexExpression>),
+    // `&array`, a slice of a whole array
+    SliceOf(Token, Box<Expression>),
+    // `for sample in samples { .. }`
+    For(Token, Box<ForExpression>),
 }
 
 impl Expression {
@@ -212,12 +440,119 @@ impl Expression {
             Tok::Sub => MathOperator::Sub,
             Tok::Mul => MathOperator::Mul,
             Tok::Div => MathOperator::Div,
+            Tok::Rem => MathOperator::Rem,
+            Tok::BitAnd => MathOperator::And,
+            Tok::BitOr => MathOperator::Or,
+            Tok::BitXor => MathOperator::Xor,
+            Tok::ShiftLeft => MathOperator::ShiftLeft,
+            Tok::ShiftRight => MathOperator::ShiftRight,
 
             _ => unreachable!(),
         };
 
         Expression::Binary(operator, op, expr)
     }
+
+    pub fn compare(op: Token, expr: Box<BinaryExpression>) -> Expression {
+        let operator = match op.node {
+            Tok::EqualEqual => CompareOperator::Equal,
+            Tok::NotEqual => CompareOperator::NotEqual,
+            Tok::Less => CompareOperator::Less,
+            Tok::LessEqual => CompareOperator::LessEqual,
+            Tok::Greater => CompareOperator::Greater,
+            Tok::GreaterEqual => CompareOperator::GreaterEqual,
+
+            _ => unreachable!(),
+        };
+
+        Expression::Compare(operator, op, expr)
+    }
+
+    pub fn logical(op: Token, expr: Box<BinaryExpression>) -> Expression {
+        let operator = match op.node {
+            Tok::AndAnd => LogicalOperator::And,
+            Tok::OrOr => LogicalOperator::Or,
+
+            _ => unreachable!(),
+        };
+
+        Expression::Logical(operator, op, expr)
+    }
+
+    pub fn unary(op: Token, expr: Box<Expression>) -> Expression {
+        let operator = match op.node {
+            Tok::Not => UnaryOperator::Not,
+            Tok::Sub => UnaryOperator::Negate,
+            Tok::Tilde => UnaryOperator::BitNot,
+
+            _ => unreachable!(),
+        };
+
+        // fold negative literals into constants instead of `0 - n`
+        match (operator, expr) {
+            (UnaryOperator::Negate, box Expression::Const(constant)) => match constant.negate(&op) {
+                Some(negated) => Expression::Const(negated),
+                None => Expression::Unary(operator, op, box Expression::Const(constant)),
+            },
+            (operator, expr) => Expression::Unary(operator, op, expr),
+        }
+    }
+
+    crate fn tuple(tok: Token, first: Expression, rest: Vec<Expression>) -> Expression {
+        let mut fields = vec![first];
+        fields.extend(rest);
+
+        Expression::Tuple(tok, fields)
+    }
+
+    crate fn array(tok: Token, first: Expression, rest: Vec<Expression>) -> Expression {
+        let mut elements = vec![first];
+        elements.extend(rest);
+
+        Expression::Array(tok, elements)
+    }
+
+    pub fn assign(name: Identifier, op: Token, value: Expression) -> Expression {
+        let operator = assign_operator(op);
+
+        Expression::Assign(op, box AssignExpression::new(name, operator, value))
+    }
+
+    crate fn assign_field(
+        base: Expression,
+        field: Identifier,
+        op: Token,
+        value: Expression,
+    ) -> Expression {
+        let operator = assign_operator(op);
+
+        Expression::AssignField(op, box AssignFieldExpression::new(base, field, operator, value))
+    }
+
+    crate fn assign_index(
+        base: Expression,
+        index: Expression,
+        op: Token,
+        value: Expression,
+    ) -> Expression {
+        let operator = assign_operator(op);
+
+        Expression::AssignIndex(op, box AssignIndexExpression::new(base, index, operator, value))
+    }
+}
+
+// `=` assigns a value as it is, and `+=` and friends combine it with the
+// current one
+fn assign_operator(op: Token) -> Option<MathOperator> {
+    match op.node {
+        Tok::Equals => None,
+        Tok::AddAssign => Some(MathOperator::Add),
+        Tok::SubAssign => Some(MathOperator::Sub),
+        Tok::MulAssign => Some(MathOperator::Mul),
+        Tok::DivAssign => Some(MathOperator::Div),
+
+        _ => unreachable!(),
+    }
 }
 
 impl fmt::Debug for Expression {
@@ -225,9 +560,88 @@ impl fmt::Debug for Expression {
         let value: &dyn fmt::Debug = match self {
             Expression::Const(constant) => constant,
             Expression::VariableAccess(id) => id,
+            Expression::Call(name, args) => {
+                write!(f, "{:?}(", name)?;
+
+                for (i, arg) in args.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{:?}", arg)?;
+                }
+
+                return write!(f, ")");
+            }
             Expression::Binary(op, _tok, box BinaryExpression { lhs, rhs }) => {
                 return write!(f, "{:?} {:?} {:?}", lhs, op, rhs);
             }
+            Expression::Compare(op, _tok, box BinaryExpression { lhs, rhs }) => {
+                return write!(f, "{:?} {:?} {:?}", lhs, op, rhs);
+            }
+            Expression::Logical(op, _tok, box BinaryExpression { lhs, rhs }) => {
+                return write!(f, "{:?} {:?} {:?}", lhs, op, rhs);
+            }
+            Expression::Unary(op, _tok, expr) => return write!(f, "{:?}{:?}", op, expr),
+            Expression::Convert(_tok, expr, ty, Conversion::Trapping) => {
+                return write!(f, "{:?} as {:?}", expr, ty)
+            }
+            Expression::Convert(_tok, expr, ty, Conversion::Saturating) => {
+                return write!(f, "{:?} as saturating {:?}", expr, ty)
+            }
+            Expression::Tuple(_tok, fields) => {
+                write!(f, "(")?;
+
+                for (i, field) in fields.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{:?}", field)?;
+                }
+
+                return write!(f, ")");
+            }
+            Expression::Let(binding) => binding,
+            Expression::Destructure(_tok, destructure) => destructure,
+            Expression::Assign(_tok, assign) => assign,
+            Expression::If(_tok, if_expr) => if_expr,
+            Expression::While(_tok, while_expr) => while_expr,
+            Expression::Loop(_tok, body) => return write!(f, "loop {:?}", body),
+            Expression::Break(_tok, None) => return write!(f, "break"),
+            Expression::Break(_tok, Some(value)) => return write!(f, "break {:?}", value),
+            Expression::Continue(_tok) => return write!(f, "continue"),
+            Expression::Return(_tok, None) => return write!(f, "return"),
+            Expression::Return(_tok, Some(value)) => return write!(f, "return {:?}", value),
+            Expression::Load(_tok, access) => return write!(f, "load{:?}", access),
+            Expression::Store(_tok, access) => return write!(f, "store{:?}", access),
+            Expression::Memory(_tok, name, None) => return write!(f, "memory.{:?}()", name),
+            Expression::Memory(_tok, name, Some(arg)) => {
+                return write!(f, "memory.{:?}({:?})", name, arg);
+            }
+            Expression::Construct(_tok, construct) => construct,
+            Expression::Field(_tok, base, field) => return write!(f, "{:?}.{:?}", base, field),
+            Expression::AssignField(_tok, assign) => assign,
+            Expression::Array(_tok, elements) => {
+                write!(f, "[")?;
+
+                for (i, element) in elements.iter().enumerate() {
+                    if i != 0 {
+                        write!(f, ", ")?;
+                    }
+
+                    write!(f, "{:?}", element)?;
+                }
+
+                return write!(f, "]");
+            }
+            Expression::RepeatArray(_tok, value, len) => {
+                return write!(f, "[{:?}; {}]", value, len);
+            }
+            Expression::Index(_tok, base, index) => return write!(f, "{:?}[{:?}]", base, index),
+            Expression::AssignIndex(_tok, assign) => assign,
+            Expression::SliceOf(_tok, array) => return write!(f, "&{:?}", array),
+            Expression::For(_tok, for_expr) => for_expr,
         };
 
         write!(f, "{:?}", value)
@@ -245,3 +659,248 @@ impl fmt::Debug for BinaryExpression {
         write!(f, "{:?} + {:?}", self.lhs, self.rhs)
     }
 }
+
+#[derive(PartialEq, Clone, new)]
+pub struct LetExpression {
+    pub name: Identifier,
+    pub ty: Option<Spanned<Type>>,
+    pub mutable: bool,
+    pub value: Expression,
+}
+
+impl fmt::Debug for LetExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "let ")?;
+
+        if self.mutable {
+            write!(f, "mut ")?;
+        }
+
+        match &self.ty {
+            Some(ty) => write!(f, "{:?}: {:?} = {:?}", self.name, ty, self.value),
+            None => write!(f, "{:?} = {:?}", self.name, self.value),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct DestructureExpression {
+    pub bindings: Vec<Binding>,
+    pub value: Expression,
+}
+
+impl DestructureExpression {
+    crate fn from_parser(first: Binding, rest: Vec<Binding>, value: Expression) -> Self {
+        let mut bindings = vec![first];
+        bindings.extend(rest);
+
+        DestructureExpression { bindings, value }
+    }
+}
+
+impl fmt::Debug for DestructureExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "let (")?;
+
+        for (i, binding) in self.bindings.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+
+            if binding.mutable {
+                write!(f, "mut ")?;
+            }
+
+            write!(f, "{:?}", binding.name)?;
+        }
+
+        write!(f, ") = {:?}", self.value)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, new)]
+pub struct Binding {
+    pub name: Identifier,
+    pub mutable: bool,
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct AssignExpression {
+    pub name: Identifier,
+    pub operator: Option<MathOperator>,
+    pub value: Expression,
+}
+
+impl fmt::Debug for AssignExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.operator {
+            Some(op) => write!(f, "{:?} {:?}= {:?}", self.name, op, self.value),
+            None => write!(f, "{:?} = {:?}", self.name, self.value),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct ConstructExpression {
+    pub name: Identifier,
+    pub fields: Vec<FieldInit>,
+}
+
+impl fmt::Debug for ConstructExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "new {:?} {{ ", self.name)?;
+
+        for (i, field) in self.fields.iter().enumerate() {
+            if i != 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{:?}: {:?}", field.name, field.value)?;
+        }
+
+        write!(f, " }}")
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, new)]
+pub struct FieldInit {
+    pub name: Identifier,
+    pub value: Expression,
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct AssignFieldExpression {
+    pub base: Expression,
+    pub field: Identifier,
+    pub operator: Option<MathOperator>,
+    pub value: Expression,
+}
+
+impl fmt::Debug for AssignFieldExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.operator {
+            Some(op) => write!(f, "{:?}.{:?} {:?}= {:?}", self.base, self.field, op, self.value),
+            None => write!(f, "{:?}.{:?} = {:?}", self.base, self.field, self.value),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct AssignIndexExpression {
+    pub base: Expression,
+    pub index: Expression,
+    pub operator: Option<MathOperator>,
+    pub value: Expression,
+}
+
+impl fmt::Debug for AssignIndexExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.operator {
+            Some(op) => write!(f, "{:?}[{:?}] {:?}= {:?}", self.base, self.index, op, self.value),
+            None => write!(f, "{:?}[{:?}] = {:?}", self.base, self.index, self.value),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct IfExpression {
+    pub condition: Expression,
+    pub then_block: Block,
+    pub else_block: Option<Block>,
+}
+
+impl fmt::Debug for IfExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "if {:?} {:?}", self.condition, self.then_block)?;
+
+        match &self.else_block {
+            Some(else_block) => write!(f, " else {:?}", else_block),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct WhileExpression {
+    pub condition: Expression,
+    pub body: Block,
+}
+
+impl fmt::Debug for WhileExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "while {:?} {:?}", self.condition, self.body)
+    }
+}
+
+#[derive(PartialEq, Clone, new)]
+pub struct ForExpression {
+    pub binding: Binding,
+    pub sequence: Expression,
+    pub body: Block,
+}
+
+impl fmt::Debug for ForExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let prefix = if self.binding.mutable { "mut " } else { "" };
+
+        write!(
+            f,
+            "for {}{:?} in {:?} {:?}",
+            prefix, self.binding.name, self.sequence, self.body
+        )
+    }
+}
+
+// The operands of a `load` or a `store`. `value` is only present for a
+// `store`.
+#[derive(PartialEq, Clone)]
+pub struct MemoryAccess {
+    pub ty: Spanned<Type>,
+    pub address: Expression,
+    pub value: Option<Expression>,
+    pub immediates: MemoryImmediates,
+}
+
+impl MemoryAccess {
+    crate fn new(
+        ty: Spanned<Type>,
+        address: Expression,
+        value: Option<Expression>,
+        immediates: MemoryImmediates,
+    ) -> MemoryAccess {
+        MemoryAccess {
+            ty,
+            address,
+            value,
+            immediates,
+        }
+    }
+}
+
+impl fmt::Debug for MemoryAccess {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "<{:?}>({:?}", self.ty, self.address)?;
+
+        if let Some(value) = &self.value {
+            write!(f, ", {:?}", value)?;
+        }
+
+        if let Some(offset) = &self.immediates.offset {
+            write!(f, ", {:?}", offset)?;
+        }
+
+        if let Some(align) = &self.immediates.align {
+            write!(f, ", {:?}", align)?;
+        }
+
+        write!(f, ")")
+    }
+}
+
+// The constant byte offset added to the address, and the alignment in
+// bytes the address is promised to have
+#[derive(Debug, PartialEq, Clone, new)]
+pub struct MemoryImmediates {
+    pub offset: Option<Token>,
+    pub align: Option<Token>,
+}
@@ -26,13 +26,44 @@ pub struct ByteSpan {
 }
 
 impl Span {
-    pub fn to_codespan_span(&self) -> codespan::ByteSpan {
+    crate fn from_locations(start: usize, end: usize) -> Span {
+        Span::ByteSpan(ByteSpan {
+            start,
+            end,
+            source: Id(0),
+        })
+    }
+
+    // A span from the start of `self` to the end of `other`
+    crate fn to(self, other: Span) -> Span {
+        match (self, other) {
+            (Span::ByteSpan(start), Span::ByteSpan(end)) => Span::ByteSpan(ByteSpan {
+                start: start.start,
+                end: end.end,
+                source: start.source,
+            }),
+            (span, _) => span,
+        }
+    }
+
+    crate fn start(&self) -> Option<usize> {
+        match self {
+            Span::ByteSpan(span) => Some(span.start),
+            Span::Synthetic(..) => None,
+        }
+    }
+
+    // spans count from the start of the source, but a codemap places each
+    // file after the ones added before it
+    pub fn to_codespan_span(&self, file: &codespan::FileMap) -> codespan::ByteSpan {
         use self::Span::*;
 
+        let start = file.span().start();
+
         match self {
             ByteSpan(span) => codespan::Span::new(
-                codespan::ByteIndex(span.start as u32),
-                codespan::ByteIndex(span.end as u32),
+                start + codespan::ByteOffset(span.start as i64),
+                start + codespan::ByteOffset(span.end as i64),
             ),
             _ => unimplemented!("turning synthetic spans into codespans"),
         }
@@ -1,52 +1,286 @@
-use super::annotated;
-use crate::lexer::Token;
-use crate::{ast, FunctionModifiers, MathOperator, Spanned, SpannedItem, Type, UnifyTable};
+use super::annotated::{self, ConstrainedType};
+use crate::lexer::{IdentifierId, Tok, Token};
+use crate::{
+    ast, CompareOperator, Conversion, Field, FunctionModifiers, LogicalOperator, MathOperator,
+    Newtype, Span, Spanned, SpannedItem, StructType, Type, TypeParam, UnaryOperator, UnifyTable,
+};
 use failure::Fail;
+use std::collections::BTreeMap;
 use std::fmt;
 
 #[derive(Debug)]
 pub struct Module {
     pub funcs: Vec<Function>,
+    pub memory: Option<Memory>,
+    pub globals: Vec<Global>,
+    pub consts: Vec<Const>,
+    // in the order they're declared
+    pub structs: Vec<StructType>,
+}
+
+// The module's linear memory, with its limits in 64KiB pages
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct Memory {
+    pub min: u32,
+    pub max: Option<u32>,
+    pub export: bool,
+}
+
+// A global's index is its position in the module
+#[derive(Debug)]
+pub struct Global {
+    pub name: Token,
+    pub ty: Spanned<Type>,
+    pub mutable: bool,
+    pub export: bool,
+    // wasm initializes globals with a constant, so this is a constant
+    // expression
+    pub value: Expression,
+}
+
+// A const's index is its position in the module
+#[derive(Debug)]
+pub struct Const {
+    pub name: Token,
+    pub ty: Spanned<Type>,
+    // only uses literals, other consts and operators
+    pub value: Expression,
 }
 
 #[derive(Debug)]
 pub struct Function {
     pub name: Token,
+    pub generics: Vec<TypeParam>,
     pub params: Vec<Spanned<Type>>,
     pub symbols: Vec<Token>,
+    pub locals: Vec<Local>,
     pub ret: Spanned<Type>,
     pub body: Block,
     pub modifiers: FunctionModifiers,
 }
 
+impl Function {
+    crate fn signature(&self) -> annotated::Signature {
+        annotated::Signature {
+            generics: self.generics.clone(),
+            params: self.params.clone(),
+            ret: self.ret.clone(),
+        }
+    }
+}
+
+// A `let` binding. Its wasm local index is the number of parameters plus
+// its position in `Function::locals`.
+#[derive(Debug)]
+pub struct Local {
+    pub name: Token,
+    pub ty: Option<Spanned<Type>>,
+    pub mutable: bool,
+}
+
 #[derive(Debug)]
 pub struct Block {
     pub expressions: Vec<Expression>,
+    pub tail: bool,
+    pub span: Span,
 }
 
 #[derive(Debug)]
 pub enum Expression {
     Const(ast::ConstExpression),
     VariableAccess(u32),
+    GlobalAccess(u32),
+    // the span is where the const is used
+    ConstAccess {
+        index: u32,
+        span: Span,
+    },
+    FunctionAccess(u32),
+    Apply {
+        function: Box<Expression>,
+        args: Vec<Expression>,
+    },
     Binary {
         operator: Spanned<MathOperator>,
         lhs: Box<Expression>,
         rhs: Box<Expression>,
     },
+    Compare {
+        operator: Spanned<CompareOperator>,
+        lhs: Box<Expression>,
+        rhs: Box<Expression>,
+    },
+    Logical {
+        operator: Spanned<LogicalOperator>,
+        lhs: Box<Expression>,
+        rhs: Box<Expression>,
+    },
+    Unary {
+        operator: Spanned<UnaryOperator>,
+        expr: Box<Expression>,
+    },
+    Convert {
+        expr: Box<Expression>,
+        ty: Spanned<Type>,
+        conversion: Conversion,
+    },
+    Tuple(Vec<Expression>),
+    Let {
+        local: u32,
+        value: Box<Expression>,
+    },
+    // binds each field of a tuple to a new local
+    Destructure {
+        locals: Vec<u32>,
+        value: Box<Expression>,
+    },
+    Assign {
+        local: u32,
+        value: Box<Expression>,
+    },
+    GlobalAssign {
+        global: u32,
+        value: Box<Expression>,
+    },
+    If {
+        condition: Box<Expression>,
+        then_block: Block,
+        else_block: Option<Block>,
+    },
+    While {
+        condition: Box<Expression>,
+        body: Block,
+    },
+    Loop {
+        body: Block,
+    },
+    Break {
+        value: Option<Box<Expression>>,
+    },
+    Continue,
+    Return {
+        value: Option<Box<Expression>>,
+        span: Span,
+    },
+    // `align` is the log2 of the alignment in bytes, like wasm encodes it
+    Load {
+        ty: Spanned<Type>,
+        address: Box<Expression>,
+        offset: u32,
+        align: u32,
+    },
+    Store {
+        ty: Spanned<Type>,
+        address: Box<Expression>,
+        value: Box<Expression>,
+        offset: u32,
+        align: u32,
+    },
+    MemorySize,
+    MemoryGrow {
+        pages: Box<Expression>,
+    },
+    // `ty` is the struct; the fields are in the order they're written
+    Construct {
+        ty: Spanned<Type>,
+        fields: Vec<(u32, Expression)>,
+    },
+    // `base` is a struct, which inference finds out
+    Field {
+        base: Box<Expression>,
+        name: Token,
+    },
+    AssignField {
+        base: Box<Expression>,
+        name: Token,
+        operator: Option<Spanned<MathOperator>>,
+        value: Box<Expression>,
+    },
+    // `address as Point` is the struct at `address`
+    StructAt {
+        ty: Spanned<Type>,
+        address: Box<Expression>,
+    },
+    // the span is the opening bracket
+    Array {
+        elements: Vec<Expression>,
+        span: Span,
+    },
+    RepeatArray {
+        value: Box<Expression>,
+        len: u32,
+        span: Span,
+    },
+    // `base` is an array or a slice, which inference finds out; the span
+    // is the opening bracket
+    Index {
+        base: Box<Expression>,
+        index: Box<Expression>,
+        span: Span,
+    },
+    AssignIndex {
+        base: Box<Expression>,
+        index: Box<Expression>,
+        span: Span,
+        operator: Option<Spanned<MathOperator>>,
+        value: Box<Expression>,
+    },
+    // `&array`; the span is the `&`
+    SliceOf {
+        array: Box<Expression>,
+        span: Span,
+    },
+    // `len(sequence)`; the span is `len`
+    Len {
+        sequence: Box<Expression>,
+        span: Span,
+    },
+    // binds `local` to each element of `sequence` in turn; the span is
+    // `for`
+    For {
+        local: u32,
+        sequence: Box<Expression>,
+        body: Block,
+        span: Span,
+    },
 }
 
 impl Expression {
     crate fn annotate(
         self,
         vars: &mut UnifyTable,
-        env: &annotated::TypeEnv,
+        env: &mut annotated::TypeEnv<'_>,
     ) -> annotated::Annotated<annotated::Expression> {
         match self {
             Expression::Const(expr) => vars.annotate_fresh(annotated::Expression::Const(expr)),
             Expression::VariableAccess(id) => {
                 let ty = env.get_local(id as usize);
-                annotated::InferType::Resolved(ty)
-                    .annotate(annotated::Expression::VariableAccess(id))
+                ty.annotate(annotated::Expression::VariableAccess(id))
+            }
+            Expression::GlobalAccess(global) => {
+                let ty = env.get_global(global as usize);
+                ty.annotate(annotated::Expression::GlobalAccess(global))
+            }
+            Expression::ConstAccess { index, span } => {
+                let ty = env.get_const(index as usize);
+                ty.annotate(annotated::Expression::ConstAccess { index, span })
+            }
+            Expression::FunctionAccess(function) => {
+                let (ty, type_args) = env.get_function(function as usize).instantiate(vars);
+                ty.annotate(annotated::Expression::FunctionAccess {
+                    function,
+                    type_args,
+                })
+            }
+            Expression::Apply {
+                box function,
+                args,
+            } => {
+                let t1 = vars.fresh();
+                let function = function.annotate(vars, env);
+                let args = args.into_iter().map(|arg| arg.annotate(vars, env)).collect();
+
+                t1.annotate(annotated::Expression::Apply(box function, args))
             }
             Expression::Binary {
                 operator,
@@ -60,6 +294,312 @@ impl Expression {
                     rhs: box rhs.annotate(vars, env),
                 })
             }
+            Expression::Compare {
+                operator,
+                box lhs,
+                box rhs,
+            } => {
+                let t1 = vars.fresh();
+                t1.annotate(annotated::Expression::Compare {
+                    operator,
+                    lhs: box lhs.annotate(vars, env),
+                    rhs: box rhs.annotate(vars, env),
+                })
+            }
+            Expression::Logical {
+                operator,
+                box lhs,
+                box rhs,
+            } => {
+                let t1 = vars.fresh();
+                t1.annotate(annotated::Expression::Logical {
+                    operator,
+                    lhs: box lhs.annotate(vars, env),
+                    rhs: box rhs.annotate(vars, env),
+                })
+            }
+            Expression::Unary { operator, box expr } => {
+                let t1 = vars.fresh();
+                t1.annotate(annotated::Expression::Unary {
+                    operator,
+                    expr: box expr.annotate(vars, env),
+                })
+            }
+            Expression::Convert {
+                box expr,
+                ty,
+                conversion,
+            } => annotated::InferType::Resolved(ty).annotate(annotated::Expression::Convert {
+                expr: box expr.annotate(vars, env),
+                conversion,
+            }),
+            Expression::Tuple(fields) => {
+                let fields: Vec<_> = fields
+                    .into_iter()
+                    .map(|field| field.annotate(vars, env))
+                    .collect();
+                let ty = annotated::InferType::Tuple(fields.iter().map(|f| f.ty.clone()).collect());
+
+                ty.annotate(annotated::Expression::Tuple(fields))
+            }
+            Expression::Let { local, box value } => {
+                let binding = env.get_local(local as usize);
+                annotated::InferType::void().annotate(annotated::Expression::Let {
+                    local,
+                    binding,
+                    value: box value.annotate(vars, env),
+                })
+            }
+            Expression::Destructure { locals, box value } => {
+                let bindings = locals
+                    .iter()
+                    .map(|local| env.get_local(*local as usize))
+                    .collect();
+
+                annotated::InferType::void().annotate(annotated::Expression::Destructure {
+                    locals,
+                    bindings,
+                    value: box value.annotate(vars, env),
+                })
+            }
+            Expression::Assign { local, box value } => {
+                let binding = env.get_local(local as usize);
+                annotated::InferType::void().annotate(annotated::Expression::Assign {
+                    local,
+                    binding,
+                    value: box value.annotate(vars, env),
+                })
+            }
+            Expression::GlobalAssign { global, box value } => {
+                let binding = env.get_global(global as usize);
+                annotated::InferType::void().annotate(annotated::Expression::GlobalAssign {
+                    global,
+                    binding,
+                    value: box value.annotate(vars, env),
+                })
+            }
+            Expression::If {
+                box condition,
+                then_block,
+                else_block,
+            } => {
+                let t1 = vars.fresh();
+                t1.annotate(annotated::Expression::If {
+                    condition: box condition.annotate(vars, env),
+                    then_block: annotated::Block::from(then_block, vars, env),
+                    else_block: else_block.map(|block| annotated::Block::from(block, vars, env)),
+                })
+            }
+            Expression::While {
+                box condition,
+                body,
+            } => {
+                let condition = condition.annotate(vars, env);
+
+                env.enter_loop(annotated::InferType::void());
+                let body = annotated::Block::from(body, vars, env);
+                env.exit_loop();
+
+                annotated::InferType::void().annotate(annotated::Expression::While {
+                    condition: box condition,
+                    body,
+                })
+            }
+            Expression::Loop { body } => {
+                let t1 = vars.fresh();
+
+                env.enter_loop(t1.clone());
+                let body = annotated::Block::from(body, vars, env);
+
+                // a loop that is never broken out of never finishes
+                let ty = if env.exit_loop() {
+                    t1
+                } else {
+                    annotated::InferType::never()
+                };
+
+                ty.annotate(annotated::Expression::Loop { body })
+            }
+            Expression::Break { value } => {
+                let target = env.break_loop();
+                let value = value.map(|box value| box value.annotate(vars, env));
+
+                annotated::InferType::never().annotate(annotated::Expression::Break { target, value })
+            }
+            Expression::Continue => {
+                annotated::InferType::never().annotate(annotated::Expression::Continue)
+            }
+            Expression::Return { value, span } => {
+                let ret = env.return_type();
+                let value = value.map(|box value| box value.annotate(vars, env));
+
+                annotated::InferType::never().annotate(annotated::Expression::Return {
+                    ret,
+                    value,
+                    span,
+                })
+            }
+            Expression::Load {
+                ty,
+                box address,
+                offset,
+                align,
+            } => annotated::InferType::Resolved(ty).annotate(annotated::Expression::Load {
+                address: box address.annotate(vars, env),
+                offset,
+                align,
+            }),
+            Expression::Store {
+                ty,
+                box address,
+                box value,
+                offset,
+                align,
+            } => annotated::InferType::void().annotate(annotated::Expression::Store {
+                ty,
+                address: box address.annotate(vars, env),
+                value: box value.annotate(vars, env),
+                offset,
+                align,
+            }),
+            Expression::MemorySize => annotated::InferType::Resolved(
+                Type::u32().synthetic("memory.size"),
+            ).annotate(annotated::Expression::MemorySize),
+            // `memory.grow` produces the previous size, or -1 if the memory
+            // couldn't grow
+            Expression::MemoryGrow { box pages } => annotated::InferType::Resolved(
+                Type::i32().synthetic("memory.grow"),
+            ).annotate(annotated::Expression::MemoryGrow {
+                pages: box pages.annotate(vars, env),
+            }),
+            Expression::Construct { ty, fields } => {
+                let fields = fields
+                    .into_iter()
+                    .map(|(field, value)| (field, value.annotate(vars, env)))
+                    .collect();
+
+                annotated::InferType::Resolved(ty.clone())
+                    .annotate(annotated::Expression::Construct { ty, fields })
+            }
+            Expression::Field { box base, name } => {
+                let base = base.annotate(vars, env);
+
+                vars.annotate_fresh(annotated::Expression::Field {
+                    base: box base,
+                    name,
+                })
+            }
+            Expression::AssignField {
+                box base,
+                name,
+                operator,
+                box value,
+            } => annotated::InferType::void().annotate(annotated::Expression::AssignField {
+                base: box base.annotate(vars, env),
+                name,
+                operator,
+                value: box value.annotate(vars, env),
+            }),
+            Expression::StructAt { ty, box address } => {
+                annotated::InferType::Resolved(ty).annotate(annotated::Expression::StructAt {
+                    address: box address.annotate(vars, env),
+                })
+            }
+            // an array literal has at least one element, whose type the
+            // others share
+            Expression::Array { elements, span } => {
+                let elements: Vec<_> = elements
+                    .into_iter()
+                    .map(|element| element.annotate(vars, env))
+                    .collect();
+                let ty = annotated::InferType::Array(
+                    box elements[0].ty.clone(),
+                    elements.len() as u32,
+                );
+
+                ty.annotate(annotated::Expression::Array { elements, span })
+            }
+            Expression::RepeatArray {
+                box value,
+                len,
+                span,
+            } => {
+                let value = value.annotate(vars, env);
+                let ty = annotated::InferType::Array(box value.ty.clone(), len);
+
+                ty.annotate(annotated::Expression::RepeatArray {
+                    value: box value,
+                    len,
+                    span,
+                })
+            }
+            Expression::Index {
+                box base,
+                box index,
+                span,
+            } => {
+                let base = base.annotate(vars, env);
+                let index = index.annotate(vars, env);
+
+                vars.annotate_fresh(annotated::Expression::Index {
+                    base: box base,
+                    index: box index,
+                    span,
+                })
+            }
+            Expression::AssignIndex {
+                box base,
+                box index,
+                span,
+                operator,
+                box value,
+            } => annotated::InferType::void().annotate(annotated::Expression::AssignIndex {
+                base: box base.annotate(vars, env),
+                index: box index.annotate(vars, env),
+                span,
+                operator,
+                value: box value.annotate(vars, env),
+            }),
+            Expression::SliceOf { box array, span } => {
+                let array = array.annotate(vars, env);
+
+                vars.annotate_fresh(annotated::Expression::SliceOf {
+                    array: box array,
+                    span,
+                })
+            }
+            // the elements aren't needed to count them, but their type
+            // still has to be found out for the sequence to be compiled
+            Expression::Len { box sequence, span } => annotated::InferType::Resolved(
+                Type::u32().synthetic("len"),
+            ).annotate(annotated::Expression::Len {
+                sequence: box sequence.annotate(vars, env),
+                element: vars.fresh(),
+                span,
+            }),
+            // the local's type is the element type, and a `for` can't be
+            // broken out of with a value
+            Expression::For {
+                local,
+                box sequence,
+                body,
+                span,
+            } => {
+                let sequence = sequence.annotate(vars, env);
+                let element = env.get_local(local as usize);
+
+                env.enter_loop(annotated::InferType::void());
+                let body = annotated::Block::from(body, vars, env);
+                env.exit_loop();
+
+                annotated::InferType::void().annotate(annotated::Expression::For {
+                    local,
+                    sequence: box sequence,
+                    element,
+                    body,
+                    span,
+                })
+            }
         }
     }
 }
@@ -75,85 +615,1491 @@ struct ResolveModule<'a> {
 
 impl ResolveModule<'a> {
     fn resolve(&self) -> Result<Module, ResolveError> {
+        let types = ResolveTypes::new(self.module)?.resolve()?;
+        let functions = self.functions()?;
+        let memory = self.memory()?;
+        let consts = ResolveConsts::new(self.module, &types)?.resolve()?;
+        let globals = self.globals(&types, &consts)?;
+
         let funcs: Result<Vec<Function>, ResolveError> = self
             .module
             .funcs
             .iter()
-            .map(|func| self.resolve_function(func))
+            .map(|func| {
+                ResolveFunction::new(func, &functions, &types, &globals, &consts)
+                    .resolve()
+            })
             .collect();
 
-        Ok(Module { funcs: funcs? })
+        let structs = self
+            .module
+            .structs
+            .iter()
+            .map(|declaration| match &types[&declaration.name.to_ident()] {
+                Type::Struct(declared) => (**declared).clone(),
+                other => unreachable!("struct {:?} resolved to {:?}", declaration.name, other),
+            })
+            .collect();
+
+        Ok(Module {
+            funcs: funcs?,
+            memory,
+            globals,
+            consts,
+            structs,
+        })
+    }
+
+    fn globals(
+        &self,
+        types: &BTreeMap<IdentifierId, Type>,
+        consts: &[Const],
+    ) -> Result<Vec<Global>, ResolveError> {
+        let mut names: BTreeMap<IdentifierId, Token> = BTreeMap::new();
+        let mut globals = vec![];
+
+        for global in &self.module.globals {
+            let id = global.name.to_ident();
+
+            // globals and consts share a namespace
+            let first = names
+                .insert(id, global.name)
+                .or_else(|| consts.iter().map(|c| c.name).find(|name| name.to_ident() == id));
+
+            if let Some(first) = first {
+                return Err(ResolveError::DuplicateGlobal {
+                    first,
+                    second: global.name,
+                });
+            }
+
+            globals.push(self.resolve_global(global, types, consts)?);
+        }
+
+        Ok(globals)
+    }
+
+    // wasm globals hold a single number, and only immutable ones can be
+    // exported
+    fn resolve_global(
+        &self,
+        global: &ast::GlobalDeclaration,
+        types: &BTreeMap<IdentifierId, Type>,
+        consts: &[Const],
+    ) -> Result<Global, ResolveError> {
+        let ty = resolve_declared_type(&global.ty, types)?;
+
+        match ty.node.representation() {
+            Type::Math(..) | Type::Bool => {}
+            _ => return Err(ResolveError::InvalidGlobalType(global.name)),
+        }
+
+        if global.export && global.mutable {
+            return Err(ResolveError::ExportedMutableGlobal(global.name));
+        }
+
+        let module = self.module;
+        let value = resolve_constant(&global.value, global.name, types, &mut |name: &Token| {
+            consts
+                .iter()
+                .position(|constant| constant.name.to_ident() == name.to_ident())
+                .map(|index| index as u32)
+                .ok_or_else(|| unknown_constant(module, name))
+        })?;
+
+        Ok(Global {
+            name: global.name,
+            ty,
+            mutable: global.mutable,
+            export: global.export,
+            value,
+        })
+    }
+
+    // wasm modules have at most one memory
+    fn memory(&self) -> Result<Option<Memory>, ResolveError> {
+        let memories = &self.module.memories;
+
+        if memories.len() > 1 {
+            return Err(ResolveError::DuplicateMemory {
+                first: memories[0].token,
+                second: memories[1].token,
+            });
+        }
+
+        let declaration = match memories.first() {
+            None => return Ok(None),
+            Some(declaration) => declaration,
+        };
+
+        let min = pages(declaration.min)?;
+
+        let max = match declaration.max {
+            None => None,
+            Some(max) => {
+                let pages = pages(max)?;
+
+                if pages < min {
+                    return Err(ResolveError::InvalidMemoryLimits(max));
+                }
+
+                Some(pages)
+            }
+        };
+
+        Ok(Some(Memory {
+            min,
+            max,
+            export: declaration.export,
+        }))
     }
 
-    fn resolve_function(&self, func: &'input ast::Function) -> Result<Function, ResolveError> {
-        ResolveFunction { func }.resolve()
+    // Functions are visible to the whole module, so a function can call
+    // itself or a function defined after it. A function's index is its
+    // position in the module.
+    fn functions(&self) -> Result<BTreeMap<IdentifierId, u32>, ResolveError> {
+        let mut functions = BTreeMap::new();
+
+        for (index, func) in self.module.funcs.iter().enumerate() {
+            if let Some(first) = functions.insert(func.name.to_ident(), index as u32) {
+                return Err(ResolveError::DuplicateFunction {
+                    first: self.module.funcs[first as usize].name,
+                    second: func.name,
+                });
+            }
+        }
+
+        Ok(functions)
     }
 }
 
-struct ResolveFunction<'a> {
-    func: &'a ast::Function,
+// Resolves the consts declared in a module. Their values can refer to each
+// other in any order, as long as they don't form a cycle.
+struct ResolveConsts<'a> {
+    module: &'a ast::Module,
+    types: &'a BTreeMap<IdentifierId, Type>,
+    declarations: BTreeMap<IdentifierId, usize>,
+    consts: Vec<Option<Const>>,
+    // the declarations being resolved, to detect cycles
+    pending: Vec<usize>,
 }
 
-#[derive(Debug, Eq, PartialEq, Clone)]
-pub enum ResolveError {}
+impl ResolveConsts<'a> {
+    fn new(
+        module: &'a ast::Module,
+        types: &'a BTreeMap<IdentifierId, Type>,
+    ) -> Result<ResolveConsts<'a>, ResolveError> {
+        let mut declarations = BTreeMap::new();
 
-impl fmt::Display for ResolveError {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "ResolveError")
+        for (index, declaration) in module.consts.iter().enumerate() {
+            if let Some(first) = declarations.insert(declaration.name.to_ident(), index) {
+                return Err(ResolveError::DuplicateConst {
+                    first: module.consts[first].name,
+                    second: declaration.name,
+                });
+            }
+        }
+
+        Ok(ResolveConsts {
+            module,
+            types,
+            declarations,
+            consts: module.consts.iter().map(|_| None).collect(),
+            pending: vec![],
+        })
     }
-}
 
-impl Fail for ResolveError {}
+    fn resolve(mut self) -> Result<Vec<Const>, ResolveError> {
+        for index in 0..self.module.consts.len() {
+            self.resolve_declaration(index)?;
+        }
 
-impl<'a> ResolveFunction<'a> {
-    fn resolve(&self) -> Result<Function, ResolveError> {
-        let ResolveFunction { func, .. } = self;
+        Ok(self
+            .consts
+            .into_iter()
+            .map(|constant| constant.expect("every const was resolved"))
+            .collect())
+    }
 
-        let mut symbols = vec![];
-        let mut params = vec![];
+    fn resolve_declaration(&mut self, index: usize) -> Result<(), ResolveError> {
+        let module = self.module;
+        let types = self.types;
+        let declaration = &module.consts[index];
 
-        for (name, ty) in func.args.iter() {
-            symbols.push(name);
-            params.push(ty.clone());
+        if self.consts[index].is_some() {
+            return Ok(());
         }
 
-        let ret = func.ret.clone();
+        if self.pending.contains(&index) {
+            return Err(ResolveError::RecursiveConst(declaration.name));
+        }
 
-        let mut expressions = vec![];
+        self.pending.push(index);
 
-        for expr in &func.body.expressions {
-            expressions.push(self.resolve_expression(expr)?);
+        let value = resolve_constant(&declaration.value, declaration.name, types, &mut |name| {
+            self.lookup(name)
+        })?;
+
+        self.pending.pop();
+
+        let ty = resolve_declared_type(&declaration.ty, types)?;
+
+        match ty.node.representation() {
+            Type::Math(..) | Type::Bool => {}
+            _ => return Err(ResolveError::InvalidConstType(declaration.name)),
         }
 
-        Ok(Function {
-            name: func.name,
-            params,
-            symbols,
-            ret,
-            body: Block { expressions },
-            modifiers: func.modifiers,
-        })
+        self.consts[index] = Some(Const {
+            name: declaration.name,
+            ty,
+            value,
+        });
+
+        Ok(())
     }
 
-    fn resolve_expression(&self, expr: &ast::Expression) -> Result<Expression, ResolveError> {
-        let expr = match expr {
-            ast::Expression::Const(constant) => Expression::Const(*constant),
-            ast::Expression::VariableAccess(id) => {
-                let local = self.func.mappings.get(&id.to_ident()).unwrap();
-                Expression::VariableAccess(*local)
-            }
-            ast::Expression::Binary(operator, tok, box ast::BinaryExpression { lhs, rhs }) => {
-                let lhs = self.resolve_expression(lhs)?;
-                let rhs = self.resolve_expression(rhs)?;
+    fn lookup(&mut self, name: &Token) -> Result<u32, ResolveError> {
+        let index = match self.declarations.get(&name.to_ident()) {
+            Some(index) => *index,
+            None => return Err(unknown_constant(self.module, name)),
+        };
 
-                Expression::Binary {
-                    operator: (*operator).copy_span(&tok),
-                    lhs: box lhs,
-                    rhs: box rhs,
-                }
+        self.resolve_declaration(index)?;
+        Ok(index as u32)
+    }
+}
+
+// The value of a const or a global, which can only be built from literals,
+// consts and operators. `owner` is the declaration, which errors point at.
+fn resolve_constant(
+    expr: &ast::Expression,
+    owner: Token,
+    types: &BTreeMap<IdentifierId, Type>,
+    lookup: &mut dyn FnMut(&Token) -> Result<u32, ResolveError>,
+) -> Result<Expression, ResolveError> {
+    let expr = match expr {
+        ast::Expression::Const(constant) => Expression::Const(*constant),
+        ast::Expression::VariableAccess(name) => Expression::ConstAccess {
+            index: lookup(name)?,
+            span: name.span,
+        },
+        ast::Expression::Binary(operator, tok, box ast::BinaryExpression { lhs, rhs }) => {
+            Expression::Binary {
+                operator: (*operator).copy_span(&tok),
+                lhs: box resolve_constant(lhs, owner, types, lookup)?,
+                rhs: box resolve_constant(rhs, owner, types, lookup)?,
             }
-        };
+        }
+        ast::Expression::Compare(operator, tok, box ast::BinaryExpression { lhs, rhs }) => {
+            Expression::Compare {
+                operator: (*operator).copy_span(&tok),
+                lhs: box resolve_constant(lhs, owner, types, lookup)?,
+                rhs: box resolve_constant(rhs, owner, types, lookup)?,
+            }
+        }
+        ast::Expression::Logical(operator, tok, box ast::BinaryExpression { lhs, rhs }) => {
+            Expression::Logical {
+                operator: (*operator).copy_span(&tok),
+                lhs: box resolve_constant(lhs, owner, types, lookup)?,
+                rhs: box resolve_constant(rhs, owner, types, lookup)?,
+            }
+        }
+        ast::Expression::Unary(operator, tok, box expr) => Expression::Unary {
+            operator: (*operator).copy_span(&tok),
+            expr: box resolve_constant(expr, owner, types, lookup)?,
+        },
+        ast::Expression::Convert(_tok, box expr, ty, conversion) => Expression::Convert {
+            expr: box resolve_constant(expr, owner, types, lookup)?,
+            ty: resolve_declared_type(ty, types)?,
+            conversion: *conversion,
+        },
+        _ => return Err(ResolveError::NonConstantInitializer(owner)),
+    };
 
-        Ok(expr)
+    Ok(expr)
+}
+
+// A name that isn't a const, which is an error even if it names a global,
+// since a global's value can change
+fn unknown_constant(module: &ast::Module, name: &Token) -> ResolveError {
+    let id = name.to_ident();
+
+    if module.globals.iter().any(|global| global.name.to_ident() == id) {
+        ResolveError::NonConstantInitializer(*name)
+    } else {
+        ResolveError::UnknownVariable(*name)
+    }
+}
+
+// A type outside of a function, which can name a declared type but not a
+// type parameter
+fn resolve_declared_type(
+    ty: &Spanned<Type>,
+    types: &BTreeMap<IdentifierId, Type>,
+) -> Result<Spanned<Type>, ResolveError> {
+    match &ty.node {
+        Type::Named(id) => match types.get(id) {
+            Some(declared) => Ok(declared.clone().copy_span(ty)),
+            None => Err(ResolveError::UnknownType(Tok::Id(*id).copy_span(ty))),
+        },
+        Type::Array(element, len) => {
+            let element = resolve_declared_type(&(**element).clone().copy_span(ty), types)?;
+            Ok(Type::Array(box element_of(element.node, ty)?, *len).copy_span(ty))
+        }
+        Type::Slice(element) => {
+            let element = resolve_declared_type(&(**element).clone().copy_span(ty), types)?;
+            Ok(Type::Slice(box element_of(element.node, ty)?).copy_span(ty))
+        }
+        _ => Ok(ty.clone()),
+    }
+}
+
+// Elements are stored in linear memory like a struct's fields, so they're
+// numbers, booleans or structs. `ty` is the whole array or slice type,
+// which errors point at.
+fn element_of(element: Type, ty: &Spanned<Type>) -> Result<Type, ResolveError> {
+    match element.representation() {
+        Type::Math(..) | Type::Bool | Type::Struct(..) => Ok(element),
+        _ => Err(ResolveError::InvalidElementType(ty.clone())),
     }
 }
+
+// Resolves the types and structs declared in a module to what they stand
+// for. They can refer to each other in any order, as long as they don't
+// form a cycle.
+struct ResolveTypes<'a> {
+    module: &'a ast::Module,
+    declarations: BTreeMap<IdentifierId, Declaration>,
+    types: BTreeMap<IdentifierId, Type>,
+    // the declarations being resolved, to detect cycles
+    pending: Vec<Declaration>,
+}
+
+// Types and structs share a namespace; the index is the declaration's
+// position among the module's types or its structs
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+enum Declaration {
+    Type(usize),
+    Struct(usize),
+}
+
+impl ResolveTypes<'a> {
+    fn new(module: &'a ast::Module) -> Result<ResolveTypes<'a>, ResolveError> {
+        let mut declarations = BTreeMap::new();
+
+        let types = module
+            .types
+            .iter()
+            .enumerate()
+            .map(|(index, declaration)| (declaration.name, Declaration::Type(index)));
+        let structs = module
+            .structs
+            .iter()
+            .enumerate()
+            .map(|(index, declaration)| (declaration.name, Declaration::Struct(index)));
+
+        for (name, declaration) in types.chain(structs) {
+            if let Some(first) = declarations.insert(name.to_ident(), declaration) {
+                return Err(ResolveError::DuplicateType {
+                    first: declaration_name(module, first),
+                    second: name,
+                });
+            }
+        }
+
+        Ok(ResolveTypes {
+            module,
+            declarations,
+            types: BTreeMap::new(),
+            pending: vec![],
+        })
+    }
+
+    fn resolve(mut self) -> Result<BTreeMap<IdentifierId, Type>, ResolveError> {
+        for index in 0..self.module.types.len() {
+            self.resolve_declaration(Declaration::Type(index))?;
+        }
+
+        for index in 0..self.module.structs.len() {
+            self.resolve_declaration(Declaration::Struct(index))?;
+        }
+
+        Ok(self.types)
+    }
+
+    fn resolve_declaration(&mut self, declaration: Declaration) -> Result<Type, ResolveError> {
+        let module = self.module;
+        let name = declaration_name(module, declaration);
+
+        if let Some(ty) = self.types.get(&name.to_ident()) {
+            return Ok(ty.clone());
+        }
+
+        if self.pending.contains(&declaration) {
+            return Err(ResolveError::RecursiveTypeDeclaration(name));
+        }
+
+        self.pending.push(declaration);
+
+        let ty = match declaration {
+            Declaration::Type(index) => self.resolve_type_declaration(index)?,
+            Declaration::Struct(index) => self.resolve_struct(index)?,
+        };
+
+        self.pending.pop();
+
+        self.types.insert(name.to_ident(), ty.clone());
+        Ok(ty)
+    }
+
+    fn resolve_type_declaration(&mut self, index: usize) -> Result<Type, ResolveError> {
+        let module = self.module;
+        let declaration = &module.types[index];
+
+        let ty = self.resolve_type(&declaration.ty.node, &declaration.ty)?;
+
+        let numeric = ty.representation().is_integer() || ty.representation().is_float();
+
+        if !declaration.nominal {
+            Ok(ty)
+        } else if numeric {
+            Ok(Type::Newtype(box Newtype {
+                index: index as u32,
+                repr: ty,
+            }))
+        } else {
+            Err(ResolveError::NonNumericNewtype(declaration.name))
+        }
+    }
+
+    // A struct's fields are stored in linear memory, so they're numbers or
+    // booleans
+    fn resolve_struct(&mut self, index: usize) -> Result<Type, ResolveError> {
+        let module = self.module;
+        let declaration = &module.structs[index];
+
+        let mut names: BTreeMap<IdentifierId, Token> = BTreeMap::new();
+        let mut fields = vec![];
+
+        for field in &declaration.fields {
+            if let Some(first) = names.insert(field.name.to_ident(), field.name) {
+                return Err(ResolveError::DuplicateField {
+                    first,
+                    second: field.name,
+                });
+            }
+
+            let ty = self.resolve_type(&field.ty.node, &field.ty)?;
+
+            match ty.representation() {
+                Type::Math(..) | Type::Bool => {}
+                _ => return Err(ResolveError::InvalidFieldType(field.name)),
+            }
+
+            fields.push(Field {
+                name: field.name.to_ident(),
+                ty,
+            });
+        }
+
+        Ok(Type::Struct(box StructType {
+            index: index as u32,
+            fields,
+        }))
+    }
+
+    // `span` is the whole declared type, which errors point at
+    fn resolve_type(&mut self, ty: &Type, span: &Spanned<Type>) -> Result<Type, ResolveError> {
+        match ty {
+            Type::Named(id) => {
+                let unknown = || ResolveError::UnknownType(Tok::Id(*id).copy_span(span));
+                let other = *self.declarations.get(id).ok_or_else(unknown)?;
+
+                self.resolve_declaration(other)
+            }
+            Type::Tuple(fields) => {
+                let fields: Result<Vec<Type>, ResolveError> = fields
+                    .iter()
+                    .map(|field| self.resolve_type(field, span))
+                    .collect();
+
+                Ok(Type::Tuple(fields?))
+            }
+            Type::Array(element, len) => {
+                let element = self.resolve_type(element, span)?;
+                Ok(Type::Array(box element_of(element, span)?, *len))
+            }
+            Type::Slice(element) => {
+                let element = self.resolve_type(element, span)?;
+                Ok(Type::Slice(box element_of(element, span)?))
+            }
+            other => Ok(other.clone()),
+        }
+    }
+}
+
+fn declaration_name(module: &ast::Module, declaration: Declaration) -> Token {
+    match declaration {
+        Declaration::Type(index) => module.types[index].name,
+        Declaration::Struct(index) => module.structs[index].name,
+    }
+}
+
+struct ResolveFunction<'a> {
+    func: &'a ast::Function,
+    functions: &'a BTreeMap<IdentifierId, u32>,
+    types: &'a BTreeMap<IdentifierId, Type>,
+    globals: &'a [Global],
+    consts: &'a [Const],
+    generics: Vec<TypeParam>,
+    params: Vec<Spanned<Type>>,
+    scopes: Vec<BTreeMap<IdentifierId, u32>>,
+    locals: Vec<Local>,
+    loop_depth: u32,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum ResolveError {
+    UnknownVariable(Token),
+    UnknownFunction(Token),
+    DuplicateFunction { first: Token, second: Token },
+    UnknownType(Token),
+    UnknownBound(Token),
+    DuplicateType { first: Token, second: Token },
+    // a type declaration that refers back to itself, like `type A = A;`
+    RecursiveTypeDeclaration(Token),
+    // newtypes only wrap numeric types, which `as` can convert from
+    NonNumericNewtype(Token),
+    DuplicateTypeParameter { first: Token, second: Token },
+    // a type parameter that no parameter uses can't be inferred at a call
+    UnusedTypeParameter(Token),
+    // an export needs a single wasm signature, so it can't be generic
+    ExportedGeneric(Token),
+    AssignToImmutable { binding: Token, assignment: Token },
+    OutsideOfLoop(Token),
+    DuplicateMemory { first: Token, second: Token },
+    // more than 65536 pages (4GiB), or a maximum below the initial size
+    InvalidMemoryLimits(Token),
+    // `memory.name(..)` other than `memory.size()` and `memory.grow(pages)`
+    UnknownMemoryOperation(Token),
+    // `load` and `store` only move numbers
+    NonNumericMemoryAccess(Token),
+    // an offset that doesn't fit in a `u32`
+    InvalidOffset(Token),
+    // alignments are powers of two, no larger than the type's size
+    InvalidAlignment(Token),
+    DuplicateGlobal { first: Token, second: Token },
+    // globals are numbers or booleans
+    InvalidGlobalType(Token),
+    // a const or a global's value that uses something other than literals,
+    // consts and operators
+    NonConstantInitializer(Token),
+    // the wasm version we target can't export mutable globals
+    ExportedMutableGlobal(Token),
+    DuplicateConst { first: Token, second: Token },
+    // a const whose value refers back to itself
+    RecursiveConst(Token),
+    // consts are numbers or booleans
+    InvalidConstType(Token),
+    DuplicateField { first: Token, second: Token },
+    // fields are numbers or booleans
+    InvalidFieldType(Token),
+    // `new T { .. }` where `T` isn't a struct
+    NotAStruct(Token),
+    // a field the struct doesn't declare
+    UnknownField(Token),
+    // `new Point { x: 1.0 }` leaves out `y`
+    MissingField { construction: Token, field: IdentifierId },
+    // elements are numbers, booleans or structs
+    InvalidElementType(Spanned<Type>),
+    // `len` takes a single array or slice
+    LenArguments(Token),
+}
+
+impl fmt::Display for ResolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::ResolveError::*;
+
+        // names are interned, so the spans are what point back at the source
+        match self {
+            UnknownVariable(name) => write!(f, "unknown variable at {:?}", name.span),
+            UnknownFunction(name) => write!(f, "unknown function at {:?}", name.span),
+            DuplicateFunction { first, second } => write!(
+                f,
+                "function at {:?} is already defined at {:?}",
+                second.span, first.span
+            ),
+            UnknownType(name) => write!(f, "unknown type at {:?}", name.span),
+            UnknownBound(name) => write!(f, "unknown bound at {:?}", name.span),
+            DuplicateType { first, second } => write!(
+                f,
+                "type at {:?} is already defined at {:?}",
+                second.span, first.span
+            ),
+            RecursiveTypeDeclaration(name) => {
+                write!(f, "type at {:?} refers to itself", name.span)
+            }
+            NonNumericNewtype(name) => {
+                write!(f, "newtype at {:?} doesn't wrap a numeric type", name.span)
+            }
+            DuplicateTypeParameter { first, second } => write!(
+                f,
+                "type parameter at {:?} is already declared at {:?}",
+                second.span, first.span
+            ),
+            UnusedTypeParameter(name) => write!(
+                f,
+                "type parameter at {:?} isn't used by any parameter",
+                name.span
+            ),
+            ExportedGeneric(name) => {
+                write!(f, "generic function at {:?} can't be exported", name.span)
+            }
+            AssignToImmutable { binding, assignment } => write!(
+                f,
+                "assignment at {:?} to the immutable binding at {:?}",
+                assignment.span, binding.span
+            ),
+            OutsideOfLoop(token) => write!(f, "{:?} outside of a loop", token.span),
+            DuplicateMemory { first, second } => write!(
+                f,
+                "memory at {:?} is already declared at {:?}",
+                second.span, first.span
+            ),
+            InvalidMemoryLimits(limit) => write!(f, "invalid memory limits at {:?}", limit.span),
+            UnknownMemoryOperation(name) => {
+                write!(f, "unknown memory operation at {:?}", name.span)
+            }
+            NonNumericMemoryAccess(token) => {
+                write!(f, "memory access at {:?} of a non-numeric type", token.span)
+            }
+            InvalidOffset(offset) => write!(f, "invalid offset at {:?}", offset.span),
+            InvalidAlignment(align) => write!(f, "invalid alignment at {:?}", align.span),
+            DuplicateGlobal { first, second } => write!(
+                f,
+                "global at {:?} is already defined at {:?}",
+                second.span, first.span
+            ),
+            InvalidGlobalType(name) => {
+                write!(f, "global at {:?} isn't a number or a boolean", name.span)
+            }
+            NonConstantInitializer(token) => {
+                write!(f, "initializer at {:?} isn't constant", token.span)
+            }
+            ExportedMutableGlobal(name) => {
+                write!(f, "mutable global at {:?} can't be exported", name.span)
+            }
+            DuplicateConst { first, second } => write!(
+                f,
+                "const at {:?} is already defined at {:?}",
+                second.span, first.span
+            ),
+            RecursiveConst(name) => write!(f, "const at {:?} refers to itself", name.span),
+            InvalidConstType(name) => {
+                write!(f, "const at {:?} isn't a number or a boolean", name.span)
+            }
+            DuplicateField { first, second } => write!(
+                f,
+                "field at {:?} is already given at {:?}",
+                second.span, first.span
+            ),
+            InvalidFieldType(name) => {
+                write!(f, "field at {:?} isn't a number or a boolean", name.span)
+            }
+            NotAStruct(name) => write!(f, "type at {:?} isn't a struct", name.span),
+            UnknownField(name) => write!(f, "unknown field at {:?}", name.span),
+            MissingField { construction, .. } => {
+                write!(f, "struct at {:?} is missing a field", construction.span)
+            }
+            InvalidElementType(ty) => write!(
+                f,
+                "elements at {:?} aren't numbers, booleans or structs",
+                ty.span
+            ),
+            LenArguments(token) => {
+                write!(f, "`len` at {:?} takes a single array or slice", token.span)
+            }
+        }
+    }
+}
+
+impl Fail for ResolveError {}
+
+impl<'a> ResolveFunction<'a> {
+    fn new(
+        func: &'a ast::Function,
+        functions: &'a BTreeMap<IdentifierId, u32>,
+        types: &'a BTreeMap<IdentifierId, Type>,
+        globals: &'a [Global],
+        consts: &'a [Const],
+    ) -> ResolveFunction<'a> {
+        ResolveFunction {
+            func,
+            functions,
+            types,
+            globals,
+            consts,
+            generics: vec![],
+            params: vec![],
+            scopes: vec![func.mappings.clone()],
+            locals: vec![],
+            loop_depth: 0,
+        }
+    }
+
+    fn resolve(mut self) -> Result<Function, ResolveError> {
+        let func = self.func;
+
+        self.generics = self.resolve_generics()?;
+
+        if func.modifiers.export && !func.generics.is_empty() {
+            return Err(ResolveError::ExportedGeneric(func.name));
+        }
+
+        let mut symbols = vec![];
+
+        for (name, ty) in func.args.iter() {
+            symbols.push(name);
+            let ty = self.resolve_type(ty)?;
+            self.params.push(ty);
+        }
+
+        for (generic, param) in func.generics.iter().zip(&self.generics) {
+            let ty = Type::Param(param.clone());
+
+            if !self.params.iter().any(|param| param.node.mentions(&ty)) {
+                return Err(ResolveError::UnusedTypeParameter(generic.name));
+            }
+        }
+
+        let ret = self.resolve_type(&func.ret)?;
+
+        let body = self.resolve_block(&func.body)?;
+
+        Ok(Function {
+            name: func.name,
+            generics: self.generics,
+            params: self.params,
+            symbols,
+            locals: self.locals,
+            ret,
+            body,
+            modifiers: func.modifiers,
+        })
+    }
+
+    // Type parameters are numbered in the order they're declared
+    fn resolve_generics(&self) -> Result<Vec<TypeParam>, ResolveError> {
+        let mut names: BTreeMap<IdentifierId, Token> = BTreeMap::new();
+        let mut generics = vec![];
+
+        for (index, generic) in self.func.generics.iter().enumerate() {
+            if let Some(first) = names.insert(generic.name.to_ident(), generic.name) {
+                return Err(ResolveError::DuplicateTypeParameter {
+                    first,
+                    second: generic.name,
+                });
+            }
+
+            let bound = match &generic.bound {
+                None => None,
+                Some(bound) => Some(resolve_bound(bound)?),
+            };
+
+            generics.push(TypeParam {
+                index: index as u32,
+                bound,
+            });
+        }
+
+        Ok(generics)
+    }
+
+    // A name in a type refers to one of the function's type parameters, or
+    // else to a type declared in the module
+    fn resolve_type(&self, ty: &Spanned<Type>) -> Result<Spanned<Type>, ResolveError> {
+        let id = match &ty.node {
+            Type::Named(id) => *id,
+            Type::Tuple(fields) => {
+                let fields: Result<Vec<Type>, ResolveError> = fields
+                    .iter()
+                    .map(|field| Ok(self.resolve_type(&field.clone().copy_span(ty))?.node))
+                    .collect();
+
+                return Ok(Type::Tuple(fields?).copy_span(ty));
+            }
+            Type::Array(element, len) => {
+                let element = self.resolve_type(&(**element).clone().copy_span(ty))?;
+                return Ok(Type::Array(box element_of(element.node, ty)?, *len).copy_span(ty));
+            }
+            Type::Slice(element) => {
+                let element = self.resolve_type(&(**element).clone().copy_span(ty))?;
+                return Ok(Type::Slice(box element_of(element.node, ty)?).copy_span(ty));
+            }
+            _ => return Ok(ty.clone()),
+        };
+
+        let param = self
+            .func
+            .generics
+            .iter()
+            .position(|generic| generic.name.to_ident() == id);
+
+        if let Some(index) = param {
+            return Ok(Type::Param(self.generics[index].clone()).copy_span(ty));
+        }
+
+        match self.types.get(&id) {
+            Some(declared) => Ok(declared.clone().copy_span(ty)),
+            None => Err(ResolveError::UnknownType(Tok::Id(id).copy_span(ty))),
+        }
+    }
+
+    fn resolve_block(&mut self, block: &ast::Block) -> Result<Block, ResolveError> {
+        self.scopes.push(BTreeMap::new());
+
+        let mut expressions = vec![];
+
+        for expr in &block.expressions {
+            expressions.push(self.resolve_expression(expr)?);
+        }
+
+        self.scopes.pop();
+
+        Ok(Block {
+            expressions,
+            tail: block.tail,
+            span: block.span,
+        })
+    }
+
+    fn resolve_loop_body(&mut self, block: &ast::Block) -> Result<Block, ResolveError> {
+        self.loop_depth += 1;
+        let body = self.resolve_block(block);
+        self.loop_depth -= 1;
+
+        body
+    }
+
+    // Locals shadow consts and globals
+    fn lookup(&self, name: &Token) -> Result<Variable, ResolveError> {
+        let id = name.to_ident();
+
+        let local = self
+            .scopes
+            .iter()
+            .rev()
+            .filter_map(|scope| scope.get(&id))
+            .next();
+
+        if let Some(local) = local {
+            return Ok(Variable::Local(*local));
+        }
+
+        let constant = self
+            .consts
+            .iter()
+            .position(|constant| constant.name.to_ident() == id);
+
+        if let Some(index) = constant {
+            return Ok(Variable::Const(index as u32));
+        }
+
+        self.globals
+            .iter()
+            .position(|global| global.name.to_ident() == id)
+            .map(|global| Variable::Global(global as u32))
+            .ok_or_else(|| ResolveError::UnknownVariable(*name))
+    }
+
+    fn lookup_function(&self, name: &Token) -> Result<u32, ResolveError> {
+        self.functions
+            .get(&name.to_ident())
+            .cloned()
+            .ok_or_else(|| ResolveError::UnknownFunction(*name))
+    }
+
+    fn declare(&mut self, name: Token, ty: Option<Spanned<Type>>, mutable: bool) -> u32 {
+        let local = (self.func.args.list.len() + self.locals.len()) as u32;
+        self.locals.push(Local { name, ty, mutable });
+
+        self.scopes
+            .last_mut()
+            .expect("a local must be declared inside a block")
+            .insert(name.to_ident(), local);
+
+        local
+    }
+
+    // Returns the declaration of a parameter, `let` local or global along
+    // with whether it was declared `mut`
+    fn binding(&self, variable: Variable) -> (Token, bool) {
+        let params = &self.func.args.list;
+
+        let local = match variable {
+            Variable::Local(local) => local as usize,
+            Variable::Global(global) => {
+                let global = &self.globals[global as usize];
+                return (global.name, global.mutable);
+            }
+            Variable::Const(index) => return (self.consts[index as usize].name, false),
+        };
+
+        if local < params.len() {
+            (params[local].name, params[local].mutable)
+        } else {
+            let Local { name, mutable, .. } = &self.locals[local - params.len()];
+            (*name, *mutable)
+        }
+    }
+
+    // A field or an element can only be assigned through a `mut` binding,
+    // like the binding itself
+    fn require_mutable(&self, base: &Expression, assignment: Token) -> Result<(), ResolveError> {
+        let local = match base {
+            Expression::VariableAccess(local) => *local,
+            Expression::Field { base, .. } | Expression::Index { base, .. } => {
+                return self.require_mutable(base, assignment)
+            }
+            _ => return Ok(()),
+        };
+
+        match self.binding(Variable::Local(local)) {
+            (_, true) => Ok(()),
+            (binding, false) => Err(ResolveError::AssignToImmutable {
+                binding,
+                assignment,
+            }),
+        }
+    }
+
+    fn resolve_expression(&mut self, expr: &ast::Expression) -> Result<Expression, ResolveError> {
+        let expr = match expr {
+            ast::Expression::Const(constant) => Expression::Const(*constant),
+            ast::Expression::VariableAccess(id) => self.lookup(id)?.access(id),
+            ast::Expression::Call(name, args) => {
+                // `len` is a builtin, unless the module declares its own
+                let builtin = name.to_ident() == IdentifierId::from_str("len")
+                    && !self.functions.contains_key(&name.to_ident());
+
+                if builtin {
+                    return self.resolve_len(*name, args);
+                }
+
+                let function = self.lookup_function(name)?;
+
+                let args: Result<Vec<Expression>, ResolveError> = args
+                    .iter()
+                    .map(|arg| self.resolve_expression(arg))
+                    .collect();
+
+                Expression::Apply {
+                    function: box Expression::FunctionAccess(function),
+                    args: args?,
+                }
+            }
+            ast::Expression::Binary(operator, tok, box ast::BinaryExpression { lhs, rhs }) => {
+                let lhs = self.resolve_expression(lhs)?;
+                let rhs = self.resolve_expression(rhs)?;
+
+                Expression::Binary {
+                    operator: (*operator).copy_span(&tok),
+                    lhs: box lhs,
+                    rhs: box rhs,
+                }
+            }
+            ast::Expression::Compare(operator, tok, box ast::BinaryExpression { lhs, rhs }) => {
+                let lhs = self.resolve_expression(lhs)?;
+                let rhs = self.resolve_expression(rhs)?;
+
+                Expression::Compare {
+                    operator: (*operator).copy_span(&tok),
+                    lhs: box lhs,
+                    rhs: box rhs,
+                }
+            }
+            ast::Expression::Logical(operator, tok, box ast::BinaryExpression { lhs, rhs }) => {
+                let lhs = self.resolve_expression(lhs)?;
+                let rhs = self.resolve_expression(rhs)?;
+
+                Expression::Logical {
+                    operator: (*operator).copy_span(&tok),
+                    lhs: box lhs,
+                    rhs: box rhs,
+                }
+            }
+            ast::Expression::Unary(operator, tok, box expr) => {
+                let expr = self.resolve_expression(expr)?;
+
+                Expression::Unary {
+                    operator: (*operator).copy_span(&tok),
+                    expr: box expr,
+                }
+            }
+            ast::Expression::Convert(_tok, box expr, ty, conversion) => {
+                let expr = self.resolve_expression(expr)?;
+                let ty = self.resolve_type(ty)?;
+
+                let is_struct = match ty.node {
+                    Type::Struct(..) => true,
+                    _ => false,
+                };
+
+                if is_struct {
+                    Expression::StructAt {
+                        ty,
+                        address: box expr,
+                    }
+                } else {
+                    Expression::Convert {
+                        expr: box expr,
+                        ty,
+                        conversion: *conversion,
+                    }
+                }
+            }
+            ast::Expression::Tuple(_tok, fields) => {
+                let fields: Result<Vec<Expression>, ResolveError> = fields
+                    .iter()
+                    .map(|field| self.resolve_expression(field))
+                    .collect();
+
+                Expression::Tuple(fields?)
+            }
+            ast::Expression::Let(box ast::LetExpression {
+                name,
+                ty,
+                mutable,
+                value,
+            }) => {
+                // resolve the value first, so `let x = x + 1` refers to the
+                // outer `x`
+                let value = self.resolve_expression(value)?;
+                let ty = match ty {
+                    None => None,
+                    Some(ty) => Some(self.resolve_type(ty)?),
+                };
+                let local = self.declare(*name, ty, *mutable);
+
+                Expression::Let {
+                    local,
+                    value: box value,
+                }
+            }
+            ast::Expression::Destructure(
+                _tok,
+                box ast::DestructureExpression { bindings, value },
+            ) => {
+                let value = self.resolve_expression(value)?;
+                let locals = bindings
+                    .iter()
+                    .map(|binding| self.declare(binding.name, None, binding.mutable))
+                    .collect();
+
+                Expression::Destructure {
+                    locals,
+                    value: box value,
+                }
+            }
+            ast::Expression::Assign(
+                tok,
+                box ast::AssignExpression {
+                    name,
+                    operator,
+                    value,
+                },
+            ) => {
+                let variable = self.lookup(name)?;
+                let (binding, mutable) = self.binding(variable);
+
+                if !mutable {
+                    return Err(ResolveError::AssignToImmutable {
+                        binding,
+                        assignment: *tok,
+                    });
+                }
+
+                let value = self.resolve_expression(value)?;
+
+                // `x += y` is resolved as `x = x + y`
+                let value = match operator {
+                    None => value,
+                    Some(operator) => Expression::Binary {
+                        operator: (*operator).copy_span(&tok),
+                        lhs: box variable.access(name),
+                        rhs: box value,
+                    },
+                };
+
+                match variable {
+                    Variable::Local(local) => Expression::Assign {
+                        local,
+                        value: box value,
+                    },
+                    Variable::Global(global) => Expression::GlobalAssign {
+                        global,
+                        value: box value,
+                    },
+                    Variable::Const(..) => unreachable!("consts are never mutable"),
+                }
+            }
+            ast::Expression::If(
+                _tok,
+                box ast::IfExpression {
+                    condition,
+                    then_block,
+                    else_block,
+                },
+            ) => {
+                let condition = self.resolve_expression(condition)?;
+                let then_block = self.resolve_block(then_block)?;
+                let else_block = match else_block {
+                    None => None,
+                    Some(block) => Some(self.resolve_block(block)?),
+                };
+
+                Expression::If {
+                    condition: box condition,
+                    then_block,
+                    else_block,
+                }
+            }
+            ast::Expression::While(_tok, box ast::WhileExpression { condition, body }) => {
+                let condition = self.resolve_expression(condition)?;
+                let body = self.resolve_loop_body(body)?;
+
+                Expression::While {
+                    condition: box condition,
+                    body,
+                }
+            }
+            ast::Expression::Loop(_tok, box body) => Expression::Loop {
+                body: self.resolve_loop_body(body)?,
+            },
+            ast::Expression::Break(tok, value) => {
+                if self.loop_depth == 0 {
+                    return Err(ResolveError::OutsideOfLoop(*tok));
+                }
+
+                let value = match value {
+                    None => None,
+                    Some(box value) => Some(box self.resolve_expression(value)?),
+                };
+
+                Expression::Break { value }
+            }
+            ast::Expression::Continue(tok) => {
+                if self.loop_depth == 0 {
+                    return Err(ResolveError::OutsideOfLoop(*tok));
+                }
+
+                Expression::Continue
+            }
+            ast::Expression::Return(tok, value) => {
+                let value = match value {
+                    None => None,
+                    Some(box value) => Some(box self.resolve_expression(value)?),
+                };
+
+                Expression::Return {
+                    value,
+                    span: tok.span,
+                }
+            }
+            ast::Expression::Load(tok, box access) | ast::Expression::Store(tok, box access) => {
+                self.resolve_memory_access(*tok, access)?
+            }
+            ast::Expression::Memory(_tok, name, arg) => {
+                let id = name.to_ident();
+
+                match arg {
+                    None if id == IdentifierId::from_str("size") => Expression::MemorySize,
+                    Some(box pages) if id == IdentifierId::from_str("grow") => {
+                        Expression::MemoryGrow {
+                            pages: box self.resolve_expression(pages)?,
+                        }
+                    }
+                    _ => return Err(ResolveError::UnknownMemoryOperation(*name)),
+                }
+            }
+            ast::Expression::Construct(tok, box ast::ConstructExpression { name, fields }) => {
+                self.resolve_construct(*tok, *name, fields)?
+            }
+            ast::Expression::Field(_tok, box base, name) => Expression::Field {
+                base: box self.resolve_expression(base)?,
+                name: *name,
+            },
+            ast::Expression::AssignField(
+                tok,
+                box ast::AssignFieldExpression {
+                    base,
+                    field,
+                    operator,
+                    value,
+                },
+            ) => {
+                let base = self.resolve_expression(base)?;
+                self.require_mutable(&base, *tok)?;
+                let value = self.resolve_expression(value)?;
+
+                Expression::AssignField {
+                    base: box base,
+                    name: *field,
+                    operator: operator.map(|operator| operator.copy_span(&tok)),
+                    value: box value,
+                }
+            }
+            ast::Expression::Array(tok, elements) => {
+                let elements: Result<Vec<Expression>, ResolveError> = elements
+                    .iter()
+                    .map(|element| self.resolve_expression(element))
+                    .collect();
+
+                Expression::Array {
+                    elements: elements?,
+                    span: tok.span,
+                }
+            }
+            ast::Expression::RepeatArray(tok, box value, len) => Expression::RepeatArray {
+                value: box self.resolve_expression(value)?,
+                len: *len,
+                span: tok.span,
+            },
+            ast::Expression::Index(tok, box base, box index) => {
+                let base = self.resolve_expression(base)?;
+                let index = self.resolve_expression(index)?;
+
+                Expression::Index {
+                    base: box base,
+                    index: box index,
+                    span: tok.span,
+                }
+            }
+            ast::Expression::AssignIndex(
+                tok,
+                box ast::AssignIndexExpression {
+                    base,
+                    index,
+                    operator,
+                    value,
+                },
+            ) => {
+                let base = self.resolve_expression(base)?;
+                self.require_mutable(&base, *tok)?;
+                let index = self.resolve_expression(index)?;
+                let value = self.resolve_expression(value)?;
+
+                Expression::AssignIndex {
+                    base: box base,
+                    index: box index,
+                    span: tok.span,
+                    operator: operator.map(|operator| operator.copy_span(&tok)),
+                    value: box value,
+                }
+            }
+            ast::Expression::SliceOf(tok, box array) => Expression::SliceOf {
+                array: box self.resolve_expression(array)?,
+                span: tok.span,
+            },
+            ast::Expression::For(
+                tok,
+                box ast::ForExpression {
+                    binding,
+                    sequence,
+                    body,
+                },
+            ) => {
+                let sequence = self.resolve_expression(sequence)?;
+
+                // the binding is only in scope in the body
+                self.scopes.push(BTreeMap::new());
+                let local = self.declare(binding.name, None, binding.mutable);
+                let body = self.resolve_loop_body(body);
+                self.scopes.pop();
+
+                Expression::For {
+                    local,
+                    sequence: box sequence,
+                    body: body?,
+                    span: tok.span,
+                }
+            }
+        };
+
+        Ok(expr)
+    }
+
+    // `len(sequence)`, the number of elements in an array or a slice
+    fn resolve_len(
+        &mut self,
+        name: Token,
+        args: &[ast::Expression],
+    ) -> Result<Expression, ResolveError> {
+        if args.len() != 1 {
+            return Err(ResolveError::LenArguments(name));
+        }
+
+        Ok(Expression::Len {
+            sequence: box self.resolve_expression(&args[0])?,
+            span: name.span,
+        })
+    }
+
+    // Every field is initialized exactly once
+    fn resolve_construct(
+        &mut self,
+        tok: Token,
+        name: Token,
+        fields: &[ast::FieldInit],
+    ) -> Result<Expression, ResolveError> {
+        let ty = self.resolve_type(&Type::Named(name.to_ident()).copy_span(&name))?;
+
+        let declared = match &ty.node {
+            Type::Struct(declared) => (**declared).clone(),
+            _ => return Err(ResolveError::NotAStruct(name)),
+        };
+
+        let mut names: BTreeMap<IdentifierId, Token> = BTreeMap::new();
+        let mut values = vec![];
+
+        for init in fields {
+            let field = declared
+                .field(init.name.to_ident())
+                .ok_or_else(|| ResolveError::UnknownField(init.name))?;
+
+            if let Some(first) = names.insert(init.name.to_ident(), init.name) {
+                return Err(ResolveError::DuplicateField {
+                    first,
+                    second: init.name,
+                });
+            }
+
+            values.push((field, self.resolve_expression(&init.value)?));
+        }
+
+        let missing = declared
+            .fields
+            .iter()
+            .find(|field| !names.contains_key(&field.name));
+
+        if let Some(field) = missing {
+            return Err(ResolveError::MissingField {
+                construction: tok,
+                field: field.name,
+            });
+        }
+
+        Ok(Expression::Construct { ty, fields: values })
+    }
+
+    // A `load` if `access` has no value, and a `store` otherwise
+    fn resolve_memory_access(
+        &mut self,
+        tok: Token,
+        access: &ast::MemoryAccess,
+    ) -> Result<Expression, ResolveError> {
+        let ty = self.resolve_type(&access.ty)?;
+
+        let math = match ty.node.representation() {
+            Type::Math(math) => *math,
+            _ => return Err(ResolveError::NonNumericMemoryAccess(tok)),
+        };
+
+        let offset = match access.immediates.offset {
+            None => 0,
+            Some(offset) => memory_offset(offset)?,
+        };
+
+        // unaligned accesses are allowed, but can be slower
+        let align = match access.immediates.align {
+            None => math.size(),
+            Some(align) => alignment(align, math.size())?,
+        };
+
+        let address = box self.resolve_expression(&access.address)?;
+
+        let expr = match &access.value {
+            None => Expression::Load {
+                ty,
+                address,
+                offset,
+                align: align.trailing_zeros(),
+            },
+            Some(value) => Expression::Store {
+                ty,
+                address,
+                value: box self.resolve_expression(value)?,
+                offset,
+                align: align.trailing_zeros(),
+            },
+        };
+
+        Ok(expr)
+    }
+}
+
+// What a name in an expression refers to
+#[derive(Debug, Copy, Clone)]
+enum Variable {
+    Local(u32),
+    Global(u32),
+    Const(u32),
+}
+
+impl Variable {
+    fn access(self, name: &Token) -> Expression {
+        match self {
+            Variable::Local(local) => Expression::VariableAccess(local),
+            Variable::Global(global) => Expression::GlobalAccess(global),
+            Variable::Const(index) => Expression::ConstAccess {
+                index,
+                span: name.span,
+            },
+        }
+    }
+}
+
+// wasm32 addresses are 32 bits, so a memory holds at most 4GiB
+const MAX_PAGES: u64 = 65536;
+
+fn pages(tok: Token) -> Result<u32, ResolveError> {
+    match tok.node {
+        Tok::Int(pages, None) if pages <= MAX_PAGES => Ok(pages as u32),
+        _ => Err(ResolveError::InvalidMemoryLimits(tok)),
+    }
+}
+
+fn memory_offset(tok: Token) -> Result<u32, ResolveError> {
+    match tok.node {
+        Tok::Int(offset, None) if offset <= u64::from(u32::max_value()) => Ok(offset as u32),
+        _ => Err(ResolveError::InvalidOffset(tok)),
+    }
+}
+
+fn alignment(tok: Token, size: u32) -> Result<u32, ResolveError> {
+    match tok.node {
+        Tok::Int(align, None) if align.is_power_of_two() && align <= u64::from(size) => {
+            Ok(align as u32)
+        }
+        _ => Err(ResolveError::InvalidAlignment(tok)),
+    }
+}
+
+// `Num` allows any numeric type, `Int` and `Float` only integers or floats,
+// and `Signed` the types that can be negated
+fn resolve_bound(bound: &Token) -> Result<ConstrainedType, ResolveError> {
+    let id = bound.to_ident();
+
+    let bounds = [
+        ("Num", ConstrainedType::Numeric),
+        ("Int", ConstrainedType::Integer),
+        ("Float", ConstrainedType::Float),
+        ("Signed", ConstrainedType::Signed),
+    ];
+
+    bounds
+        .iter()
+        .find(|(name, _)| IdentifierId::from_str(name) == id)
+        .map(|(_, constrained)| constrained.clone())
+        .ok_or_else(|| ResolveError::UnknownBound(*bound))
+}
@@ -1,6 +1,8 @@
 use crate::compile::math::{MathOperator, MathType};
-use crate::ir::pos::{Spanned, SpannedItem};
+use crate::ir::annotated::ConstrainedType;
+use crate::ir::pos::{Span, Spanned, SpannedItem};
 use crate::ir::resolved::ResolveError;
+use crate::lexer::{IdentifierId, LiteralError, Tok, Token};
 use crate::InferType;
 use failure::Fail;
 use itertools::Itertools;
@@ -8,18 +10,27 @@ use nan_preserving_float::{F32, F64};
 use std::convert::From;
 use std::fmt;
 
-#[derive(Debug, Eq, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone)]
 pub enum CompileError {
     ResolveError(ResolveError),
     TypeError(TypeError),
+    ConstError(ConstError),
     UnifyError(InferType, InferType),
     LexError,
+    InvalidLiteral(Spanned<LiteralError>),
+    // the function allocates a struct, an array or a returned tuple, but the
+    // module's memory has a maximum, which the heap can't grow past
+    AllocationInBoundedMemory(Token),
     Unimplemented,
 }
 
 impl fmt::Display for CompileError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            CompileError::ResolveError(error) => write!(f, "{}", error),
+            CompileError::TypeError(error) => write!(f, "{}", error),
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
@@ -28,6 +39,102 @@ impl Fail for CompileError {}
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum TypeError {
     MismatchedBinary(MathOperator, Type, Type),
+    // `expression` produces `found`, but the signature declares `expected`
+    MismatchedReturn {
+        expected: Spanned<Type>,
+        found: InferType,
+        expression: Span,
+    },
+    // `var` would have to contain itself, like a function that takes
+    // itself as an argument
+    RecursiveType {
+        var: InferType,
+        ty: InferType,
+    },
+    // the literal doesn't fit in the type inference gave it
+    LiteralOutOfRange {
+        literal: Span,
+        ty: MathType,
+    },
+    // an array literal of tuples, arrays or slices, which can't be stored
+    // as elements
+    InvalidElementType {
+        array: Span,
+        ty: InferType,
+    },
+    // a field the struct doesn't declare, or a field access on a value
+    // that isn't a struct
+    UnknownField {
+        field: Span,
+    },
+    // a field access on a value whose type inference never found out
+    FieldOfUnknownType {
+        field: Span,
+    },
+    // indexing, `len`, `for` or `&` on a value that isn't an array or a slice
+    NotASequence {
+        expression: Span,
+    },
+    // `&slice`; only a whole array can be borrowed as a slice
+    NotAnArray {
+        expression: Span,
+    },
+    // like `FieldOfUnknownType`, but for an array or a slice
+    SequenceOfUnknownType {
+        expression: Span,
+    },
+}
+
+impl fmt::Display for TypeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use self::TypeError::*;
+
+        match self {
+            MismatchedBinary(operator, lhs, rhs) => {
+                write!(f, "{:?} of mismatched types {:?} and {:?}", operator, lhs, rhs)
+            }
+            MismatchedReturn {
+                expected,
+                found,
+                expression,
+            } => write!(
+                f,
+                "value at {:?} is {}, but {:?} is declared",
+                expression, found, expected.node
+            ),
+            RecursiveType { var, ty } => {
+                write!(f, "{} would have to contain itself in {}", var, ty)
+            }
+            LiteralOutOfRange { literal, ty } => {
+                write!(f, "literal at {:?} doesn't fit in {:?}", literal, ty)
+            }
+            InvalidElementType { array, ty } => write!(
+                f,
+                "elements at {:?} are {}, not numbers, booleans or structs",
+                array, ty
+            ),
+            UnknownField { field } => write!(f, "unknown field at {:?}", field),
+            FieldOfUnknownType { field } => {
+                write!(f, "field access at {:?} on a value of unknown type", field)
+            }
+            NotASequence { expression } => {
+                write!(f, "value at {:?} isn't an array or a slice", expression)
+            }
+            NotAnArray { expression } => write!(f, "value at {:?} isn't an array", expression),
+            SequenceOfUnknownType { expression } => {
+                write!(f, "array or slice at {:?} has an unknown type", expression)
+            }
+        }
+    }
+}
+
+// Evaluating a const, or a global's initial value, failed
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub enum ConstError {
+    // the result of `expression` doesn't fit in `ty`, or it shifts by at
+    // least the width of `ty`
+    Overflow { expression: Span, ty: MathType },
+    DivisionByZero { expression: Span },
 }
 
 impl From<ResolveError> for CompileError {
@@ -42,6 +149,12 @@ impl From<TypeError> for CompileError {
     }
 }
 
+impl From<ConstError> for CompileError {
+    fn from(error: ConstError) -> CompileError {
+        CompileError::ConstError(error)
+    }
+}
+
 #[derive(PartialEq, Copy, Clone)]
 pub enum ConstExpression {
     I32(i32),
@@ -72,6 +185,64 @@ pub enum Type {
     Function(Box<FunctionType>),
     Apply(Box<FunctionType>, Vec<Type>),
     Void,
+    // the type of expressions that never produce a value, like `return`
+    Never,
+    // a type written as a name, before name resolution decides what it
+    // refers to
+    Named(IdentifierId),
+    // a generic function's type parameter
+    Param(TypeParam),
+    // a type declared with `newtype`
+    Newtype(Box<Newtype>),
+    // `(i32, f64)`; tuples have at least two fields
+    Tuple(Vec<Type>),
+    // a type declared with `struct`, whose values are pointers to their
+    // fields in linear memory
+    Struct(Box<StructType>),
+    // `[f32; 64]`, a pointer to a fixed number of elements in linear memory
+    Array(Box<Type>, u32),
+    // `&[f32]`, a pointer to elements in linear memory along with how many
+    // there are
+    Slice(Box<Type>),
+}
+
+// The `index`th type parameter of a generic function. A bound limits the
+// types it can be instantiated with, and which operations it supports.
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash)]
+pub struct TypeParam {
+    pub index: u32,
+    pub bound: Option<ConstrainedType>,
+}
+
+// The `index`th type declared in a module, which is distinct from every
+// other type but represented like `repr`
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash)]
+pub struct Newtype {
+    pub index: u32,
+    pub repr: Type,
+}
+
+// The `index`th struct declared in a module. Its fields are numbers or
+// booleans, in the order they're declared.
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash)]
+pub struct StructType {
+    pub index: u32,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash)]
+pub struct Field {
+    pub name: IdentifierId,
+    pub ty: Type,
+}
+
+impl StructType {
+    crate fn field(&self, name: IdentifierId) -> Option<u32> {
+        self.fields
+            .iter()
+            .position(|field| field.name == name)
+            .map(|index| index as u32)
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, Ord, PartialOrd, Clone, Hash)]
@@ -94,6 +265,54 @@ impl Type {
         Type::Bool
     }
 
+    crate fn tuple(first: Spanned<Type>, rest: Vec<Spanned<Type>>) -> Type {
+        let mut fields = vec![first.node];
+        fields.extend(rest.into_iter().map(|field| field.node));
+
+        Type::Tuple(fields)
+    }
+
+    // `[element; len]`, where `len` is an integer literal without a suffix
+    crate fn array(element: Spanned<Type>, len: Token) -> Result<Type, CompileError> {
+        Ok(Type::Array(box element.node, array_length(len)?))
+    }
+
+    // The type of the elements of an array or a slice
+    crate fn element(&self) -> Option<&Type> {
+        match self {
+            Type::Array(element, _) | Type::Slice(element) => Some(&**element),
+            _ => None,
+        }
+    }
+
+    // Whether a value of this type is a pointer into linear memory, or
+    // holds one
+    crate fn points_into_memory(&self) -> bool {
+        match self {
+            Type::Struct(..) | Type::Array(..) | Type::Slice(..) => true,
+            Type::Tuple(fields) => fields.iter().any(|field| field.points_into_memory()),
+            _ => false,
+        }
+    }
+
+    // What a value of this type looks like at runtime, which only differs
+    // from the type itself for newtypes
+    pub fn representation(&self) -> &Type {
+        match self {
+            Type::Newtype(newtype) => newtype.repr.representation(),
+            other => other,
+        }
+    }
+
+    // Whether `ty` is this type or one of its fields
+    crate fn mentions(&self, ty: &Type) -> bool {
+        match self {
+            Type::Tuple(fields) => fields.iter().any(|field| field.mentions(ty)),
+            Type::Array(element, _) | Type::Slice(element) => element.mentions(ty),
+            other => other == ty,
+        }
+    }
+
     pub fn is_integer(&self) -> bool {
         match self {
             Type::Math(math) => match math {
@@ -141,6 +360,10 @@ impl Type {
     pub fn void() -> Spanned<Type> {
         Type::Void.synthetic("void")
     }
+
+    pub fn never() -> Spanned<Type> {
+        Type::Never.synthetic("never")
+    }
 }
 
 impl fmt::Debug for Type {
@@ -161,10 +384,38 @@ impl fmt::Debug for Type {
                 params.iter().map(|p| format!("{:?}", p)).join(", ")
             ),
             Type::Void => write!(f, "void"),
+            Type::Never => write!(f, "never"),
+            Type::Named(id) => write!(f, "{:?}", id),
+            Type::Newtype(box Newtype { index, repr }) => write!(f, "newtype{}({:?})", index, repr),
+            Type::Tuple(fields) => write!(
+                f,
+                "({})",
+                fields.iter().map(|field| format!("{:?}", field)).join(", ")
+            ),
+            Type::Struct(box StructType { index, .. }) => write!(f, "struct{}", index),
+            Type::Array(element, len) => write!(f, "[{:?}; {}]", element, len),
+            Type::Slice(element) => write!(f, "&[{:?}]", element),
+            Type::Param(TypeParam { index, bound: None }) => write!(f, "T{}", index),
+            Type::Param(TypeParam {
+                index,
+                bound: Some(bound),
+            }) => write!(f, "T{}: {:?}", index, bound),
         }
     }
 }
 
+// The length of an array, which is small enough that even an array of 8
+// byte elements fits in the 4GiB a wasm32 memory can address
+crate fn array_length(len: Token) -> Result<u32, CompileError> {
+    let error = match len.node {
+        Tok::Int(len, None) if len <= u64::from(u32::max_value() / 8) => return Ok(len as u32),
+        Tok::Int(_, None) => LiteralError::OutOfRange,
+        _ => LiteralError::InvalidSuffix,
+    };
+
+    Err(CompileError::InvalidLiteral(error.copy_span(&len)))
+}
+
 #[derive(Debug, Eq, PartialEq, Copy, Clone, new)]
 pub struct FunctionModifiers {
     #[new(default)]
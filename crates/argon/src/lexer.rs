@@ -1,7 +1,11 @@
 mod tokenize;
 
+#[cfg(test)]
+mod tests;
+
 pub use self::tokenize::Lexer;
-use crate::ir::pos::{Spanned, SpannedItem};
+use crate::ir::pos::Spanned;
+use crate::MathType;
 
 use nan_preserving_float::F64;
 use std::fmt;
@@ -9,6 +13,19 @@ use std::fmt;
 #[derive(Debug)]
 pub struct LexicalError {}
 
+// Why a numeric literal couldn't be turned into a token
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LiteralError {
+    // there are no digits, or a digit isn't valid in the literal's base
+    // (`0b102`)
+    InvalidDigit,
+    // the literal ends in something other than a numeric type, or in an
+    // integer type after a decimal point or exponent (`1.5i32`)
+    InvalidSuffix,
+    // the literal doesn't fit in 64 bits, or is a float too big to represent
+    OutOfRange,
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub struct IdentifierId(u64);
 
@@ -22,26 +39,78 @@ impl IdentifierId {
 pub enum Tok {
     Export,
     Def,
+    Let,
+    Mut,
+    If,
+    Else,
+    True,
+    False,
+    While,
+    Loop,
+    Break,
+    Continue,
+    Return,
+    As,
+    Saturating,
+    Type,
+    Newtype,
+    Memory,
+    Load,
+    Store,
+    Global,
+    Const,
+    Struct,
+    New,
+    For,
+    In,
     Arrow,
     OpenParen,
     CloseParen,
     OpenBrace,
     CloseBrace,
+    OpenBracket,
+    CloseBracket,
     Colon,
     Comma,
+    Semicolon,
+    Dot,
+    DotDot,
+    Equals,
     I32,
     I64,
     U32,
     U64,
     F32,
     F64,
+    Bool,
     Add,
     Sub,
     Mul,
     Div,
+    Rem,
+    BitAnd,
+    BitOr,
+    BitXor,
+    ShiftLeft,
+    ShiftRight,
+    AddAssign,
+    SubAssign,
+    MulAssign,
+    DivAssign,
+    EqualEqual,
+    NotEqual,
+    Less,
+    LessEqual,
+    Greater,
+    GreaterEqual,
+    Not,
+    Tilde,
+    AndAnd,
+    OrOr,
     Id(IdentifierId),
-    Int(i32),
-    Float(F64),
+    // the magnitude of an integer literal and its suffix, if it had one
+    Int(u64, Option<MathType>),
+    Float(F64, Option<MathType>),
     WS,
 }
 
@@ -55,26 +124,6 @@ impl Tok {
 pub type Token = Spanned<Tok>;
 
 impl Token {
-    crate fn to_spanned_i32(self) -> Spanned<i32> {
-        use self::Tok::*;
-
-        match self {
-            Spanned { node: Int(int), .. } => int.copy_span(&self),
-            _ => panic!("Expected Tok::Int, found {:?}",),
-        }
-    }
-
-    crate fn to_spanned_f64(self) -> Spanned<F64> {
-        use self::Tok::*;
-
-        match self {
-            Spanned {
-                node: Float(float), ..
-            } => float.copy_span(&self),
-            _ => panic!("Expected Tok::Float, found {:?}",),
-        }
-    }
-
     crate fn to_ident(&self) -> IdentifierId {
         use self::Tok::*;
 
@@ -0,0 +1,146 @@
+use super::{IdentifierId, Lexer, LiteralError, Tok};
+use crate::{CompileError, MathType};
+use nan_preserving_float::F64;
+
+fn lex(source: &str) -> Result<Vec<Tok>, CompileError> {
+    Lexer::new(source).map(|token| token.map(|(_, tok, _)| tok.node)).collect()
+}
+
+fn literal(source: &str) -> Tok {
+    let tokens = lex(source).unwrap();
+    assert_eq!(tokens.len(), 1, "{:?} should be one token, got {:?}", source, tokens);
+    tokens[0]
+}
+
+fn literal_error(source: &str) -> LiteralError {
+    match lex(source) {
+        Err(CompileError::InvalidLiteral(error)) => error.node,
+        other => panic!("expected {:?} to be an invalid literal, got {:?}", source, other),
+    }
+}
+
+fn float(float: f64, suffix: Option<MathType>) -> Tok {
+    Tok::Float(F64::from_float(float), suffix)
+}
+
+#[test]
+fn lexes_decimal_integers() {
+    assert_eq!(literal("0"), Tok::Int(0, None));
+    assert_eq!(literal("42"), Tok::Int(42, None));
+    assert_eq!(literal("1_000_000"), Tok::Int(1_000_000, None));
+}
+
+#[test]
+fn lexes_integers_up_to_64_bits() {
+    assert_eq!(literal("4294967296"), Tok::Int(4_294_967_296, None));
+    assert_eq!(
+        literal("18446744073709551615"),
+        Tok::Int(18_446_744_073_709_551_615, None)
+    );
+}
+
+#[test]
+fn lexes_integers_in_other_bases() {
+    assert_eq!(literal("0xff"), Tok::Int(255, None));
+    assert_eq!(literal("0xDEAD_beef"), Tok::Int(0xdead_beef, None));
+    assert_eq!(literal("0o17"), Tok::Int(15, None));
+    assert_eq!(literal("0b1010"), Tok::Int(10, None));
+}
+
+#[test]
+fn lexes_floats() {
+    assert_eq!(literal("1.5"), float(1.5, None));
+    assert_eq!(literal("1e3"), float(1e3, None));
+    assert_eq!(literal("1e-9"), float(1e-9, None));
+    assert_eq!(literal("2.5E+3"), float(2.5e3, None));
+    assert_eq!(literal("1_000.25"), float(1000.25, None));
+}
+
+#[test]
+fn lexes_suffixes() {
+    assert_eq!(literal("10u64"), Tok::Int(10, Some(MathType::U64)));
+    assert_eq!(literal("10_i32"), Tok::Int(10, Some(MathType::I32)));
+    assert_eq!(literal("0xffu32"), Tok::Int(255, Some(MathType::U32)));
+    assert_eq!(literal("1.5f32"), float(1.5, Some(MathType::F32)));
+    assert_eq!(literal("1e3f64"), float(1e3, Some(MathType::F64)));
+    assert_eq!(literal("1f32"), float(1.0, Some(MathType::F32)));
+}
+
+#[test]
+fn float_suffixes_are_hex_digits_in_hex_literals() {
+    assert_eq!(literal("0x1f32"), Tok::Int(0x1f32, None));
+}
+
+#[test]
+fn a_dot_without_a_digit_is_not_part_of_a_literal() {
+    assert_eq!(lex("1.").unwrap(), vec![Tok::Int(1, None), Tok::Dot]);
+    assert_eq!(lex("1 - 2").unwrap().len(), 3);
+}
+
+#[test]
+fn a_sign_only_continues_a_literal_after_an_exponent() {
+    assert_eq!(
+        lex("1-2").unwrap(),
+        vec![Tok::Int(1, None), Tok::Sub, Tok::Int(2, None)]
+    );
+    assert_eq!(
+        lex("0x1e-2").unwrap(),
+        vec![Tok::Int(0x1e, None), Tok::Sub, Tok::Int(2, None)]
+    );
+}
+
+#[test]
+fn rejects_literals_that_do_not_fit_in_64_bits() {
+    assert_eq!(literal_error("18446744073709551616"), LiteralError::OutOfRange);
+    assert_eq!(
+        literal_error("0x1_0000_0000_0000_0000"),
+        LiteralError::OutOfRange
+    );
+    assert_eq!(literal_error("1e400"), LiteralError::OutOfRange);
+    assert_eq!(literal_error("1e39f32"), LiteralError::OutOfRange);
+}
+
+#[test]
+fn rejects_digits_from_the_wrong_base() {
+    assert_eq!(literal_error("0b102"), LiteralError::InvalidDigit);
+    assert_eq!(literal_error("0o8"), LiteralError::InvalidDigit);
+    assert_eq!(literal_error("0x"), LiteralError::InvalidDigit);
+}
+
+#[test]
+fn rejects_unknown_suffixes() {
+    assert_eq!(literal_error("10u8"), LiteralError::InvalidSuffix);
+    assert_eq!(literal_error("1.5i32"), LiteralError::InvalidSuffix);
+    assert_eq!(literal_error("0xffz"), LiteralError::InvalidSuffix);
+}
+
+#[test]
+fn a_range_is_not_a_float() {
+    assert_eq!(
+        lex("1..16").unwrap(),
+        vec![Tok::Int(1, None), Tok::DotDot, Tok::Int(16, None)]
+    );
+    assert_eq!(
+        lex("memory.size").unwrap(),
+        vec![Tok::Memory, Tok::Dot, Tok::Id(IdentifierId::from_str("size"))]
+    );
+}
+
+#[test]
+fn lexes_array_types_and_for_loops() {
+    assert_eq!(
+        lex("&[f32; 4]").unwrap(),
+        vec![
+            Tok::BitAnd,
+            Tok::OpenBracket,
+            Tok::F32,
+            Tok::Semicolon,
+            Tok::Int(4, None),
+            Tok::CloseBracket
+        ]
+    );
+    assert_eq!(
+        lex("for x in index").unwrap(),
+        vec![Tok::For, Tok::id("x"), Tok::In, Tok::id("index")]
+    );
+}
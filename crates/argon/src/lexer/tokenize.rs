@@ -1,7 +1,7 @@
-use super::Tok;
+use super::{LiteralError, Tok};
 use crate::ir::pos::SpannedItem;
 use crate::lexer::Token;
-use crate::CompileError;
+use crate::{CompileError, MathType};
 use nan_preserving_float::F64;
 use unicode_xid::UnicodeXID;
 
@@ -10,23 +10,74 @@ lazy_static! {
         Matchers::keywords(&[
             ("export", Tok::Export),
             ("def", Tok::Def),
+            ("let", Tok::Let),
+            ("mut", Tok::Mut),
+            ("if", Tok::If),
+            ("else", Tok::Else),
+            ("true", Tok::True),
+            ("false", Tok::False),
+            ("while", Tok::While),
+            ("loop", Tok::Loop),
+            ("break", Tok::Break),
+            ("continue", Tok::Continue),
+            ("return", Tok::Return),
+            ("as", Tok::As),
+            ("saturating", Tok::Saturating),
+            ("type", Tok::Type),
+            ("newtype", Tok::Newtype),
+            ("memory", Tok::Memory),
+            ("load", Tok::Load),
+            ("store", Tok::Store),
+            ("global", Tok::Global),
+            ("const", Tok::Const),
+            ("struct", Tok::Struct),
+            ("new", Tok::New),
+            ("for", Tok::For),
+            ("in", Tok::In),
             ("i32", Tok::I32),
             ("i64", Tok::I64),
             ("u32", Tok::U32),
             ("u64", Tok::U64),
             ("f32", Tok::F32),
             ("f64", Tok::F64),
+            ("bool", Tok::Bool),
             ("->", Tok::Arrow),
             ("(", Tok::OpenParen),
             (")", Tok::CloseParen),
             ("{", Tok::OpenBrace),
             ("}", Tok::CloseBrace),
+            ("[", Tok::OpenBracket),
+            ("]", Tok::CloseBracket),
             (":", Tok::Colon),
+            ("+=", Tok::AddAssign),
+            ("-=", Tok::SubAssign),
+            ("*=", Tok::MulAssign),
+            ("/=", Tok::DivAssign),
             ("+", Tok::Add),
             ("-", Tok::Sub),
             ("*", Tok::Mul),
             ("/", Tok::Div),
+            ("%", Tok::Rem),
             (",", Tok::Comma),
+            (";", Tok::Semicolon),
+            ("..", Tok::DotDot),
+            (".", Tok::Dot),
+            ("==", Tok::EqualEqual),
+            ("!=", Tok::NotEqual),
+            ("<<", Tok::ShiftLeft),
+            (">>", Tok::ShiftRight),
+            ("<=", Tok::LessEqual),
+            (">=", Tok::GreaterEqual),
+            ("<", Tok::Less),
+            (">", Tok::Greater),
+            ("=", Tok::Equals),
+            ("!", Tok::Not),
+            ("~", Tok::Tilde),
+            ("&&", Tok::AndAnd),
+            ("||", Tok::OrOr),
+            ("&", Tok::BitAnd),
+            ("|", Tok::BitOr),
+            ("^", Tok::BitXor),
         ])
     };
 }
@@ -145,7 +196,11 @@ impl Iterator for Lexer<'input> {
 
                 LexerNext::EmitCurrent(size, tok, next_state) => {
                     let (start, token, end) = self.finalize_current(size, next_state);
-                    return Some(Ok((start, tok(token).spanned(start, end), end)));
+
+                    return Some(match tok(token) {
+                        Ok(tok) => Ok((start, tok.spanned(start, end), end)),
+                        Err(error) => Err(CompileError::InvalidLiteral(error.spanned(start, end))),
+                    });
                 }
 
                 LexerNext::FinalizeButDontEmitToken(size, next_state) => {
@@ -176,8 +231,9 @@ enum LexerState {
     Top,
     StartIdent,
     ContinueIdent,
-    Integer,
-    Decimal,
+    Number,
+    Exponent,
+    RadixNumber,
     Whitespace,
 }
 
@@ -185,7 +241,7 @@ enum LexerState {
 enum LexerNext<'a> {
     WholeToken(usize, Tok),
     FinalizeButDontEmitToken(usize, LexerState),
-    EmitCurrent(usize, fn(&'a str) -> Tok, LexerState),
+    EmitCurrent(usize, fn(&'a str) -> Result<Tok, LiteralError>, LexerState),
     Transition(usize, LexerState),
     Continue(usize),
     EOF,
@@ -200,7 +256,7 @@ impl LexerNext<'a> {
         LexerNext::Continue(1)
     }
 
-    fn emit(tok: fn(&str) -> Tok, next_state: LexerState) -> LexerNext<'a> {
+    fn emit(tok: fn(&str) -> Result<Tok, LiteralError>, next_state: LexerState) -> LexerNext<'a> {
         LexerNext::EmitCurrent(1, tok, next_state)
     }
 
@@ -227,7 +283,11 @@ impl<'a> LexerNext<'a> {
         LexerNext::WholeToken(size, t)
     }
 
-    fn emit_current(size: usize, tok: fn(&str) -> Tok, next_state: LexerState) -> LexerNext<'a> {
+    fn emit_current(
+        size: usize,
+        tok: fn(&str) -> Result<Tok, LiteralError>,
+        next_state: LexerState,
+    ) -> LexerNext<'a> {
         LexerNext::EmitCurrent(size, tok, next_state)
     }
 }
@@ -240,8 +300,10 @@ impl LexerState {
                 Some(c) => {
                     if let Some((tok, size)) = MATCHERS.match_keyword(rest) {
                         LexerNext::emit_token(tok, size)
+                    } else if is_radix_prefix(rest) {
+                        LexerNext::Transition(2, LexerState::RadixNumber)
                     } else if c.is_digit(10) {
-                        LexerNext::transition_to(LexerState::Integer).reconsume()
+                        LexerNext::transition_to(LexerState::Number).reconsume()
                     } else if c.is_whitespace() {
                         LexerNext::transition_to(LexerState::Whitespace)
                     } else if UnicodeXID::is_xid_start(c) {
@@ -285,26 +347,39 @@ impl LexerState {
                 }
             },
 
-            LexerState::Integer => match c {
-                None => LexerNext::emit_current(0, tk_int, LexerState::Top),
+            // The whole literal, including any suffix, is lexed as one token
+            // and taken apart by `tk_number`. A `.` only continues the
+            // literal if a digit follows it, and a `+` or `-` only if it's
+            // the sign of an exponent.
+            LexerState::Number => match c {
+                None => LexerNext::emit_current(0, tk_number, LexerState::Top),
                 Some(c) => {
-                    if c.is_digit(10) {
+                    if c == '.' && starts_with_digit(&rest[1..]) {
+                        LexerNext::consume()
+                    } else if (c == 'e' || c == 'E') && starts_with_sign(&rest[1..]) {
+                        LexerNext::transition_to(LexerState::Exponent)
+                    } else if UnicodeXID::is_xid_continue(c) {
                         LexerNext::consume()
-                    } else if c == '.' {
-                        LexerNext::transition_to(LexerState::Decimal)
                     } else {
-                        LexerNext::emit(tk_int, LexerState::Top).reconsume()
+                        LexerNext::emit(tk_number, LexerState::Top).reconsume()
                     }
                 }
             },
 
-            LexerState::Decimal => match c {
-                None => LexerNext::emit_current(0, tk_float, LexerState::Top),
+            LexerState::Exponent => match c {
+                Some('+') | Some('-') => LexerNext::transition_to(LexerState::Number),
+                other => unreachable!("an exponent must start with a sign, found {:?}", other),
+            },
+
+            // `0x`, `0o` and `0b` literals have no decimal point or exponent
+            // (`e` is a hex digit)
+            LexerState::RadixNumber => match c {
+                None => LexerNext::emit_current(0, tk_number, LexerState::Top),
                 Some(c) => {
-                    if c.is_digit(10) {
+                    if UnicodeXID::is_xid_continue(c) {
                         LexerNext::consume()
                     } else {
-                        LexerNext::emit(tk_float, LexerState::Top).reconsume()
+                        LexerNext::emit(tk_number, LexerState::Top).reconsume()
                     }
                 }
             },
@@ -314,16 +389,123 @@ impl LexerState {
     }
 }
 
-fn tk_int(token: &str) -> Tok {
-    Tok::Int(token.parse().unwrap())
+fn tk_id(token: &str) -> Result<Tok, LiteralError> {
+    Ok(Tok::id(token))
 }
 
-fn tk_id(token: &str) -> Tok {
-    Tok::id(token)
+const SUFFIXES: &[(&str, MathType)] = &[
+    ("i32", MathType::I32),
+    ("i64", MathType::I64),
+    ("u32", MathType::U32),
+    ("u64", MathType::U64),
+    ("f32", MathType::F32),
+    ("f64", MathType::F64),
+];
+
+fn tk_number(token: &str) -> Result<Tok, LiteralError> {
+    let (radix, rest) = match token.get(..2) {
+        Some("0x") => (16, &token[2..]),
+        Some("0o") => (8, &token[2..]),
+        Some("0b") => (2, &token[2..]),
+        _ => (10, token),
+    };
+
+    let (digits, suffix) = split_suffix(rest, radix);
+    let digits: String = digits.chars().filter(|c| *c != '_').collect();
+
+    let is_float = radix == 10 && digits.contains(|c: char| c == '.' || c == 'e' || c == 'E');
+
+    if is_float || suffix.map_or(false, is_float_type) {
+        float_literal(&digits, suffix)
+    } else {
+        integer_literal(&digits, radix, suffix)
+    }
 }
 
-fn tk_float(token: &str) -> Tok {
-    Tok::Float(F64::from_float(token.parse().unwrap()))
+// Splits a known type off the end of a literal. Float suffixes are never
+// split off hex literals, so `0x1f32` is the integer 0x1f32.
+fn split_suffix(literal: &str, radix: u32) -> (&str, Option<MathType>) {
+    for (name, ty) in SUFFIXES {
+        if radix == 16 && is_float_type(*ty) {
+            continue;
+        }
+
+        if literal.ends_with(name) {
+            return (&literal[..literal.len() - name.len()], Some(*ty));
+        }
+    }
+
+    (literal, None)
+}
+
+fn is_float_type(ty: MathType) -> bool {
+    ty == MathType::F32 || ty == MathType::F64
+}
+
+fn integer_literal(
+    digits: &str,
+    radix: u32,
+    suffix: Option<MathType>,
+) -> Result<Tok, LiteralError> {
+    if let Some(c) = digits.chars().find(|c| !c.is_digit(radix)) {
+        // a letter is the start of a suffix we don't know, anything else is
+        // a digit from the wrong base
+        return Err(if c.is_alphabetic() {
+            LiteralError::InvalidSuffix
+        } else {
+            LiteralError::InvalidDigit
+        });
+    }
+
+    if digits.is_empty() {
+        return Err(LiteralError::InvalidDigit);
+    }
+
+    // the digits are all valid, so the only way to fail is to overflow
+    match u64::from_str_radix(digits, radix) {
+        Ok(int) => Ok(Tok::Int(int, suffix)),
+        Err(_) => Err(LiteralError::OutOfRange),
+    }
+}
+
+fn float_literal(digits: &str, suffix: Option<MathType>) -> Result<Tok, LiteralError> {
+    if let Some(ty) = suffix {
+        if !is_float_type(ty) {
+            return Err(LiteralError::InvalidSuffix);
+        }
+    }
+
+    if digits
+        .chars()
+        .any(|c| c.is_alphabetic() && c != 'e' && c != 'E')
+    {
+        return Err(LiteralError::InvalidSuffix);
+    }
+
+    let float: f64 = digits.parse().map_err(|_| LiteralError::InvalidDigit)?;
+
+    let in_range = match suffix {
+        Some(MathType::F32) => (float as f32).is_finite(),
+        _ => float.is_finite(),
+    };
+
+    if in_range {
+        Ok(Tok::Float(F64::from_float(float), suffix))
+    } else {
+        Err(LiteralError::OutOfRange)
+    }
+}
+
+fn is_radix_prefix(rest: &str) -> bool {
+    rest.starts_with("0x") || rest.starts_with("0o") || rest.starts_with("0b")
+}
+
+fn starts_with_digit(rest: &str) -> bool {
+    rest.chars().next().map_or(false, |c| c.is_digit(10))
+}
+
+fn starts_with_sign(rest: &str) -> bool {
+    rest.starts_with('+') || rest.starts_with('-')
 }
 
 struct Matchers {
@@ -354,7 +536,7 @@ impl Keywords {
 
     fn match_keyword(&self, rest: &str) -> Option<(Tok, usize)> {
         for (string, token, len) in &self.tokens {
-            if rest.starts_with(string) {
+            if rest.starts_with(string) && !continues_ident(string, &rest[*len..]) {
                 return Some((*token, *len));
             }
         }
@@ -362,3 +544,14 @@ impl Keywords {
         None
     }
 }
+
+// A word-like keyword only matches if it isn't the start of a longer
+// identifier (`let` shouldn't match the beginning of `letter`)
+fn continues_ident(keyword: &str, after: &str) -> bool {
+    let is_word = keyword.chars().all(UnicodeXID::is_xid_continue);
+
+    match after.chars().next() {
+        Some(c) => is_word && UnicodeXID::is_xid_continue(c),
+        None => false,
+    }
+}
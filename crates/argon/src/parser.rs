@@ -1,6 +1,9 @@
 use crate::lexer::{Lexer, Token};
 use crate::{ast, CompileError, ModuleParser};
 
+#[cfg(test)]
+mod tests;
+
 pub type ParseError = lalrpop_util::ParseError<usize, Token, CompileError>;
 
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
@@ -25,6 +28,12 @@ pub fn location(error: ParseError) -> ErrorLocation {
             token: (location, ..),
             ..
         } => ErrorLocation::Byte(location),
+        lalrpop_util::ParseError::User {
+            error: CompileError::InvalidLiteral(literal),
+        } => match literal.span.start() {
+            Some(start) => ErrorLocation::Byte(start),
+            None => panic!("literals always come from the source, found {:?}", literal),
+        },
         lalrpop_util::ParseError::User { error } => panic!("{:?}", error),
     }
 }
@@ -0,0 +1,532 @@
+use super::parse;
+use crate::ast;
+use crate::lexer::{IdentifierId, Tok};
+use crate::{Conversion, Type};
+
+const NAMES: &[&str] = &["a", "b", "c", "d"];
+
+fn expression(source: &str) -> ast::Expression {
+    let source = format!(
+        "def f(a: i32, b: i32, c: i32, d: i32) -> i32 {{ {} }}",
+        source
+    );
+
+    let mut module = parse(&source).unwrap();
+    let mut function = module.funcs.remove(0);
+
+    function.body.expressions.pop().unwrap()
+}
+
+fn parses(source: &str) -> bool {
+    let source = format!("def f(a: i32, b: i32, c: i32) -> bool {{ {} }}", source);
+    parse(&source).is_ok()
+}
+
+// Renders an expression with every operator explicitly grouped, so
+// `a - b - c` becomes `((a - b) - c)`
+fn grouped(expr: &ast::Expression) -> String {
+    match expr {
+        ast::Expression::VariableAccess(id) => {
+            let id = id.to_ident();
+
+            NAMES
+                .iter()
+                .find(|name| IdentifierId::from_str(name) == id)
+                .expect("tests only use the names in NAMES")
+                .to_string()
+        }
+        ast::Expression::Const(ast::ConstExpression::Integer(int, _)) => int.node.to_string(),
+        ast::Expression::Binary(op, _, box ast::BinaryExpression { lhs, rhs }) => {
+            format!("({} {:?} {})", grouped(lhs), op, grouped(rhs))
+        }
+        ast::Expression::Compare(op, _, box ast::BinaryExpression { lhs, rhs }) => {
+            format!("({} {:?} {})", grouped(lhs), op, grouped(rhs))
+        }
+        ast::Expression::Logical(op, _, box ast::BinaryExpression { lhs, rhs }) => {
+            format!("({} {:?} {})", grouped(lhs), op, grouped(rhs))
+        }
+        ast::Expression::Unary(op, _, expr) => format!("({:?}{})", op, grouped(expr)),
+        ast::Expression::Convert(_, expr, ty, _) => format!("({} as {:?})", grouped(expr), ty.node),
+        ast::Expression::Field(_, base, field) => {
+            let field = grouped(&ast::Expression::VariableAccess(*field));
+            format!("({}.{})", grouped(base), field)
+        }
+        ast::Expression::Index(_, base, index) => {
+            format!("({}[{}])", grouped(base), grouped(index))
+        }
+        other => panic!("unexpected expression in a precedence test: {:?}", other),
+    }
+}
+
+fn assert_groups(source: &str, expected: &str) {
+    assert_eq!(grouped(&expression(source)), expected, "parsing {:?}", source);
+}
+
+#[test]
+fn multiplicative_operators_are_left_associative() {
+    assert_groups("a * b / c % d", "(((a * b) / c) % d)");
+    assert_groups("a % b * c", "((a % b) * c)");
+}
+
+#[test]
+fn additive_operators_are_left_associative() {
+    assert_groups("a - b - c", "((a - b) - c)");
+    assert_groups("a - b + c", "((a - b) + c)");
+}
+
+#[test]
+fn multiplicative_operators_bind_tighter_than_additive() {
+    assert_groups("a + b * c", "(a + (b * c))");
+    assert_groups("a % b - c", "((a % b) - c)");
+}
+
+#[test]
+fn shifts_are_left_associative() {
+    assert_groups("a << b >> c", "((a << b) >> c)");
+}
+
+#[test]
+fn additive_operators_bind_tighter_than_shifts() {
+    assert_groups("a << b + c", "(a << (b + c))");
+    assert_groups("a - b >> c", "((a - b) >> c)");
+}
+
+#[test]
+fn bitwise_and_is_left_associative() {
+    assert_groups("a & b & c", "((a & b) & c)");
+}
+
+#[test]
+fn shifts_bind_tighter_than_bitwise_and() {
+    assert_groups("a & b << c", "(a & (b << c))");
+}
+
+#[test]
+fn bitwise_xor_is_left_associative() {
+    assert_groups("a ^ b ^ c", "((a ^ b) ^ c)");
+}
+
+#[test]
+fn bitwise_and_binds_tighter_than_xor() {
+    assert_groups("a ^ b & c", "(a ^ (b & c))");
+}
+
+#[test]
+fn bitwise_or_is_left_associative() {
+    assert_groups("a | b | c", "((a | b) | c)");
+}
+
+#[test]
+fn bitwise_xor_binds_tighter_than_or() {
+    assert_groups("a | b ^ c", "(a | (b ^ c))");
+}
+
+#[test]
+fn comparisons_are_not_associative() {
+    assert!(!parses("a < b < c"));
+    assert!(!parses("a == b == c"));
+    assert!(!parses("a != b <= c"));
+}
+
+#[test]
+fn bitwise_operators_bind_tighter_than_comparisons() {
+    assert_groups("a | b == c", "((a | b) == c)");
+    assert_groups("a & 1 != 0", "((a & 1) != 0)");
+}
+
+#[test]
+fn logical_and_is_left_associative() {
+    assert_groups("a && b && c", "((a && b) && c)");
+}
+
+#[test]
+fn comparisons_bind_tighter_than_logical_and() {
+    assert_groups("a == b && c < d", "((a == b) && (c < d))");
+}
+
+#[test]
+fn logical_or_is_left_associative() {
+    assert_groups("a || b || c", "((a || b) || c)");
+}
+
+#[test]
+fn logical_and_binds_tighter_than_logical_or() {
+    assert_groups("a || b && c", "(a || (b && c))");
+    assert_groups("a && b || c", "((a && b) || c)");
+}
+
+#[test]
+fn unary_operators_bind_tighter_than_binary_operators() {
+    assert_groups("!a && b", "((!a) && b)");
+    assert_groups("!!a", "(!(!a))");
+}
+
+#[test]
+fn negation_binds_tighter_than_binary_operators() {
+    assert_groups("-a * b", "((-a) * b)");
+    assert_groups("a - -b", "(a - (-b))");
+    assert_groups("~a & b", "((~a) & b)");
+    assert_groups("-~a", "(-(~a))");
+}
+
+#[test]
+fn negative_literals_are_folded() {
+    assert_groups("-1", "-1");
+    assert_groups("a * -2", "(a * -2)");
+    assert_groups("- -3", "3");
+    assert_groups("-(4)", "-4");
+}
+
+#[test]
+fn conversions_bind_tighter_than_binary_operators() {
+    assert_groups("a as i64 * b", "((a as i64) * b)");
+    assert_groups("a + b as u32", "(a + (b as u32))");
+}
+
+#[test]
+fn unary_operators_bind_tighter_than_conversions() {
+    assert_groups("-a as u32", "((-a) as u32)");
+}
+
+#[test]
+fn conversions_are_left_associative() {
+    assert_groups("a as u32 as i64", "((a as u32) as i64)");
+}
+
+#[test]
+fn conversions_only_target_numeric_types() {
+    assert!(!parses("a as bool"));
+}
+
+#[test]
+fn parentheses_override_precedence() {
+    assert_groups("(a + b) * c", "((a + b) * c)");
+    assert_groups("a - (b - c)", "(a - (b - c))");
+    assert_groups("!(a && b)", "(!(a && b))");
+    assert_groups("((a))", "a");
+}
+
+#[test]
+fn parenthesized_comparisons_can_be_compared() {
+    assert_groups("(a < b) == c", "((a < b) == c)");
+}
+
+fn body(source: &str) -> ast::Block {
+    let source = format!("def f(a: i32) {}", source);
+
+    let mut module = parse(&source).unwrap();
+    module.funcs.remove(0).body
+}
+
+#[test]
+fn the_last_expression_is_the_tail() {
+    let block = body("{ a; a + 1 }");
+
+    assert_eq!(block.expressions.len(), 2);
+    assert!(block.tail);
+}
+
+#[test]
+fn a_trailing_semicolon_ends_a_statement() {
+    let block = body("{ a; a + 1; }");
+
+    assert_eq!(block.expressions.len(), 2);
+    assert!(!block.tail);
+}
+
+#[test]
+fn a_trailing_let_is_not_a_tail() {
+    assert!(!body("{ let b = a; }").tail);
+    assert!(!body("{}").tail);
+}
+
+#[test]
+fn a_trailing_block_like_expression_is_the_tail() {
+    assert!(body("{ a; if a > 0 { a } else { 0 } }").tail);
+}
+
+#[test]
+fn conversions_can_saturate() {
+    match expression("a as saturating i32") {
+        ast::Expression::Convert(_, _, ty, conversion) => {
+            assert_eq!(ty.node, Type::i32());
+            assert_eq!(conversion, Conversion::Saturating);
+        }
+        other => panic!("expected a conversion, found {:?}", other),
+    }
+}
+
+fn function(source: &str) -> ast::Function {
+    parse(source).unwrap().funcs.remove(0)
+}
+
+fn id(name: &str) -> IdentifierId {
+    IdentifierId::from_str(name)
+}
+
+#[test]
+fn parses_type_parameters_and_bounds() {
+    let function = function("def add<T: Num, U>(a: T, b: U) -> T { a }");
+
+    let generics: Vec<_> = function
+        .generics
+        .iter()
+        .map(|generic| (generic.name.to_ident(), generic.bound.map(|bound| bound.to_ident())))
+        .collect();
+
+    assert_eq!(generics, vec![(id("T"), Some(id("Num"))), (id("U"), None)]);
+    assert_eq!(function.args.list[1].ty.node, Type::Named(id("U")));
+    assert_eq!(function.ret.node, Type::Named(id("T")));
+}
+
+#[test]
+fn functions_are_not_generic_by_default() {
+    assert!(function("def f(a: i32) {}").generics.is_empty());
+}
+
+#[test]
+fn type_parameter_lists_cant_be_empty() {
+    assert!(parse("def f<>(a: i32) {}").is_err());
+}
+
+#[test]
+fn parses_type_declarations_between_functions() {
+    let module = parse("type Meters = f64; def f(a: Meters) {} newtype UserId = u32;").unwrap();
+
+    let types: Vec<_> = module
+        .types
+        .iter()
+        .map(|decl| (decl.name.to_ident(), decl.ty.node.clone(), decl.nominal))
+        .collect();
+
+    assert_eq!(
+        types,
+        vec![(id("Meters"), Type::f64(), false), (id("UserId"), Type::u32(), true)]
+    );
+    assert_eq!(module.funcs.len(), 1);
+}
+
+#[test]
+fn conversions_can_target_named_types() {
+    match expression("a as UserId") {
+        ast::Expression::Convert(_, _, ty, _) => assert_eq!(ty.node, Type::Named(id("UserId"))),
+        other => panic!("expected a conversion, found {:?}", other),
+    }
+}
+
+#[test]
+fn parses_tuple_types() {
+    let function = function("def f(a: (i32, (f64, bool))) -> (u32, u64) { a }");
+
+    assert_eq!(
+        function.args.list[0].ty.node,
+        Type::Tuple(vec![Type::i32(), Type::Tuple(vec![Type::f64(), Type::bool()])])
+    );
+    assert_eq!(function.ret.node, Type::Tuple(vec![Type::u32(), Type::u64()]));
+}
+
+#[test]
+fn a_parenthesized_expression_is_not_a_tuple() {
+    assert_groups("(a + b) * c", "((a + b) * c)");
+
+    match expression("(a, b + c)") {
+        ast::Expression::Tuple(_, fields) => {
+            let fields: Vec<_> = fields.iter().map(grouped).collect();
+            assert_eq!(fields, vec!["a", "(b + c)"]);
+        }
+        other => panic!("expected a tuple, found {:?}", other),
+    }
+}
+
+#[test]
+fn parses_destructuring_lets() {
+    let mut function = function("def f(a: i32) { let (b, mut c) = (a, a); }");
+
+    match function.body.expressions.remove(0) {
+        ast::Expression::Destructure(_, box ast::DestructureExpression { bindings, .. }) => {
+            let bindings: Vec<_> = bindings
+                .iter()
+                .map(|binding| (binding.name.to_ident(), binding.mutable))
+                .collect();
+
+            assert_eq!(bindings, vec![(id("b"), false), (id("c"), true)]);
+        }
+        other => panic!("expected a destructuring let, found {:?}", other),
+    }
+}
+
+#[test]
+fn destructuring_needs_at_least_two_bindings() {
+    assert!(!parses("let (a) = b; true"));
+}
+
+#[test]
+fn parses_memory_declarations() {
+    let module = parse("memory 1; export memory 2..16;").unwrap();
+
+    let memories: Vec<_> = module
+        .memories
+        .iter()
+        .map(|memory| (memory.min.node, memory.max.map(|max| max.node), memory.export))
+        .collect();
+
+    assert_eq!(
+        memories,
+        vec![
+            (Tok::Int(1, None), None, false),
+            (Tok::Int(2, None), Some(Tok::Int(16, None)), true),
+        ]
+    );
+}
+
+#[test]
+fn parses_loads_and_stores() {
+    match expression("load<f64>(a, 8, 4)") {
+        ast::Expression::Load(_, box access) => {
+            assert_eq!(access.ty.node, Type::f64());
+            assert!(access.value.is_none());
+            assert_eq!(access.immediates.offset.map(|tok| tok.node), Some(Tok::Int(8, None)));
+            assert_eq!(access.immediates.align.map(|tok| tok.node), Some(Tok::Int(4, None)));
+        }
+        other => panic!("expected a load, found {:?}", other),
+    }
+
+    match expression("store<i32>(a, b + c)") {
+        ast::Expression::Store(_, box access) => {
+            assert_eq!(access.value.as_ref().map(grouped), Some("(b + c)".to_string()));
+            assert_eq!(access.immediates, ast::MemoryImmediates::new(None, None));
+        }
+        other => panic!("expected a store, found {:?}", other),
+    }
+}
+
+#[test]
+fn parses_memory_operations() {
+    assert!(parses("memory.size(); memory.grow(a); true"));
+    assert!(!parses("memory.size; true"));
+}
+
+#[test]
+fn parses_global_declarations() {
+    let module = parse("global mut counter: i64 = 0; export global limit: u32 = 10;").unwrap();
+
+    let globals: Vec<_> = module
+        .globals
+        .iter()
+        .map(|global| {
+            let ty = global.ty.node.clone();
+            (global.name.to_ident(), ty, global.mutable, global.export)
+        })
+        .collect();
+
+    assert_eq!(
+        globals,
+        vec![
+            (id("counter"), Type::i64(), true, false),
+            (id("limit"), Type::u32(), false, true),
+        ]
+    );
+}
+
+#[test]
+fn parses_const_declarations() {
+    let module = parse("const LIMIT: u32 = 1 << 20; const HALF: f64 = 0.5;").unwrap();
+
+    let consts: Vec<_> = module
+        .consts
+        .iter()
+        .map(|constant| (constant.name.to_ident(), constant.ty.node.clone()))
+        .collect();
+
+    assert_eq!(consts, vec![(id("LIMIT"), Type::u32()), (id("HALF"), Type::f64())]);
+}
+
+#[test]
+fn parses_struct_declarations() {
+    let module = parse("struct Point { x: f64, y: f64, } struct Empty {}").unwrap();
+
+    let structs: Vec<_> = module
+        .structs
+        .iter()
+        .map(|declaration| {
+            let fields: Vec<_> = declaration
+                .fields
+                .iter()
+                .map(|field| (field.name.to_ident(), field.ty.node.clone()))
+                .collect();
+
+            (declaration.name.to_ident(), fields)
+        })
+        .collect();
+
+    assert_eq!(
+        structs,
+        vec![
+            (id("Point"), vec![(id("x"), Type::f64()), (id("y"), Type::f64())]),
+            (id("Empty"), vec![]),
+        ]
+    );
+}
+
+#[test]
+fn field_access_binds_tighter_than_unary_operators() {
+    assert_groups("-a.b.c as i64", "((-((a.b).c)) as i64)");
+    assert_groups("a.b * c.d", "((a.b) * (c.d))");
+}
+
+#[test]
+fn parses_field_assignments() {
+    assert!(parses("a.b = c; a.b.c += 1; true"));
+    assert!(parses("let p = new Point { x: a, y: b + c }; p.x == 1"));
+    assert!(!parses("new Point { x: a, x }; true"));
+}
+
+#[test]
+fn parses_array_and_slice_types() {
+    let function = function("def f(a: [f32; 4], b: &[Sample]) -> [[i32; 2]; 3] { a }");
+
+    assert_eq!(function.args.list[0].ty.node, Type::Array(box Type::f32(), 4));
+    assert_eq!(
+        function.args.list[1].ty.node,
+        Type::Slice(box Type::Named(id("Sample")))
+    );
+    assert_eq!(
+        function.ret.node,
+        Type::Array(box Type::Array(box Type::i32(), 2), 3)
+    );
+}
+
+#[test]
+fn array_lengths_are_unsuffixed_integers() {
+    assert!(parse("def f(a: [f32; 4]) {}").is_ok());
+    assert!(parse("def f(a: [f32; 4u32]) {}").is_err());
+    assert!(parse("def f(a: [f32; 1.0]) {}").is_err());
+    assert!(parse("def f(a: [f32; 4294967296]) {}").is_err());
+}
+
+#[test]
+fn indexing_binds_like_field_access() {
+    assert_groups("-a[b].c", "(-((a[b]).c))");
+    assert_groups("a[b + c] * d[a][b]", "((a[(b + c)]) * ((d[a])[b]))");
+}
+
+#[test]
+fn parses_array_literals_and_index_assignments() {
+    assert!(parses("let x = [a, b, c,]; x[0] = a; x[1] += b; true"));
+    assert!(parses("let x = [0; 16]; let s = &x; len(s) == 16"));
+    assert!(!parses("let x = []; true"));
+    assert!(!parses("let x = [0; a]; true"));
+}
+
+#[test]
+fn parses_for_loops() {
+    let mut function = function("def f(a: &[i32]) { for mut x in a { x += 1; } }");
+
+    match function.body.expressions.remove(0) {
+        ast::Expression::For(_, box ast::ForExpression { binding, .. }) => {
+            assert_eq!(binding.name.to_ident(), id("x"));
+            assert!(binding.mutable);
+        }
+        other => panic!("expected a for loop, found {:?}", other),
+    }
+}
@@ -5,6 +5,12 @@ pub trait AsRuntimeValue {
     fn as_runtime_value(&self) -> RuntimeValue;
 }
 
+impl AsRuntimeValue for bool {
+    fn as_runtime_value(&self) -> RuntimeValue {
+        RuntimeValue::I32(*self as i32)
+    }
+}
+
 impl AsRuntimeValue for u32 {
     fn as_runtime_value(&self) -> RuntimeValue {
         RuntimeValue::I32(*self as i32)
@@ -1,24 +1,174 @@
 use parity_wasm::elements;
 use wasmi;
-use argon::ParseError;
+use argon::{Compilation, CompileOptions, ParseError};
+use nan_preserving_float::{F32, F64};
+use std::sync::atomic::{AtomicUsize, Ordering, ATOMIC_USIZE_INIT};
+use std::{env, fs, process};
 
 crate mod coerce;
 
+static SOURCES: AtomicUsize = ATOMIC_USIZE_INIT;
+
+// Compiles `source` the way a package build does, from a file on disk
+pub fn compile(source: &str) -> Result<elements::Module, failure::Error> {
+    compile_with_options(source, CompileOptions::default())
+}
+
+pub fn compile_with_options(
+    source: &str,
+    options: CompileOptions,
+) -> Result<elements::Module, failure::Error> {
+    let name = format!(
+        "argon-test-{}-{}.argon",
+        process::id(),
+        SOURCES.fetch_add(1, Ordering::SeqCst)
+    );
+    let path = env::temp_dir().join(name);
+    fs::write(&path, source)?;
+
+    let mut compilation = Compilation::with_options(options);
+    let key = compilation.add(&path)?;
+    let module = compilation.get(&key).map(|module| module.unwrap().into_owned());
+
+    fs::remove_file(&path)?;
+    module
+}
+
+// Compiles `source`, which is expected to be rejected with an `E`, and
+// returns the error
+pub fn rejected<E: failure::Fail>(source: &str) -> E {
+    init_logger();
+
+    let error = match compile(source) {
+        Ok(_) => panic!("expected {:?} to be rejected", source),
+        Err(error) => error,
+    };
+
+    match error.downcast::<E>() {
+        Ok(error) => error,
+        Err(error) => panic!("{:?} was rejected with an unexpected error: {}", source, error),
+    }
+}
+
+// Asserts that a value matches a pattern, like the kind of error
+// `rejected` returns
+#[allow(unused_macros)]
+macro_rules! assert_matches {
+    ($value:expr, $pattern:pat) => {
+        match $value {
+            $pattern => {}
+            other => panic!("expected {}, found {:?}", stringify!($pattern), other),
+        }
+    };
+}
+
+fn instantiate(module: &elements::Module) -> wasmi::ModuleRef {
+    let module = wasmi::Module::from_parity_wasm_module(module.clone()).unwrap();
+
+    wasmi::ModuleInstance::new(&module, &wasmi::ImportsBuilder::default())
+        .unwrap()
+        .run_start(&mut wasmi::NopExternals)
+        .unwrap()
+}
+
 pub fn invoke(
     module: &elements::Module,
     name: &str,
     args: &[wasmi::RuntimeValue],
 ) -> Option<wasmi::RuntimeValue> {
-    let module = wasmi::Module::from_parity_wasm_module(module.clone()).unwrap();
-    let main = wasmi::ModuleInstance::new(&module, &wasmi::ImportsBuilder::default())
+    let main = instantiate(module);
+
+    main.invoke_export(name, args, &mut wasmi::NopExternals)
         .unwrap()
-        .run_start(&mut wasmi::NopExternals)
-        .unwrap();
+}
+
+// Where `invoke_with_out_pointer` asks for the tuple to be stored; any
+// address aligned to 8 bytes works
+const OUT_POINTER: u32 = 64;
+
+// Calls an export that returns a tuple, which it stores through a pointer
+// passed as the first argument, and reads the fields back. `like` gives the
+// type of each field.
+pub fn invoke_with_out_pointer(
+    module: &elements::Module,
+    name: &str,
+    args: &[wasmi::RuntimeValue],
+    like: &[wasmi::RuntimeValue],
+) -> Vec<wasmi::RuntimeValue> {
+    let main = instantiate(module);
+
+    let mut call = vec![wasmi::RuntimeValue::I32(OUT_POINTER as i32)];
+    call.extend_from_slice(args);
+
+    let result = main.invoke_export(name, &call, &mut wasmi::NopExternals).unwrap();
+    assert_eq!(result, None, "a tuple isn't returned as a value");
+
+    let memory = main
+        .export_by_name("memory")
+        .and_then(|export| export.as_memory().cloned())
+        .expect("a module returning tuples exports its memory");
+
+    let mut offset = OUT_POINTER;
+
+    like.iter()
+        .map(|field| {
+            let size = match field {
+                wasmi::RuntimeValue::I32(..) | wasmi::RuntimeValue::F32(..) => 4,
+                wasmi::RuntimeValue::I64(..) | wasmi::RuntimeValue::F64(..) => 8,
+            };
+
+            // each field is aligned to its size
+            offset = (offset + size - 1) / size * size;
+
+            let bytes = memory.get(offset, size as usize).unwrap();
+            let bits = bytes.iter().rev().fold(0u64, |bits, byte| bits << 8 | *byte as u64);
+
+            offset += size;
+
+            match field {
+                wasmi::RuntimeValue::I32(..) => wasmi::RuntimeValue::I32(bits as i32),
+                wasmi::RuntimeValue::I64(..) => wasmi::RuntimeValue::I64(bits as i64),
+                wasmi::RuntimeValue::F32(..) => {
+                    wasmi::RuntimeValue::F32(F32::from_bits(bits as u32))
+                }
+                wasmi::RuntimeValue::F64(..) => wasmi::RuntimeValue::F64(F64::from_bits(bits)),
+            }
+        })
+        .collect()
+}
+
+// Copies `bytes` into the module's exported memory at `address` before
+// calling the export, like a host passing a buffer to a kernel
+pub fn invoke_with_buffer(
+    module: &elements::Module,
+    name: &str,
+    address: u32,
+    bytes: &[u8],
+    args: &[wasmi::RuntimeValue],
+) -> Option<wasmi::RuntimeValue> {
+    let main = instantiate(module);
+
+    let memory = main
+        .export_by_name("memory")
+        .and_then(|export| export.as_memory().cloned())
+        .expect("the module exports its memory");
+
+    memory.set(address, bytes).unwrap();
 
     main.invoke_export(name, args, &mut wasmi::NopExternals)
         .unwrap()
 }
 
+// The value of an exported global right after instantiating the module
+pub fn exported_global(module: &elements::Module, name: &str) -> wasmi::RuntimeValue {
+    let main = instantiate(module);
+
+    main.export_by_name(name)
+        .and_then(|export| export.as_global().cloned())
+        .expect("the module exports the global")
+        .get()
+}
+
 #[allow(unused, unused_must_use)]
 crate fn init_logger() {
     pretty_env_logger::try_init();
@@ -45,22 +195,15 @@ macro_rules! syntax {
     ($mod_name:ident { module $syntax:expr; invoke $name:ident ($($args:expr),*) = $expected:tt }) => {
         #[allow(unused)]
         mod $mod_name {
-            use argon::test_helpers::*;
-
             use crate::invoke;
-            use argon::{ast, compile_module, parser};
             use argon::ir::Type;
             use nan_preserving_float::{F32, F64};
 
-            fn module() -> ast::Module<'static> {
-                println!("{}", $syntax);
-                parser::parse($syntax).unwrap()
-            }
-
             #[test]
             fn test_compile() {
                 crate::init_logger();
-                let module = compile_module(&module());
+                println!("{}", $syntax);
+                let module = crate::compile($syntax);
                 let value = invoke(&module.unwrap(), stringify!($name), &runtime_values!($($args),*));
 
                 assert_eq!(value, return_type!($expected));
@@ -74,11 +217,11 @@ macro_rules! syntax {
             use argon::test_helpers::*;
 
             use crate::invoke;
-            use argon::{ast, compile_module, parser};
+            use argon::{ast, parser};
             use argon::ir::Type;
             use nan_preserving_float::{F32, F64};
 
-            fn module() -> ast::Module<'static> {
+            fn module() -> ast::Module {
                 println!("{}", $syntax);
                 parser::parse($syntax).unwrap()
             }
@@ -87,7 +230,7 @@ macro_rules! syntax {
             fn test_parse() {
                 crate::init_logger();
 
-                let expected: Vec<ast::Module<'static>> = 
+                let expected: Vec<ast::Module> =
                     AstBuilder::new().module(|$module_builder| $parse).done();
 
                 assert_eq!(vec![module()], expected);
@@ -97,7 +240,7 @@ macro_rules! syntax {
             fn test_compile() {
                 crate::init_logger();
 
-                let module = compile_module(&module());
+                let module = crate::compile($syntax);
                 let value = invoke(&module.unwrap(), stringify!($name), &runtime_values!($($args),*));
 
                 assert_eq!(value, return_type!($expected));
@@ -106,16 +249,90 @@ macro_rules! syntax {
     }
 }
 
+// An exported function returning a tuple, whose fields are read back out
+// of memory
+// A module returning tuples as multiple values, which wasmi can't run, so
+// the type section is compared byte for byte instead
+macro_rules! multi_value {
+    ($mod_name:ident { module $syntax:expr; types = [$($byte:expr),*] }) => {
+        multi_value!($mod_name { module $syntax; types = [$($byte),*]; memory = false });
+    };
+
+    ($mod_name:ident { module $syntax:expr; types = [$($byte:expr),*]; memory = $memory:expr }) => {
+        #[allow(unused)]
+        mod $mod_name {
+            use argon::{CompileOptions, TupleReturns};
+            use parity_wasm::elements;
+
+            #[test]
+            fn test_compile() {
+                crate::init_logger();
+                println!("{}", $syntax);
+
+                let options = CompileOptions {
+                    tuple_returns: TupleReturns::MultiValue,
+                    ..CompileOptions::default()
+                };
+                let module = crate::compile_with_options($syntax, options).unwrap();
+
+                let types = module
+                    .sections()
+                    .iter()
+                    .filter_map(|section| match section {
+                        elements::Section::Unparsed { id: 1, payload } => Some(payload.clone()),
+                        _ => None,
+                    })
+                    .next()
+                    .expect("the type section is encoded by hand");
+
+                let expected: Vec<u8> = vec![$($byte),*];
+                assert_eq!(types, expected);
+
+                // nothing is returned through memory, though a module that
+                // takes slices still shares one
+                assert_eq!(module.memory_section().is_some(), $memory);
+                assert!(module.global_section().is_none());
+            }
+        }
+    };
+}
+
+macro_rules! tuple_return {
+    ($mod_name:ident { module $syntax:expr; invoke $name:ident ($($args:expr),*) = ($($expected:expr),*) }) => {
+        #[allow(unused)]
+        mod $mod_name {
+            use crate::invoke_with_out_pointer;
+
+            #[test]
+            fn test_compile() {
+                crate::init_logger();
+                println!("{}", $syntax);
+
+                let module = crate::compile($syntax);
+                let expected = runtime_values!($($expected),*);
+                let fields = invoke_with_out_pointer(
+                    &module.unwrap(),
+                    stringify!($name),
+                    &runtime_values!($($args),*),
+                    &expected,
+                );
+
+                assert_eq!(fields, expected);
+            }
+        }
+    };
+}
+
 macro_rules! compile_error {
     ($mod_name:ident { module $syntax:expr; error at $pos:expr }) => {
         #[allow(unused)]
         mod $mod_name {
-            use argon::{ast, compile_module, parser};
+            use argon::{ast, parser};
             use argon::ir::{Type};
             use nan_preserving_float::{F32, F64};
             use $crate::ParseError;
 
-            fn module() -> Result<ast::Module<'static>, ParseError<'static>> {
+            fn module() -> Result<ast::Module, ParseError> {
                 parser::parse($syntax)
             }
 
@@ -131,7 +348,20 @@ macro_rules! compile_error {
                 assert_eq!(location, parser::ErrorLocation::Byte($pos));
             }
         }
-    }
+    };
+
+    // parses, but is rejected later in compilation
+    ($mod_name:ident { module $syntax:expr; error }) => {
+        mod $mod_name {
+            #[test]
+            fn test() {
+                crate::init_logger();
+
+                argon::parser::parse($syntax).expect("Expected to parse");
+                assert!(crate::compile($syntax).is_err(), "Expected compile error");
+            }
+        }
+    };
 }
 
 syntax!(noop_function {
@@ -170,7 +400,9 @@ compile_error!(missing_type {
 macro_rules! binary_operator {
     ($name:ident | $lhs:tt $operator:tt $rhs:tt = $result:tt) => {
         macro_rules! for_type {
-            ($ty:ident) => {
+            // float literals need a decimal point, so `$fraction` turns `50`
+            // into `50.0` for float types
+            ($ty:ident, $fraction:expr) => {
                 mod $ty {
                     syntax!(vars {
                         module concat!("export def ", stringify!($name), "(x: ", stringify!($ty), ", y: ", stringify!($ty), ") -> ", stringify!($ty), " { x ", stringify!($operator), " y }");
@@ -179,7 +411,7 @@ macro_rules! binary_operator {
                     });
 
                     syntax!(const_right {
-                        module concat!("export def ", stringify!($name), "(x: ", stringify!($ty), ") -> ", stringify!($ty), " { x ", stringify!($operator), " ", stringify!($rhs), " }");
+                        module concat!("export def ", stringify!($name), "(x: ", stringify!($ty), ") -> ", stringify!($ty), " { x ", stringify!($operator), " ", stringify!($rhs), $fraction, " }");
 
                         invoke $name(($lhs as $ty)) = { ($lhs as $ty) $operator ($rhs as $ty) }
                     });
@@ -188,12 +420,12 @@ macro_rules! binary_operator {
         }
 
         mod $name {
-            for_type!(i32);
-            for_type!(i64);
-            for_type!(u32);
-            for_type!(u64);
-            for_type!(f32);
-            for_type!(f64);
+            for_type!(i32, "");
+            for_type!(i64, "");
+            for_type!(u32, "");
+            for_type!(u64, "");
+            for_type!(f32, ".0");
+            for_type!(f64, ".0");
         }
     }
 }
@@ -225,4 +457,1280 @@ syntax!(middle_times {
     module "export def multi(x: i32, y: i32) -> i32 { x + 5 * y }";
 
     invoke multi(5i32, 10i32) = 55i32
-});
\ No newline at end of file
+});
+syntax!(let_binding {
+    module "export def double(x: i32) -> i32 { let y = x + x; y }";
+
+    invoke double(5i32) = 10i32
+});
+
+syntax!(typed_let_binding {
+    module "export def add_ten(x: i64) -> i64 { let ten: i64 = 10; x + ten }";
+
+    invoke add_ten(5i64) = 15i64
+});
+
+syntax!(shadowed_let_binding {
+    module "export def shadow(x: f64) -> f64 { let x = x * 2.0; let x = x + 1.0; x }";
+
+    invoke shadow(5.0f64) = 11.0f64
+});
+
+syntax!(mutable_let_binding {
+    module "export def reassign(x: i32) -> i32 { let mut y = x; y = y * 3; y }";
+
+    invoke reassign(5i32) = 15i32
+});
+
+syntax!(compound_assignment {
+    module "export def compound(mut x: i64) -> i64 { x += 10; x -= 2; x *= 3; x /= 4; x }";
+
+    invoke compound(6i64) = 10i64
+});
+
+syntax!(if_else {
+    module "export def choose(c: bool, x: i32, y: i32) -> i32 { if c { x } else { y } }";
+
+    invoke choose(0i32, 5i32, 10i32) = 10i32
+});
+
+syntax!(else_if {
+    module "export def pick(a: bool, b: bool, x: f64) -> f64 { if a { x } else if b { x * 2.0 } else { 3.5 } }";
+
+    invoke pick(0i32, 1i32, 1.25f64) = 2.5f64
+});
+
+syntax!(if_without_else {
+    module "export def bump(c: bool, mut x: i32) -> i32 { if c { x += 1 } x }";
+
+    invoke bump(1i32, 5i32) = 6i32
+});
+
+syntax!(signed_less_than {
+    module "export def lt(x: i32, y: i32) -> bool { x < y }";
+
+    invoke lt(-1i32, 1i32) = 1i32
+});
+
+syntax!(unsigned_less_than {
+    module "export def lt(x: u32, y: u32) -> bool { x < y }";
+
+    invoke lt(4294967295u32, 1u32) = 0i32
+});
+
+syntax!(float_comparison {
+    module "export def max(x: f64, y: f64) -> f64 { if x >= y { x } else { y } }";
+
+    invoke max(1.5f64, 2.5f64) = 2.5f64
+});
+
+syntax!(integer_equality {
+    module "export def eq(x: i64, y: i64) -> bool { x == y + 1 }";
+
+    invoke eq(5i64, 4i64) = 1i32
+});
+
+syntax!(boolean_literal {
+    module "export def yes() -> bool { true }";
+
+    invoke yes() = 1i32
+});
+
+syntax!(logical_not {
+    module "export def not(x: bool) -> bool { !x }";
+
+    invoke not(0i32) = 1i32
+});
+
+syntax!(logical_and {
+    module "export def between(x: i32, lo: i32, hi: i32) -> bool { x >= lo && x < hi }";
+
+    invoke between(5i32, 0i32, 10i32) = 1i32
+});
+
+syntax!(logical_or_short_circuits {
+    module "export def safe(x: i32, y: i32) -> bool { y == 0 || x / y > 1 }";
+
+    invoke safe(10i32, 0i32) = 1i32
+});
+
+syntax!(while_loop {
+    module "export def sum(n: i32) -> i32 { let mut total = 0; let mut i = 0; while i < n { i += 1; total += i } total }";
+
+    invoke sum(10i32) = 55i32
+});
+
+syntax!(loop_with_break_value {
+    module "export def first_square_over(n: i64) -> i64 { let mut i = 0; let square = loop { i += 1; if i * i > n { break i * i } }; square }";
+
+    invoke first_square_over(50i64) = 64i64
+});
+
+syntax!(while_with_continue {
+    module "export def skip_two(n: u32) -> u32 { let mut total = 0; let mut i = 0; while i < n { i += 1; if i == 2 { continue } total += i } total }";
+
+    invoke skip_two(4u32) = 8u32
+});
+
+syntax!(nested_loop_break {
+    module "export def grid(n: i32) -> i32 { let mut count: i32 = 0; let mut i = 0; while i < n { i += 1; let mut j = 0; loop { j += 1; if j > i { break } count += 1 } } count }";
+
+    invoke grid(3i32) = 6i32
+});
+
+syntax!(function_call {
+    module "export def sum_of_squares(x: i32, y: i32) -> i32 { square(x) + square(y) } def square(x: i32) -> i32 { x * x }";
+
+    invoke sum_of_squares(3i32, 4i32) = 25i32
+});
+
+syntax!(recursive_call {
+    module "export def factorial(n: i64) -> i64 { if n <= 1 { 1 } else { n * factorial(n - 1) } }";
+
+    invoke factorial(10i64) = 3628800i64
+});
+
+syntax!(signed_remainder {
+    module "export def rem(x: i32, y: i32) -> i32 { x % y }";
+
+    invoke rem(7i32, -2i32) = 1i32
+});
+
+syntax!(unsigned_remainder {
+    module "export def rem(x: u32, y: u32) -> u32 { x % y }";
+
+    invoke rem(7u32, 2u32) = 1u32
+});
+
+syntax!(bitwise_operators {
+    module "export def mask(x: i64, y: i64) -> i64 { x & y | x ^ y }";
+
+    invoke mask(12i64, 10i64) = 14i64
+});
+
+syntax!(shift_left {
+    module "export def shl(x: u64, y: u64) -> u64 { x << y }";
+
+    invoke shl(1u64, 40u64) = 1099511627776u64
+});
+
+syntax!(signed_shift_right {
+    module "export def shr(x: i32, y: i32) -> i32 { x >> y }";
+
+    invoke shr(-16i32, 2i32) = (-4i32)
+});
+
+syntax!(unsigned_shift_right {
+    module "export def shr(x: u32, y: u32) -> u32 { x >> y }";
+
+    invoke shr(4294967280u32, 2u32) = 1073741820u32
+});
+
+syntax!(parenthesized_expression {
+    module "export def scale(a: i32, b: i32, c: i32) -> i32 { (a + b) * c }";
+
+    invoke scale(1i32, 2i32, 3i32) = 9i32
+});
+
+syntax!(integer_negation {
+    module "export def neg(x: i64) -> i64 { -x }";
+
+    invoke neg(5i64) = (-5i64)
+});
+
+syntax!(float_negation {
+    module "export def neg(x: f32) -> f32 { -x * 2.0 }";
+
+    invoke neg(1.5f32) = (-3.0f32)
+});
+
+syntax!(bitwise_not {
+    module "export def flip(x: u32) -> u32 { ~x }";
+
+    invoke flip(0u32) = 4294967295u32
+});
+
+syntax!(negative_literal {
+    module "export def offset(x: i32) -> i32 { x + -10 }";
+
+    invoke offset(3i32) = (-7i32)
+});
+
+syntax!(hex_literal {
+    module "export def mask(x: u32) -> u32 { x & 0xffff_0000 }";
+
+    invoke mask(0x1234_5678u32) = 0x1234_0000u32
+});
+
+syntax!(binary_and_octal_literals {
+    module "export def sum() -> i32 { 0b1010 + 0o17 }";
+
+    invoke sum() = 25i32
+});
+
+syntax!(wide_integer_literal {
+    module "export def big() -> u64 { 18_446_744_073_709_551_615 }";
+
+    invoke big() = 18_446_744_073_709_551_615u64
+});
+
+syntax!(exponent_literal {
+    module "export def scale(x: f64) -> f64 { x * 1.5e3 }";
+
+    invoke scale(2f64) = 3000f64
+});
+
+syntax!(suffixed_literal {
+    module "export def wide() -> u64 { let x = 10u64; x }";
+
+    invoke wide() = 10u64
+});
+
+syntax!(float_suffixed_integer {
+    module "export def half() -> f32 { let x = 1f32; x / 2.0 }";
+
+    invoke half() = 0.5f32
+});
+
+compile_error!(out_of_range_literal {
+    module "export def big() -> u64 { 18446744073709551616 }";
+
+    error at 26
+});
+
+compile_error!(unknown_literal_suffix {
+    module "export def small() -> i32 { 10u8 }";
+
+    error at 28
+});
+
+syntax!(unsuffixed_literals_default_to_i32 {
+    module "export def wraps() -> bool { 2147483647 + 1 < 0 }";
+
+    invoke wraps() = 1i32
+});
+
+syntax!(unsuffixed_literals_take_the_inferred_type {
+    module "export def big() -> u32 { let x = 4294967295; x }";
+
+    invoke big() = 4294967295u32
+});
+
+// Converts `$value` to `$from`, and then checks that `x as $to` and
+// `x as saturating $to` agree with Rust's `as` for each of the `$to` types
+macro_rules! conversion {
+    ($value:tt: $from:ident => $($to:ident),*) => {
+        mod $from {
+            $(
+                syntax!($to {
+                    module concat!("export def convert(x: ", stringify!($from), ") -> ", stringify!($to), " { x as ", stringify!($to), " }");
+
+                    invoke convert(($value as $from)) = { ($value as $from) as $to }
+                });
+            )*
+
+            mod saturating {
+                $(
+                    syntax!($to {
+                        module concat!("export def convert(x: ", stringify!($from), ") -> ", stringify!($to), " { x as saturating ", stringify!($to), " }");
+
+                        invoke convert(($value as $from)) = { ($value as $from) as $to }
+                    });
+                )*
+            }
+        }
+    }
+}
+
+// Converts `$value` to `$from`, and then checks that `x as saturating $to`
+// is `$expected`, for floats that don't fit in `$to`
+macro_rules! saturation {
+    ($value:tt: $from:ident => $($to:ident = $expected:tt),*) => {
+        mod $from {
+            $(
+                syntax!($to {
+                    module concat!("export def convert(x: ", stringify!($from), ") -> ", stringify!($to), " { x as saturating ", stringify!($to), " }");
+
+                    invoke convert(($value as $from)) = { $expected as $to }
+                });
+            )*
+        }
+    }
+}
+
+mod convert_positive {
+    conversion!(100: i32 => i32, i64, u32, u64, f32, f64);
+    conversion!(100: i64 => i32, i64, u32, u64, f32, f64);
+    conversion!(100: u32 => i32, i64, u32, u64, f32, f64);
+    conversion!(100: u64 => i32, i64, u32, u64, f32, f64);
+    conversion!(100: f32 => i32, i64, u32, u64, f32, f64);
+    conversion!(100: f64 => i32, i64, u32, u64, f32, f64);
+}
+
+// signed integers sign extend; negative floats can't become unsigned
+// integers without trapping
+mod convert_negative {
+    conversion!((-3): i32 => i32, i64, u32, u64, f32, f64);
+    conversion!((-3): i64 => i32, i64, u32, u64, f32, f64);
+    conversion!((-3): f32 => i32, i64, f32, f64);
+    conversion!((-3): f64 => i32, i64, f32, f64);
+}
+
+// unsigned integers zero extend, and wrap when narrowed
+mod convert_large {
+    conversion!(4_000_000_000u64: u32 => i32, i64, u32, u64, f32, f64);
+    conversion!(4_000_000_000u64: u64 => i32, i64, u32, u64, f32, f64);
+    conversion!(4_000_000_000u64: i64 => i32, i64, u32, u64, f32, f64);
+}
+
+// floats truncate towards zero
+mod convert_fraction {
+    conversion!(2.75: f32 => i32, i64, u32, u64, f32, f64);
+    conversion!(2.75: f64 => i32, i64, u32, u64, f32, f64);
+}
+
+mod convert_negative_fraction {
+    conversion!((-2.75): f64 => i32, i64);
+}
+
+// NaN becomes 0
+mod saturate_nan {
+    saturation!((::std::f64::NAN): f32 => i32 = 0, i64 = 0, u32 = 0, u64 = 0);
+    saturation!((::std::f64::NAN): f64 => i32 = 0, i64 = 0, u32 = 0, u64 = 0);
+}
+
+mod saturate_large {
+    saturation!(1e20: f32 => i32 = (::std::i32::MAX), i64 = (::std::i64::MAX), u32 = (::std::u32::MAX), u64 = (::std::u64::MAX));
+    saturation!(1e20: f64 => i32 = (::std::i32::MAX), i64 = (::std::i64::MAX), u32 = (::std::u32::MAX), u64 = (::std::u64::MAX));
+}
+
+mod saturate_negative {
+    saturation!((-1e20): f32 => i32 = (::std::i32::MIN), i64 = (::std::i64::MIN), u32 = 0, u64 = 0);
+    saturation!((-1e20): f64 => i32 = (::std::i32::MIN), i64 = (::std::i64::MIN), u32 = 0, u64 = 0);
+}
+
+mod saturate_infinity {
+    saturation!((::std::f64::INFINITY): f32 => i32 = (::std::i32::MAX), u64 = (::std::u64::MAX));
+    saturation!((::std::f64::NEG_INFINITY): f64 => i64 = (::std::i64::MIN), u32 = 0);
+}
+
+// 2^32 is the first value past `u32`'s range, and fits in the 64 bit types
+mod saturate_at_the_bounds {
+    saturation!(4294967296.0: f64 => i32 = (::std::i32::MAX), i64 = 4294967296i64, u32 = (::std::u32::MAX), u64 = 4294967296u64);
+}
+
+// a negative fraction truncates to 0, which fits in the unsigned types
+mod saturate_negative_fraction {
+    saturation!((-0.5): f64 => i32 = 0, u32 = 0, u64 = 0);
+}
+
+syntax!(conversion_in_an_expression {
+    module "export def average(total: u64, count: u32) -> f64 { total as f64 / count as f64 }";
+
+    invoke average(10u64, 4u32) = 2.5f64
+});
+
+syntax!(conversion_of_a_literal {
+    module "export def big() -> i64 { let x = 1 as i64; x << 40 }";
+
+    invoke big() = 1_099_511_627_776i64
+});
+
+syntax!(early_return {
+    module "export def clamp(x: i32) -> i32 { if x < 0 { return 0 } x }";
+
+    invoke clamp(-5i32) = 0i32
+});
+
+syntax!(multiple_returns {
+    module "export def sign(x: i64) -> i64 { if x < 0 { return -1 } if x > 0 { return 1 } x }";
+
+    invoke sign(-9i64) = (-1i64)
+});
+
+syntax!(return_from_loop {
+    module "export def first_multiple(n: u32, of: u32) -> u32 { let mut i = n; loop { if i % of == 0 { return i } i += 1 } }";
+
+    invoke first_multiple(10u32, 7u32) = 14u32
+});
+
+syntax!(literal_return_value {
+    module "export def answer() -> i64 { 42 }";
+
+    invoke answer() = 42i64
+});
+
+syntax!(void_function_discards_value {
+    module "export def discard(x: i32) { x + 1 }";
+
+    invoke discard(1i32) = void
+});
+
+syntax!(discarded_statement_values {
+    module "export def twice(x: i32) -> i32 { double(x); x + 1; double(x) } def double(x: i32) -> i32 { x * 2 }";
+
+    invoke twice(4i32) = 8i32
+});
+
+syntax!(trailing_semicolon_is_void {
+    module "export def ignore(x: i64) { x * 2; }";
+
+    invoke ignore(4i64) = void
+});
+
+syntax!(generic_identity {
+    module "export def pick(x: i64, flag: bool) -> i64 { if id(flag) { id(x) } else { 0 } } def id<T>(x: T) -> T { x }";
+
+    invoke pick(7i64, true) = 7i64
+});
+
+syntax!(bounded_generic_at_an_integer_type {
+    module "export def sum(x: i32, y: i32) -> i32 { add(x, y) } def add<T: Num>(a: T, b: T) -> T { a + b }";
+
+    invoke sum(2i32, 3i32) = 5i32
+});
+
+syntax!(bounded_generic_at_a_float_type {
+    module "export def sum(x: f64) -> f64 { add(x, 0.5) + add(1, 2) as f64 } def add<T: Num>(a: T, b: T) -> T { a + b }";
+
+    invoke sum(2.0f64) = 5.5f64
+});
+
+syntax!(generic_calling_a_generic {
+    module "export def run(x: u64) -> u64 { twice(x) } def twice<T: Num>(x: T) -> T { add(x, x) } def add<T: Num>(a: T, b: T) -> T { a + b }";
+
+    invoke run(21u64) = 42u64
+});
+
+syntax!(type_alias {
+    module "type Meters = f64; export def double(x: Meters) -> f64 { let y: Meters = x * 2.0; y }";
+
+    invoke double(1.5f64) = 3.0f64
+});
+
+syntax!(newtype_arithmetic {
+    module "newtype UserId = u32; export def next(id: UserId) -> UserId { id + 1 }";
+
+    invoke next(41u32) = 42u32
+});
+
+syntax!(newtype_conversion {
+    module "newtype UserId = u32; newtype Count = i64; export def count(id: u32) -> i64 { let id = id as UserId; (id as Count + 1) as i64 }";
+
+    invoke count(9u32) = 10i64
+});
+
+tuple_return!(exported_tuple {
+    module "export def pair(x: i32) -> (i32, f64) { (x + 1, 2.5) }";
+
+    invoke pair(1i32) = (2i32, 2.5f64)
+});
+
+tuple_return!(tuple_fields_are_aligned {
+    module "export def mixed(x: i32) -> (i32, i64, f32, (f64, bool)) { (x, 2, 3.5, (4.5, true)) }";
+
+    invoke mixed(1i32) = (1i32, 2i64, 3.5f32, 4.5f64, true)
+});
+
+tuple_return!(tuple_through_if {
+    module "export def order(a: u32, b: u32) -> (u32, u32) { if a < b { (a, b) } else { (b, a) } }";
+
+    invoke order(5u32, 3u32) = (3u32, 5u32)
+});
+
+tuple_return!(early_tuple_return {
+    module "export def clamp(x: f32) -> (f32, bool) { if x > 1.0 { return (1.0, true); } (x, false) }";
+
+    invoke clamp(2.5f32) = (1.0f32, true)
+});
+
+tuple_return!(tuple_out_of_a_loop {
+    module "export def count(n: i64) -> (i64, i64) { let mut i = 0; loop { if i == n { break (i, i * 2); } i += 1; } }";
+
+    invoke count(3i64) = (3i64, 6i64)
+});
+
+// the section's size, one type, and `[i32] -> [i32, f64]`
+multi_value!(multi_value_tuple_return {
+    module "export def pair(x: i32) -> (i32, f64) { (x, 1.5) }";
+
+    types = [0x07, 0x01, 0x60, 0x01, 0x7f, 0x02, 0x7f, 0x7c]
+});
+
+// `[i64] -> [i64]` for the caller and `[i64] -> [i64, i64]` for the callee
+multi_value!(multi_value_tuple_call {
+    module "export def sum(x: i64) -> i64 { let (a, b) = split(x); a + b } def split(x: i64) -> (i64, i64) { (x / 2, x % 2) }";
+
+    types = [0x0c, 0x02, 0x60, 0x01, 0x7e, 0x01, 0x7e, 0x60, 0x01, 0x7e, 0x02, 0x7e, 0x7e]
+});
+
+syntax!(destructuring_a_tuple_call {
+    module "export def sum(x: i64) -> i64 { let (a, b) = split(x); a + b } def split(x: i64) -> (i64, i64) { (x / 2, x % 2) }";
+
+    invoke sum(7i64) = 4i64
+});
+
+syntax!(tuple_locals_and_parameters {
+    module "export def run(x: i32) -> i32 { let pair = (x, x * 10); sum(pair) } def sum(pair: (i32, i32)) -> i32 { let (first, second) = pair; first + second }";
+
+    invoke run(4i32) = 44i32
+});
+
+syntax!(generic_tuple_return {
+    module "export def run(x: f64) -> f64 { let (a, b) = swap(x, 1); a as f64 + b } def swap<T, U>(a: T, b: U) -> (U, T) { (b, a) }";
+
+    invoke run(2.5f64) = 3.5f64
+});
+
+syntax!(tuple_equality {
+    module "export def same(a: i32, b: f64) -> bool { (a, b) == (a, 1.5) && (a, b) != (a + 1, b) }";
+
+    invoke same(1i32, 1.5f64) = 1i32
+});
+
+// Stores `$value` as each of the types and loads it back from the same
+// address
+macro_rules! load_store {
+    ($value:tt => $($ty:ident),*) => {
+        $(
+            syntax!($ty {
+                module concat!("memory 1; export def round_trip(x: ", stringify!($ty), ") -> ", stringify!($ty), " { store<", stringify!($ty), ">(8, x); load<", stringify!($ty), ">(8) }");
+
+                invoke round_trip(($value as $ty)) = { $value as $ty }
+            });
+        )*
+    }
+}
+
+mod load_store_positive {
+    load_store!(200 => i32, i64, u32, u64, f32, f64);
+}
+
+mod load_store_negative {
+    load_store!((-7) => i32, i64, f32, f64);
+}
+
+syntax!(load_and_store_bytes_are_little_endian {
+    module "memory 1; export def low_word(x: u64) -> u32 { store<u64>(16, x); load<u32>(16) }";
+
+    invoke low_word(0x1_0000_0002u64) = 2u32
+});
+
+syntax!(load_and_store_with_offset_and_alignment {
+    module "memory 1; export def shift(x: i64) -> i64 { store<i64>(1, x, 16, 1); load<i64>(9, 8, 1) }";
+
+    invoke shift(-12345i64) = (-12345i64)
+});
+
+syntax!(load_through_a_newtype {
+    module "newtype Cents = u32; memory 1; export def cents(x: u32) -> u32 { store<Cents>(4, x as Cents); load<Cents>(4) as u32 }";
+
+    invoke cents(250u32) = 250u32
+});
+
+// a module that doesn't declare a memory gets one
+syntax!(load_and_store_without_a_memory {
+    module "export def round_trip(x: f64) -> f64 { store<f64>(8, x); load<f64>(8) }";
+
+    invoke round_trip(2.5f64) = 2.5f64
+});
+
+// the tuple is returned through memory the call allocates, so it doesn't
+// overwrite what the module stored at address 0
+syntax!(tuple_calls_leave_low_memory_alone {
+    module "export def run(x: i64) -> i64 { store<i64>(0, 40); let (a, b) = split(x); load<i64>(0) + a + b } def split(x: i64) -> (i64, i64) { (x / 2, x % 2) }";
+
+    invoke run(7i64) = 44i64
+});
+
+// each call's slot is freed once the fields are read, so calling in a loop
+// doesn't grow memory past the page the first call needs
+syntax!(tuple_calls_in_a_loop {
+    module "export def run() -> u32 { let mut i = 0; let mut total = 0; while i < 100000 { let (a, b) = split(i); total += a - b; i += 1 } memory.size() } def split(x: i64) -> (i64, i64) { (x, x) }";
+
+    invoke run() = 2u32
+});
+
+compile_error!(tuple_calls_in_a_bounded_memory {
+    module "memory 1..1; export def sum(x: i64) -> i64 { let (a, b) = split(x); a + b } def split(x: i64) -> (i64, i64) { (x / 2, x % 2) }";
+
+    error
+});
+
+// growing past the maximum fails with -1 instead of trapping
+syntax!(memory_size_and_grow {
+    module "memory 1..2; export def grow() -> i32 { let before = memory.grow(1); let failed = memory.grow(1); before * 10 + failed + memory.size() as i32 }";
+
+    invoke grow() = 11i32
+});
+
+#[allow(unused)]
+mod host_buffer {
+    use crate::invoke_with_buffer;
+    use wasmi::RuntimeValue;
+
+    const KERNEL: &str = "export memory 1; export def sum(buffer: u32, len: u32) -> i32 { let mut total = 0; let mut i = 0; while i < len { total += load<i32>(buffer + i * 4); i += 1 } total }";
+
+    #[test]
+    fn sums_a_buffer_written_by_the_host() {
+        crate::init_logger();
+
+        let module = crate::compile(KERNEL).unwrap();
+
+        let mut bytes = vec![];
+
+        for int in &[3i32, -1, 40, 100] {
+            for byte in 0..4 {
+                bytes.push((int >> (byte * 8)) as u8);
+            }
+        }
+
+        let args = [RuntimeValue::I32(256), RuntimeValue::I32(4)];
+        let total = invoke_with_buffer(&module, "sum", 256, &bytes, &args);
+
+        assert_eq!(total, Some(RuntimeValue::I32(142)));
+    }
+}
+
+syntax!(mutable_global {
+    module "global mut calls: u32 = 0; def tick() { calls += 1; } export def run(n: u32) -> u32 { let mut i = 0; while i < n { tick(); i += 1 } calls }";
+
+    invoke run(5u32) = 5u32
+});
+
+syntax!(immutable_global {
+    module "global offset: i64 = -40; export def shift(x: i64) -> i64 { x + offset }";
+
+    invoke shift(2i64) = (-38i64)
+});
+
+syntax!(locals_shadow_globals {
+    module "global x: f64 = 1.5; export def pick(y: f64) -> f64 { let x = y; x }";
+
+    invoke pick(4.0f64) = 4.0f64
+});
+
+syntax!(newtype_and_boolean_globals {
+    module "newtype Cents = u64; global price: Cents = 250; global enabled: bool = true; export def total(n: u64) -> u64 { if enabled { price as u64 * n } else { 0 } }";
+
+    invoke total(3u64) = 750u64
+});
+
+// the global the tuple's memory is allocated from comes after the module's
+syntax!(globals_and_tuple_calls {
+    module "global mut calls: i64 = 0; export def run(x: i64) -> i64 { calls += 1; let (a, b) = split(x); a + b + calls } def split(x: i64) -> (i64, i64) { (x / 2, x % 2) }";
+
+    invoke run(7i64) = 5i64
+});
+
+#[allow(unused)]
+mod exported_globals {
+    use crate::exported_global;
+    use wasmi::RuntimeValue;
+
+    #[test]
+    fn the_host_can_read_an_exported_global() {
+        crate::init_logger();
+
+        let source = "export global limit: i32 = -7; export def get() -> i32 { limit }";
+        let module = crate::compile(source).unwrap();
+
+        assert_eq!(exported_global(&module, "limit"), RuntimeValue::I32(-7));
+    }
+}
+
+syntax!(const_is_inlined {
+    module "const LIMIT: u32 = 1 << 20; export def limit() -> u32 { LIMIT }";
+
+    invoke limit() = 1048576u32
+});
+
+syntax!(const_refers_to_a_later_const {
+    module "const AREA: f64 = WIDTH * 2.0; const WIDTH: f64 = 1.25; export def area() -> f64 { AREA }";
+
+    invoke area() = 2.5f64
+});
+
+syntax!(global_initialized_from_a_const {
+    module "const START: i64 = -(1 << 40); global mut next: i64 = START + 1; export def take() -> i64 { next += 1; next }";
+
+    invoke take() = (-1099511627774i64)
+});
+
+syntax!(boolean_const {
+    module "const DEBUG: bool = 3 > 4 || false; export def debug() -> bool { !DEBUG }";
+
+    invoke debug() = 1i32
+});
+
+syntax!(folded_subexpression {
+    module "export def scaled(x: u64) -> u64 { x * (1 << 3) + 10 / 2 }";
+
+    invoke scaled(4u64) = 37u64
+});
+
+// outside of consts and globals, folding leaves alone what would trap
+syntax!(division_by_a_literal_zero_still_compiles {
+    module "export def pick(x: i32) -> i32 { if x > 0 { x } else { 1 / 0 } }";
+
+    invoke pick(6i32) = 6i32
+});
+
+syntax!(saturating_conversion_in_a_const {
+    module "const CLAMPED: i32 = 3000000000.0 as saturating i32; export def clamped() -> i32 { CLAMPED }";
+
+    invoke clamped() = 2147483647i32
+});
+
+#[allow(unused)]
+mod const_errors {
+    use argon::{CompileError, ConstError, MathType};
+
+    fn const_error(source: &str) -> ConstError {
+        match crate::rejected(source) {
+            CompileError::ConstError(error) => error,
+            other => panic!("expected a const error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn overflow_in_a_const() {
+        assert_matches!(
+            const_error("const BIG: u32 = 4294967295 + 1;"),
+            ConstError::Overflow { ty: MathType::U32, .. }
+        );
+        assert_matches!(
+            const_error("const WIDE: i64 = 1 << 64;"),
+            ConstError::Overflow { ty: MathType::I64, .. }
+        );
+        assert_matches!(
+            const_error("const NEGATIVE: u64 = 0 - 1;"),
+            ConstError::Overflow { ty: MathType::U64, .. }
+        );
+    }
+
+    #[test]
+    fn overflow_points_at_the_expression() {
+        match const_error("const BIG: u32 = 4294967295 + 1;") {
+            ConstError::Overflow { expression, .. } => {
+                assert_eq!(format!("{:?}", expression), "17:31")
+            }
+            other => panic!("expected an overflow, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn division_by_zero_in_a_const() {
+        assert_matches!(
+            const_error("const NOPE: i32 = 10 / (5 - 5);"),
+            ConstError::DivisionByZero { .. }
+        );
+        assert_matches!(
+            const_error("const ALSO: u32 = 10 % 0;"),
+            ConstError::DivisionByZero { .. }
+        );
+    }
+
+    #[test]
+    fn division_by_zero_points_at_the_expression() {
+        match const_error("const ALSO: u32 = 10 % 0;") {
+            ConstError::DivisionByZero { expression } => {
+                assert_eq!(format!("{:?}", expression), "18:24")
+            }
+            other => panic!("expected a division by zero, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn overflow_in_a_global() {
+        assert_matches!(
+            const_error("const MAX: i32 = 2147483647; global mut next: i32 = MAX + 1;"),
+            ConstError::Overflow { ty: MathType::I32, .. }
+        );
+    }
+
+    #[test]
+    fn unused_consts_are_still_checked() {
+        assert_matches!(
+            const_error("const UNUSED: i32 = 1 / 0; export def f() -> i32 { 1 }"),
+            ConstError::DivisionByZero { .. }
+        );
+    }
+
+    // a conversion that would trap is rejected, unless it saturates
+    #[test]
+    fn trapping_conversion_in_a_const() {
+        assert_matches!(
+            const_error("const CLAMPED: i32 = 3000000000.0 as i32;"),
+            ConstError::Overflow { ty: MathType::I32, .. }
+        );
+    }
+}
+
+syntax!(struct_construction_and_field_access {
+    module "struct Point { x: f64, y: f64 } export def area(w: f64, h: f64) -> f64 { let p = new Point { x: w, y: h }; p.x * p.y }";
+
+    invoke area(1.5f64, 4.0f64) = 6.0f64
+});
+
+syntax!(field_assignment {
+    module "struct Counter { hits: u32, total: u64 } export def count(n: u32) -> u64 { let mut c = new Counter { total: 0, hits: 0 }; while c.hits < n { c.hits += 1; c.total += c.hits as u64 } c.total }";
+
+    invoke count(4u32) = 10u64
+});
+
+compile_error!(field_assignment_to_immutable {
+    module "struct Point { x: f64, y: f64 } def f() { let p = new Point { x: 1.0, y: 2.0 }; p.x = 0.5; }";
+
+    error
+});
+
+syntax!(structs_are_passed_by_pointer {
+    module "struct Point { x: f64, y: f64 } def shift(mut p: Point, by: f64) { p.x += by; p.y = p.y - by; } export def run() -> f64 { let p = new Point { x: 1.0, y: 2.0 }; shift(p, 0.5); p.x * 10.0 + p.y }";
+
+    invoke run() = 16.5f64
+});
+
+syntax!(struct_returned_from_a_function {
+    module "struct Point { x: f64, y: f64 } def at(x: f64) -> Point { new Point { x: x, y: x * 2.0 } } export def y(x: f64) -> f64 { at(x).y }";
+
+    invoke y(3.0f64) = 6.0f64
+});
+
+syntax!(boolean_and_newtype_fields {
+    module "newtype Id = u32; struct User { id: Id, active: bool } export def check(n: u32) -> bool { let u = new User { active: n > 2, id: n as Id }; u.active && u.id as u32 == n }";
+
+    invoke check(3u32) = 1i32
+});
+
+// the struct is only known once inference sees through the tuple
+syntax!(field_of_a_destructured_struct {
+    module "struct Point { x: f64, y: f64 } export def first(x: f64) -> f64 { let (p, n) = (new Point { x: x, y: 0.0 }, 2.0); p.x * n }";
+
+    invoke first(1.5f64) = 3.0f64
+});
+
+syntax!(structs_compare_by_address {
+    module "struct Cell { value: i32 } export def same() -> bool { let a = new Cell { value: 1 }; let b = a; let c = new Cell { value: 1 }; a == b && a != c }";
+
+    invoke same() = 1i32
+});
+
+// 16 bytes each, so this runs past the first few pages of memory
+syntax!(allocating_grows_memory {
+    module "struct Pair { a: f64, b: f64 } export def fill(n: u32) -> f64 { let mut sum = 0.0; let mut i = 0; while i < n { let p = new Pair { a: 1.0, b: 2.0 }; sum += p.a + p.b; i += 1 } sum }";
+
+    invoke fill(10000u32) = 30000.0f64
+});
+
+// the heap never shrinks, so it would eventually run into the maximum
+compile_error!(allocating_in_a_bounded_memory {
+    module "memory 1..4; struct Pair { a: f64, b: f64 } export def first(a: f64) -> f64 { let p = new Pair { a: a, b: 2.0 }; p.a }";
+
+    error
+});
+
+#[allow(unused)]
+mod scratch_locals {
+    use parity_wasm::elements::ValueType;
+
+    // the address `new` holds onto is handed back once the struct is built,
+    // so both `+=` reuse it instead of declaring locals of their own
+    #[test]
+    fn temps_are_reused() {
+        crate::init_logger();
+
+        let source = "struct Cell { n: i32 } export def f() -> i32 { let mut c = new Cell { n: 0 }; c.n += 1; c.n += 2; c.n }";
+        let module = crate::compile(source).unwrap();
+
+        let body = &module.code_section().unwrap().bodies()[0];
+        let locals: Vec<ValueType> = body
+            .locals()
+            .iter()
+            .flat_map(|local| (0..local.count()).map(move |_| local.value_type()))
+            .collect();
+
+        // `c`, and the one temp
+        assert_eq!(locals, vec![ValueType::I32, ValueType::I32]);
+    }
+}
+
+#[allow(unused)]
+mod host_structs {
+    use crate::invoke_with_buffer;
+    use nan_preserving_float::F64;
+    use wasmi::RuntimeValue;
+
+    // little endian, like wasm memory
+    fn push_bits(bytes: &mut Vec<u8>, bits: u64, size: u32) {
+        for byte in 0..size {
+            bytes.push((bits >> (byte * 8)) as u8);
+        }
+    }
+
+    // `gain` is aligned to 8 bytes, after the two 4 byte fields
+    const MIXER: &str = "struct Sample { left: f32, right: f32, gain: f64 } export def mix(address: u32) -> f64 { let s = address as Sample; (s.left + s.right) as f64 * s.gain }";
+
+    #[test]
+    fn reads_a_struct_the_host_laid_out() {
+        crate::init_logger();
+
+        let module = crate::compile(MIXER).unwrap();
+
+        let mut bytes = vec![];
+        push_bits(&mut bytes, u64::from(1.5f32.to_bits()), 4);
+        push_bits(&mut bytes, u64::from(2.5f32.to_bits()), 4);
+        push_bits(&mut bytes, 0.5f64.to_bits(), 8);
+
+        let args = [RuntimeValue::I32(256)];
+        let mixed = invoke_with_buffer(&module, "mix", 256, &bytes, &args);
+
+        assert_eq!(mixed, Some(RuntimeValue::F64(F64::from_bits(2.0f64.to_bits()))));
+    }
+
+    // a field is aligned to its own size, so `value` skips 4 bytes
+    #[test]
+    fn aligns_fields_to_their_size() {
+        crate::init_logger();
+
+        let source = "struct Flagged { set: bool, value: i64 } export def get(address: u32) -> i64 { let f = address as Flagged; if f.set { f.value } else { 0 } }";
+        let module = crate::compile(source).unwrap();
+
+        let mut bytes = vec![1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff];
+        push_bits(&mut bytes, -9i64 as u64, 8);
+
+        let args = [RuntimeValue::I32(128)];
+        let value = invoke_with_buffer(&module, "get", 128, &bytes, &args);
+
+        assert_eq!(value, Some(RuntimeValue::I64(-9)));
+    }
+}
+
+#[allow(unused)]
+mod struct_errors {
+    use argon::resolved::ResolveError;
+    use argon::{CompileError, TypeError};
+
+    fn type_error(source: &str) -> TypeError {
+        match crate::rejected(source) {
+            CompileError::TypeError(error) => error,
+            other => panic!("expected a type error, found {:?}", other),
+        }
+    }
+
+    const POINT: &str = "struct Point { x: f64, y: f64 }";
+
+    #[test]
+    fn every_field_is_initialized_once() {
+        assert_matches!(
+            crate::rejected::<ResolveError>(&format!(
+                "{} def f() {{ new Point {{ x: 1.0 }}; }}",
+                POINT
+            )),
+            ResolveError::MissingField { .. }
+        );
+        assert_matches!(
+            crate::rejected::<ResolveError>(&format!(
+                "{} def f() {{ new Point {{ x: 1.0, y: 2.0, x: 3.0 }}; }}",
+                POINT
+            )),
+            ResolveError::DuplicateField { .. }
+        );
+        assert_matches!(
+            crate::rejected::<ResolveError>(&format!(
+                "{} def f() {{ new Point {{ x: 1.0, y: 2.0, z: 3.0 }}; }}",
+                POINT
+            )),
+            ResolveError::UnknownField(..)
+        );
+    }
+
+    #[test]
+    fn errors_point_at_the_source() {
+        let error = crate::compile(&format!("{} def f(p: Point) -> f64 {{ p.z }}", POINT))
+            .unwrap_err();
+
+        assert_eq!(error.to_string(), "unknown field at 58:60");
+    }
+
+    #[test]
+    fn unknown_field() {
+        assert_matches!(
+            type_error(&format!("{} def f(p: Point) -> f64 {{ p.z }}", POINT)),
+            TypeError::UnknownField { .. }
+        );
+    }
+
+    #[test]
+    fn field_values_have_the_field_type() {
+        assert_matches!(
+            crate::rejected(&format!("{} def f() {{ new Point {{ x: 1.0, y: true }}; }}", POINT)),
+            CompileError::UnifyError(..)
+        );
+        assert_matches!(
+            crate::rejected(&format!("{} def f(mut p: Point) {{ p.x = 1; }}", POINT)),
+            CompileError::UnifyError(..)
+        );
+    }
+
+    #[test]
+    fn fields_of_non_structs() {
+        assert_matches!(
+            type_error("def f(a: i32) -> i32 { a.x }"),
+            TypeError::UnknownField { .. }
+        );
+        assert_matches!(
+            type_error("def f() -> i32 { let a = 1; a.x }"),
+            TypeError::UnknownField { .. }
+        );
+    }
+
+    #[test]
+    fn fields_are_numbers_or_booleans() {
+        assert_matches!(
+            crate::rejected::<ResolveError>("struct Nested { inner: (i32, i32) }"),
+            ResolveError::InvalidFieldType(..)
+        );
+        assert_matches!(
+            crate::rejected::<ResolveError>("struct A { b: B } struct B { a: A }"),
+            ResolveError::RecursiveTypeDeclaration(..)
+        );
+        assert_matches!(
+            crate::rejected::<ResolveError>("struct Twice { x: i32, x: i32 }"),
+            ResolveError::DuplicateField { .. }
+        );
+    }
+
+    #[test]
+    fn structs_and_types_share_a_namespace() {
+        assert_matches!(
+            crate::rejected::<ResolveError>("type Point = f64; struct Point { x: f64 }"),
+            ResolveError::DuplicateType { .. }
+        );
+    }
+}
+
+syntax!(for_over_an_array {
+    module "export def sum() -> f64 { let samples = [1.5f64, 2.0, 4.5]; let mut total = 0.0; for s in samples { total += s } total }";
+
+    invoke sum() = 8.0f64
+});
+
+syntax!(index_of_an_inferred_array {
+    module "export def second() -> f64 { let a = [1.0, 2.0]; a[1] }";
+
+    invoke second() = 2.0f64
+});
+
+// the literals take their type from the `u32` they're added to, through the
+// slice
+syntax!(slice_of_unsuffixed_literals {
+    module "export def run() -> u32 { let a = [1, 2, 3]; let s = &a; let mut total = 0; for x in s { total += x } total + len(s) }";
+
+    invoke run() = 9u32
+});
+
+syntax!(index_assignment {
+    module "export def squares(n: u32) -> u32 { let mut a = [0u32; 8]; let mut i = 0; while i < 8 { a[i] = i * i; i += 1 } a[n] += 1; a[n] }";
+
+    invoke squares(3u32) = 10u32
+});
+
+compile_error!(index_assignment_to_immutable {
+    module "def f(a: [i32; 2]) { a[0] = 1; }";
+
+    error
+});
+
+syntax!(length_of_arrays_and_slices {
+    module "export def count() -> u32 { let flags = [true, false, true]; len(flags) + len(&flags) }";
+
+    invoke count() = 6u32
+});
+
+syntax!(break_and_continue_in_a_for_loop {
+    module "export def first_over(limit: i32) -> i32 { let a: [i32; 5] = [3, 8, -2, 12, 40]; let mut found = -1; for x in a { if x < 0 { continue } if x > limit { found = x; break } } found }";
+
+    invoke first_over(10i32) = 12i32
+});
+
+syntax!(arrays_of_structs {
+    module "struct Point { x: f64, y: f64 } export def total() -> f64 { let mut points = [new Point { x: 1.0, y: 2.0 }, new Point { x: 3.0, y: 4.0 }]; let mut sum = 0.0; for p in points { sum += p.x * p.y } points[1].x = 0.5; sum + points[1].x }";
+
+    invoke total() = 14.5f64
+});
+
+syntax!(slices_are_passed_as_a_pointer_and_a_length {
+    module "def sum(xs: &[i64]) -> i64 { let mut total = 0; for x in xs { total += x } total } export def run() -> i64 { let a = [1i64, 2, 3, 4]; sum(&a) }";
+
+    invoke run() = 10i64
+});
+
+syntax!(slice_returned_from_a_function {
+    module "def whole(a: [u32; 3]) -> &[u32] { &a } export def run() -> u32 { let s = whole([5u32, 6, 7]); s[2] + len(s) }";
+
+    invoke run() = 10u32
+});
+
+// a slice comes back as its pointer and length, `[i32, i32] -> [i32, i32]`
+multi_value!(multi_value_slice_return {
+    module "export def same(samples: &[f32]) -> &[f32] { samples }";
+
+    types = [0x08, 0x01, 0x60, 0x02, 0x7f, 0x7f, 0x02, 0x7f, 0x7f];
+    memory = true
+});
+
+#[allow(unused)]
+mod host_slices {
+    use crate::invoke_with_buffer;
+    use nan_preserving_float::F32;
+    use wasmi::RuntimeValue;
+
+    // the host passes a slice as the address of its first element and the
+    // number of elements
+    #[test]
+    fn sums_samples_the_host_wrote() {
+        crate::init_logger();
+
+        let source = "export def sum(samples: &[f32]) -> f32 { let mut total = 0.0; for s in samples { total += s } total }";
+        let module = crate::compile(source).unwrap();
+
+        let mut bytes = vec![];
+
+        for sample in &[0.25f32, -1.0, 3.5] {
+            let bits = sample.to_bits();
+
+            for byte in 0..4 {
+                bytes.push((bits >> (byte * 8)) as u8);
+            }
+        }
+
+        let args = [RuntimeValue::I32(512), RuntimeValue::I32(3)];
+        let total = invoke_with_buffer(&module, "sum", 512, &bytes, &args);
+
+        assert_eq!(total, Some(RuntimeValue::F32(F32::from_bits(2.75f32.to_bits()))));
+    }
+}
+
+#[allow(unused)]
+mod bounds_checks {
+    use argon::CompileOptions;
+    use wasmi::{NopExternals, RuntimeValue};
+
+    const AT: &str = "export def at(i: u32) -> i32 { let a: [i32; 3] = [10, 20, 30]; a[i] }";
+
+    fn at_with_options(
+        options: CompileOptions,
+        index: i32,
+    ) -> Result<Option<RuntimeValue>, wasmi::Error> {
+        crate::init_logger();
+
+        let module = crate::compile_with_options(AT, options).unwrap();
+        let args = [RuntimeValue::I32(index)];
+
+        crate::instantiate(&module).invoke_export("at", &args, &mut NopExternals)
+    }
+
+    fn at(index: i32) -> Result<Option<RuntimeValue>, wasmi::Error> {
+        at_with_options(CompileOptions::default(), index)
+    }
+
+    #[test]
+    fn indexes_within_bounds() {
+        assert_eq!(at(0).unwrap(), Some(RuntimeValue::I32(10)));
+        assert_eq!(at(2).unwrap(), Some(RuntimeValue::I32(30)));
+    }
+
+    // indices are unsigned, so -1 is past the end too
+    #[test]
+    fn traps_past_the_end() {
+        assert!(at(3).is_err());
+        assert!(at(-1).is_err());
+    }
+
+    // without the check, `a[3]` reads the memory after the array
+    #[test]
+    fn release_builds_leave_out_the_check() {
+        let release = CompileOptions::release();
+
+        assert_eq!(at_with_options(release, 2).unwrap(), Some(RuntimeValue::I32(30)));
+        assert!(at_with_options(release, 3).is_ok());
+    }
+}
+
+#[allow(unused)]
+mod array_errors {
+    use argon::resolved::ResolveError;
+    use argon::{CompileError, TypeError};
+
+    fn type_error(source: &str) -> TypeError {
+        match crate::rejected(source) {
+            CompileError::TypeError(error) => error,
+            other => panic!("expected a type error, found {:?}", other),
+        }
+    }
+
+    #[test]
+    fn only_arrays_and_slices_are_indexed() {
+        assert_matches!(
+            type_error("def f(a: i32) -> i32 { a[0] }"),
+            TypeError::NotASequence { .. }
+        );
+        assert_matches!(
+            type_error("def f(a: i32) -> u32 { len(a) }"),
+            TypeError::NotASequence { .. }
+        );
+        assert_matches!(
+            crate::rejected::<ResolveError>("def f(a: [i32; 2]) -> u32 { len(a, a) }"),
+            ResolveError::LenArguments(..)
+        );
+        assert_matches!(
+            type_error("def f(a: &[i32]) { let b = &a; }"),
+            TypeError::NotAnArray { .. }
+        );
+    }
+
+    #[test]
+    fn elements_have_the_same_type() {
+        assert_matches!(
+            crate::rejected("def f() { let a = [1.0f32, 2.0f64]; }"),
+            CompileError::UnifyError(..)
+        );
+        assert_matches!(
+            crate::rejected("def f() { let a: [f64; 3] = [1.0, 2.0]; }"),
+            CompileError::UnifyError(..)
+        );
+        assert_matches!(
+            crate::rejected("def f(mut a: [i32; 2]) { a[0] = true; }"),
+            CompileError::UnifyError(..)
+        );
+    }
+
+    #[test]
+    fn indices_are_u32s() {
+        assert_matches!(
+            crate::rejected("def f(a: [i32; 2], i: i64) -> i32 { a[i] }"),
+            CompileError::UnifyError(..)
+        );
+    }
+
+    #[test]
+    fn elements_are_numbers_booleans_or_structs() {
+        assert_matches!(
+            crate::rejected::<ResolveError>("def f(a: [(i32, i32); 2]) {}"),
+            ResolveError::InvalidElementType(..)
+        );
+        assert_matches!(
+            crate::rejected::<ResolveError>("def f(a: &[&[i32]]) {}"),
+            ResolveError::InvalidElementType(..)
+        );
+        assert_matches!(
+            type_error("def f() { let a = [(1, 2)]; }"),
+            TypeError::InvalidElementType { .. }
+        );
+        assert_matches!(
+            crate::rejected::<ResolveError>("def f<T>(a: [T; 2]) {}"),
+            ResolveError::InvalidElementType(..)
+        );
+    }
+}